@@ -22,6 +22,43 @@ impl ComponentAuthZone {
         })
     }
 
+    /// Lists the resource address and amount of every proof in the auth zone, without removing
+    /// any of the proofs.
+    pub fn sys_list<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        env: &mut Y,
+    ) -> Result<Vec<(ResourceAddress, Decimal)>, E>
+    where
+        Y: EngineApi<E> + Invokable<AuthZoneListInvocation, E>,
+    {
+        let node_id = Self::auth_zone_node_id(env).expect("Auth Zone doesn't exist");
+        env.invoke(AuthZoneListInvocation {
+            receiver: node_id.into(),
+        })
+    }
+
+    /// Runs `then` if a proof of the given resource could be created from the auth zone, and `els`
+    /// otherwise. The check is made against the listing of the auth zone, so no proof is created.
+    pub fn sys_if_proves<Y, E, T>(
+        resource_address: ResourceAddress,
+        env: &mut Y,
+        then: impl FnOnce() -> T,
+        els: impl FnOnce() -> T,
+    ) -> Result<T, E>
+    where
+        Y: EngineApi<E> + Invokable<AuthZoneListInvocation, E>,
+        E: Debug + ScryptoCategorize + ScryptoDecode,
+    {
+        let proves = Self::sys_list(env)?
+            .into_iter()
+            .any(|(address, amount)| address == resource_address && amount.is_positive());
+
+        if proves {
+            Ok(then())
+        } else {
+            Ok(els())
+        }
+    }
+
     pub fn sys_clear<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(env: &mut Y) -> Result<(), E>
     where
         Y: EngineApi<E> + Invokable<AuthZoneClearInvocation, E>,
@@ -69,6 +106,7 @@ impl ComponentAuthZone {
             receiver: node_id.into(),
             amount,
             resource_address,
+            allow_zero_amount: false,
         })
     }
 
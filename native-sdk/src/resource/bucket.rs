@@ -1,4 +1,5 @@
-use radix_engine_interface::api::Invokable;
+use crate::resource::ComponentAuthZone;
+use radix_engine_interface::api::{EngineApi, Invokable};
 use radix_engine_interface::data::{ScryptoCategorize, ScryptoDecode};
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
@@ -71,6 +72,15 @@ pub trait SysBucket {
     where
         Y: Invokable<BucketCreateProofInvocation, E>;
 
+    fn sys_create_and_push_proof<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        sys_calls: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: EngineApi<E>
+            + Invokable<BucketCreateProofInvocation, E>
+            + Invokable<AuthZonePushInvocation, E>;
+
     fn sys_is_empty<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
         &self,
         sys_calls: &mut Y,
@@ -182,6 +192,20 @@ impl SysBucket for Bucket {
         api.invoke(BucketCreateProofInvocation { receiver: self.0 })
     }
 
+    /// Creates a proof of this bucket and pushes it onto the auth zone of the current frame.
+    fn sys_create_and_push_proof<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: EngineApi<E>
+            + Invokable<BucketCreateProofInvocation, E>
+            + Invokable<AuthZonePushInvocation, E>,
+    {
+        let proof = self.sys_create_proof(api)?;
+        ComponentAuthZone::sys_push(proof, api)
+    }
+
     fn sys_is_empty<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
         &self,
         api: &mut Y,
@@ -3,6 +3,7 @@ use radix_engine_interface::api::{EngineApi, Invokable, InvokableModel};
 use radix_engine_interface::data::ScryptoDecode;
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
+use sbor::rust::collections::BTreeSet;
 use sbor::rust::fmt::Debug;
 
 pub struct Vault(pub VaultId); // native stub
@@ -59,6 +60,16 @@ impl Vault {
         sys_calls.invoke(VaultGetAmountInvocation { receiver: self.0 })
     }
 
+    pub fn sys_resource_address<Y, E: Debug + ScryptoDecode>(
+        &self,
+        sys_calls: &mut Y,
+    ) -> Result<ResourceAddress, E>
+    where
+        Y: EngineApi<E> + Invokable<VaultGetResourceAddressInvocation, E>,
+    {
+        sys_calls.invoke(VaultGetResourceAddressInvocation { receiver: self.0 })
+    }
+
     pub fn sys_create_proof<Y, E>(&self, sys_calls: &mut Y) -> Result<Proof, E>
     where
         E: Debug + ScryptoDecode,
@@ -66,4 +77,19 @@ impl Vault {
     {
         sys_calls.invoke(VaultCreateProofInvocation { receiver: self.0 })
     }
+
+    pub fn sys_create_proof_by_ids<Y, E>(
+        &self,
+        ids: &BTreeSet<NonFungibleLocalId>,
+        sys_calls: &mut Y,
+    ) -> Result<Proof, E>
+    where
+        E: Debug + ScryptoDecode,
+        Y: EngineApi<E> + Invokable<VaultCreateProofByIdsInvocation, E>,
+    {
+        sys_calls.invoke(VaultCreateProofByIdsInvocation {
+            receiver: self.0,
+            ids: ids.clone(),
+        })
+    }
 }
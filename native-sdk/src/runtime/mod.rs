@@ -1,3 +1,5 @@
+mod retrying;
 mod runtime;
 
+pub use retrying::*;
 pub use runtime::*;
@@ -0,0 +1,107 @@
+use radix_engine_interface::api::types::{LockHandle, RENodeId, ScryptoRENode, SubstateOffset};
+use radix_engine_interface::api::{EngineApi, Invocation, Invokable};
+use sbor::rust::fmt::Debug;
+use sbor::rust::vec::Vec;
+
+/// Wraps an api so that invocations failing with an error deemed transient are retried, up to a
+/// bounded number of times, before the error is surfaced. System calls other than invocations are
+/// passed through as they are.
+///
+/// The `sys_*` helpers of this crate are generic over the api, so passing them a
+/// [`RetryingApi`] makes them retry without any change to the helpers themselves:
+///
+/// ```ignore
+/// let mut api = RetryingApi::new(api, 3, |error| matches!(error, MyError::Busy));
+/// let proof = ComponentAuthZone::sys_pop(&mut api)?;
+/// ```
+///
+/// Helpers called with the api directly keep their default behaviour of never retrying.
+pub struct RetryingApi<'a, Y, F> {
+    api: &'a mut Y,
+    max_retries: u32,
+    is_transient: F,
+}
+
+impl<'a, Y, F> RetryingApi<'a, Y, F> {
+    /// Wraps the api, retrying an invocation at most `max_retries` times - so calling it at most
+    /// `max_retries + 1` times - for as long as it fails with an error for which `is_transient`
+    /// returns `true`. There is no backoff between attempts, as the engine has no notion of
+    /// waiting within a transaction.
+    pub fn new(api: &'a mut Y, max_retries: u32, is_transient: F) -> Self {
+        Self {
+            api,
+            max_retries,
+            is_transient,
+        }
+    }
+}
+
+impl<'a, Y, F, N, E> Invokable<N, E> for RetryingApi<'a, Y, F>
+where
+    Y: Invokable<N, E>,
+    F: Fn(&E) -> bool,
+    N: Invocation + Clone,
+{
+    fn invoke(&mut self, invocation: N) -> Result<N::Output, E> {
+        let mut retries = 0;
+        loop {
+            match self.api.invoke(invocation.clone()) {
+                Err(error) if retries < self.max_retries && (self.is_transient)(&error) => {
+                    retries += 1;
+                }
+                rtn => return rtn,
+            }
+        }
+    }
+}
+
+impl<'a, Y, F, E> EngineApi<E> for RetryingApi<'a, Y, F>
+where
+    Y: EngineApi<E>,
+    E: Debug,
+{
+    fn sys_create_node(&mut self, node: ScryptoRENode) -> Result<RENodeId, E> {
+        self.api.sys_create_node(node)
+    }
+
+    fn sys_drop_node(&mut self, node_id: RENodeId) -> Result<(), E> {
+        self.api.sys_drop_node(node_id)
+    }
+
+    fn sys_drop_nodes(&mut self, node_ids: Vec<RENodeId>) -> Result<(), E> {
+        self.api.sys_drop_nodes(node_ids)
+    }
+
+    fn sys_get_visible_nodes(&mut self) -> Result<Vec<RENodeId>, E> {
+        self.api.sys_get_visible_nodes()
+    }
+
+    fn sys_lock_substate(
+        &mut self,
+        node_id: RENodeId,
+        offset: SubstateOffset,
+        mutable: bool,
+    ) -> Result<LockHandle, E> {
+        self.api.sys_lock_substate(node_id, offset, mutable)
+    }
+
+    fn sys_read(&mut self, lock_handle: LockHandle) -> Result<Vec<u8>, E> {
+        self.api.sys_read(lock_handle)
+    }
+
+    fn sys_read_field(&mut self, lock_handle: LockHandle, path: Vec<usize>) -> Result<Vec<u8>, E> {
+        self.api.sys_read_field(lock_handle, path)
+    }
+
+    fn sys_write(&mut self, lock_handle: LockHandle, buffer: Vec<u8>) -> Result<(), E> {
+        self.api.sys_write(lock_handle, buffer)
+    }
+
+    fn sys_write_batch(&mut self, writes: Vec<(LockHandle, Vec<u8>)>) -> Result<(), E> {
+        self.api.sys_write_batch(writes)
+    }
+
+    fn sys_drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), E> {
+        self.api.sys_drop_lock(lock_handle)
+    }
+}
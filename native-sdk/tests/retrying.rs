@@ -0,0 +1,133 @@
+use native_sdk::resource::ComponentAuthZone;
+use native_sdk::runtime::RetryingApi;
+use radix_engine_interface::api::types::{LockHandle, RENodeId, ScryptoRENode, SubstateOffset};
+use radix_engine_interface::api::{EngineApi, Invokable};
+use radix_engine_interface::model::{AuthZonePopInvocation, Proof};
+use radix_engine_interface::{ScryptoCategorize, ScryptoDecode, ScryptoEncode};
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+enum MockError {
+    Busy,
+    Fatal,
+}
+
+/// An api whose auth zone pops fail with the given errors, in order, before succeeding.
+struct MockApi {
+    pop_errors: Vec<MockError>,
+    pop_attempts: u32,
+}
+
+impl MockApi {
+    fn new(pop_errors: Vec<MockError>) -> Self {
+        Self {
+            pop_errors,
+            pop_attempts: 0,
+        }
+    }
+}
+
+impl Invokable<AuthZonePopInvocation, MockError> for MockApi {
+    fn invoke(&mut self, _invocation: AuthZonePopInvocation) -> Result<Proof, MockError> {
+        self.pop_attempts += 1;
+        if self.pop_errors.is_empty() {
+            Ok(Proof(7))
+        } else {
+            Err(self.pop_errors.remove(0))
+        }
+    }
+}
+
+impl EngineApi<MockError> for MockApi {
+    fn sys_create_node(&mut self, _node: ScryptoRENode) -> Result<RENodeId, MockError> {
+        unimplemented!()
+    }
+
+    fn sys_drop_node(&mut self, _node_id: RENodeId) -> Result<(), MockError> {
+        unimplemented!()
+    }
+
+    fn sys_drop_nodes(&mut self, _node_ids: Vec<RENodeId>) -> Result<(), MockError> {
+        unimplemented!()
+    }
+
+    fn sys_get_visible_nodes(&mut self) -> Result<Vec<RENodeId>, MockError> {
+        Ok(vec![RENodeId::AuthZoneStack(0)])
+    }
+
+    fn sys_lock_substate(
+        &mut self,
+        _node_id: RENodeId,
+        _offset: SubstateOffset,
+        _mutable: bool,
+    ) -> Result<LockHandle, MockError> {
+        unimplemented!()
+    }
+
+    fn sys_read(&mut self, _lock_handle: LockHandle) -> Result<Vec<u8>, MockError> {
+        unimplemented!()
+    }
+
+    fn sys_read_field(
+        &mut self,
+        _lock_handle: LockHandle,
+        _path: Vec<usize>,
+    ) -> Result<Vec<u8>, MockError> {
+        unimplemented!()
+    }
+
+    fn sys_write(&mut self, _lock_handle: LockHandle, _buffer: Vec<u8>) -> Result<(), MockError> {
+        unimplemented!()
+    }
+
+    fn sys_write_batch(&mut self, _writes: Vec<(LockHandle, Vec<u8>)>) -> Result<(), MockError> {
+        unimplemented!()
+    }
+
+    fn sys_drop_lock(&mut self, _lock_handle: LockHandle) -> Result<(), MockError> {
+        unimplemented!()
+    }
+}
+
+fn is_busy(error: &MockError) -> bool {
+    matches!(error, MockError::Busy)
+}
+
+#[test]
+fn test_transient_failure_is_retried() {
+    let mut api = MockApi::new(vec![MockError::Busy]);
+
+    let proof = ComponentAuthZone::sys_pop(&mut RetryingApi::new(&mut api, 3, is_busy));
+
+    assert_eq!(proof, Ok(Proof(7)));
+    assert_eq!(api.pop_attempts, 2);
+}
+
+#[test]
+fn test_failure_is_not_retried_by_default() {
+    let mut api = MockApi::new(vec![MockError::Busy]);
+
+    let proof = ComponentAuthZone::sys_pop(&mut api);
+
+    assert_eq!(proof, Err(MockError::Busy));
+    assert_eq!(api.pop_attempts, 1);
+}
+
+#[test]
+fn test_non_transient_failure_is_not_retried() {
+    let mut api = MockApi::new(vec![MockError::Fatal]);
+
+    let proof = ComponentAuthZone::sys_pop(&mut RetryingApi::new(&mut api, 3, is_busy));
+
+    assert_eq!(proof, Err(MockError::Fatal));
+    assert_eq!(api.pop_attempts, 1);
+}
+
+#[test]
+fn test_retries_are_bounded() {
+    let mut api = MockApi::new(vec![MockError::Busy; 5]);
+
+    let proof = ComponentAuthZone::sys_pop(&mut RetryingApi::new(&mut api, 3, is_busy));
+
+    assert_eq!(proof, Err(MockError::Busy));
+    assert_eq!(api.pop_attempts, 4);
+}
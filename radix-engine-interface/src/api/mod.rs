@@ -24,6 +24,8 @@ pub trait Invokable<I: Invocation, E> {
 pub trait EngineApi<E: Debug> {
     fn sys_create_node(&mut self, node: ScryptoRENode) -> Result<RENodeId, E>;
     fn sys_drop_node(&mut self, node_id: RENodeId) -> Result<(), E>;
+    /// Drops several nodes in order, stopping at the first node which fails to drop.
+    fn sys_drop_nodes(&mut self, node_ids: Vec<RENodeId>) -> Result<(), E>;
     fn sys_get_visible_nodes(&mut self) -> Result<Vec<RENodeId>, E>;
     fn sys_lock_substate(
         &mut self,
@@ -32,7 +34,12 @@ pub trait EngineApi<E: Debug> {
         mutable: bool,
     ) -> Result<LockHandle, E>;
     fn sys_read(&mut self, lock_handle: LockHandle) -> Result<Vec<u8>, E>;
+    /// Reads the encoded field at the given SBOR path of a locked substate. For component state
+    /// and key value store entries, the path is into the stored value rather than the substate.
+    fn sys_read_field(&mut self, lock_handle: LockHandle, path: Vec<usize>) -> Result<Vec<u8>, E>;
     fn sys_write(&mut self, lock_handle: LockHandle, buffer: Vec<u8>) -> Result<(), E>;
+    /// Writes to several locked substates, applying none of the writes if any of them is invalid.
+    fn sys_write_batch(&mut self, writes: Vec<(LockHandle, Vec<u8>)>) -> Result<(), E>;
     fn sys_drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), E>;
 }
 
@@ -83,10 +90,12 @@ pub trait InvokableModel<E>:
     + Invokable<AuthZoneCreateProofByIdsInvocation, E>
     + Invokable<AuthZoneClearInvocation, E>
     + Invokable<AuthZoneDrainInvocation, E>
+    + Invokable<AuthZoneListInvocation, E>
     + Invokable<AuthZoneAssertAccessRuleInvocation, E>
     + Invokable<AccessRulesAddAccessCheckInvocation, E>
     + Invokable<ComponentGlobalizeInvocation, E>
     + Invokable<ComponentGlobalizeWithOwnerInvocation, E>
+    + Invokable<ComponentGlobalizeWithAccessRulesInvocation, E>
     + Invokable<ComponentSetRoyaltyConfigInvocation, E>
     + Invokable<ComponentClaimRoyaltyInvocation, E>
     + Invokable<PackageSetRoyaltyConfigInvocation, E>
@@ -101,9 +110,14 @@ pub trait InvokableModel<E>:
     + Invokable<BucketCreateProofInvocation, E>
     + Invokable<BucketCreateProofInvocation, E>
     + Invokable<ProofCloneInvocation, E>
+    + Invokable<ProofMakeOneShotInvocation, E>
+    + Invokable<ProofIntoResourceProofInvocation, E>
     + Invokable<ProofGetAmountInvocation, E>
     + Invokable<ProofGetNonFungibleLocalIdsInvocation, E>
     + Invokable<ProofGetResourceAddressInvocation, E>
+    + Invokable<ProofValidateAgainstStoredRuleInvocation, E>
+    + Invokable<ProofSatisfiedRolesInvocation, E>
+    + Invokable<ProofIsPresenceInvocation, E>
     + Invokable<ResourceManagerBucketBurnInvocation, E>
     + Invokable<ResourceManagerCreateNonFungibleInvocation, E>
     + Invokable<ResourceManagerCreateFungibleInvocation, E>
@@ -120,6 +134,8 @@ pub trait InvokableModel<E>:
     + Invokable<ResourceManagerMintFungibleInvocation, E>
     + Invokable<ResourceManagerGetResourceTypeInvocation, E>
     + Invokable<ResourceManagerGetTotalSupplyInvocation, E>
+    + Invokable<ResourceManagerGetBehaviorInvocation, E>
+    + Invokable<ResourceManagerIsFrozenInvocation, E>
     + Invokable<ResourceManagerUpdateNonFungibleDataInvocation, E>
     + Invokable<ResourceManagerNonFungibleExistsInvocation, E>
     + Invokable<ResourceManagerGetNonFungibleInvocation, E>
@@ -149,15 +165,38 @@ pub trait InvokableModel<E>:
     + Invokable<LoggerLogInvocation, E>
     + Invokable<AccessControllerCreateGlobalInvocation, E>
     + Invokable<AccessControllerCreateProofInvocation, E>
+    + Invokable<AccessControllerCreateProofsInvocation, E>
     + Invokable<AccessControllerInitiateRecoveryAsPrimaryInvocation, E>
     + Invokable<AccessControllerInitiateRecoveryAsRecoveryInvocation, E>
     + Invokable<AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation, E>
     + Invokable<AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation, E>
+    + Invokable<AccessControllerBatchConfirmRecoveryInvocation, E>
     + Invokable<AccessControllerTimedConfirmRecoveryInvocation, E>
     + Invokable<AccessControllerCancelPrimaryRoleRecoveryProposalInvocation, E>
     + Invokable<AccessControllerCancelRecoveryRoleRecoveryProposalInvocation, E>
     + Invokable<AccessControllerLockPrimaryRoleInvocation, E>
     + Invokable<AccessControllerUnlockPrimaryRoleInvocation, E>
     + Invokable<AccessControllerStopTimedRecoveryInvocation, E>
+    + Invokable<AccessControllerExtendTimedRecoveryInvocation, E>
+    + Invokable<AccessControllerGetRoleRuleInvocation, E>
+    + Invokable<AccessControllerEndorseRecoveryRoleRecoveryProposalInvocation, E>
+    + Invokable<AccessControllerExportStateInvocation, E>
+    + Invokable<AccessControllerGetControlledResourceManagerInvocation, E>
+    + Invokable<AccessControllerVerifyControlledAssetInvocation, E>
+    + Invokable<AccessControllerGetControlledVaultsInvocation, E>
+    + Invokable<AccessControllerValidateProposalInvocation, E>
+    + Invokable<AccessControllerTransferOwnershipInvocation, E>
+    + Invokable<AccessControllerCheckRoleInvocation, E>
+    + Invokable<AccessControllerCanActAsInvocation, E>
+    + Invokable<AccessControllerInitiateRecoveryAsDelegateInvocation, E>
+    + Invokable<AccessControllerAddRecoveryDelegateInvocation, E>
+    + Invokable<AccessControllerRemoveRecoveryDelegateInvocation, E>
+    + Invokable<AccessControllerGetConfigInvocation, E>
+    + Invokable<AccessControllerGetDefaultRuleInvocation, E>
+    + Invokable<AccessControllerPauseInvocation, E>
+    + Invokable<AccessControllerResumeInvocation, E>
+    + Invokable<AccessControllerGetTimingInfoInvocation, E>
+    + Invokable<AccessControllerGetProposalAuthorsInvocation, E>
+    + Invokable<AccessControllerGetTimelineInvocation, E>
 {
 }
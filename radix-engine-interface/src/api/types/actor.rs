@@ -221,6 +221,7 @@ pub enum ComponentFn {
     ClaimRoyalty,
     Globalize,
     GlobalizeWithOwner,
+    GlobalizeWithAccessRules,
 }
 
 #[derive(
@@ -495,6 +496,7 @@ pub enum AuthZoneStackFn {
     CreateProofByIds,
     Clear,
     Drain,
+    List,
     AssertAccessRule,
 }
 
@@ -534,6 +536,8 @@ pub enum ResourceManagerFn {
     GetNonFungible,
     GetResourceType,
     GetTotalSupply,
+    GetBehavior,
+    IsFrozen,
     NonFungibleExists,
     CreateBucket,
     CreateVault,
@@ -627,9 +631,14 @@ pub enum VaultFn {
 #[strum(serialize_all = "snake_case")]
 pub enum ProofFn {
     Clone,
+    MakeOneShot,
+    IntoResourceProof,
     GetAmount,
     GetNonFungibleLocalIds,
     GetResourceAddress,
+    ValidateAgainstStoredRule,
+    SatisfiedRoles,
+    IsPresence,
 }
 
 #[derive(
@@ -856,12 +865,14 @@ pub enum AccessControllerFn {
     CreateGlobal,
 
     CreateProof,
+    CreateProofs,
 
     InitiateRecoveryAsPrimary,
     InitiateRecoveryAsRecovery,
 
     QuickConfirmPrimaryRoleRecoveryProposal,
     QuickConfirmRecoveryRoleRecoveryProposal,
+    BatchConfirmRecovery,
 
     TimedConfirmRecovery,
 
@@ -872,6 +883,38 @@ pub enum AccessControllerFn {
     UnlockPrimaryRole,
 
     StopTimedRecovery,
+    ExtendTimedRecovery,
+
+    GetRoleRule,
+
+    EndorseRecoveryRoleRecoveryProposal,
+
+    ExportState,
+
+    GetControlledResourceManager,
+    VerifyControlledAsset,
+    GetControlledVaults,
+
+    ValidateProposal,
+
+    TransferOwnership,
+
+    CheckRole,
+    CanActAs,
+
+    InitiateRecoveryAsDelegate,
+    AddRecoveryDelegate,
+    RemoveRecoveryDelegate,
+
+    GetConfig,
+    GetDefaultRule,
+
+    Pause,
+    Resume,
+
+    GetTimingInfo,
+    GetProposalAuthors,
+    GetTimeline,
 }
 
 pub struct AccessControllerPackage;
@@ -895,6 +938,13 @@ impl AccessControllerPackage {
                     receiver,
                 })
             }
+            AccessControllerFn::CreateProofs => {
+                scrypto_decode::<AccessControllerCreateProofsMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::CreateProofs(AccessControllerCreateProofsInvocation {
+                    receiver,
+                })
+            }
             AccessControllerFn::InitiateRecoveryAsPrimary => {
                 let args =
                     scrypto_decode::<AccessControllerInitiateRecoveryAsPrimaryMethodArgs>(args)
@@ -935,6 +985,7 @@ impl AccessControllerPackage {
                             rule_set: args.rule_set,
                             timed_recovery_delay_in_minutes: args.timed_recovery_delay_in_minutes,
                         },
+                        proposal_nonce: args.proposal_nonce,
                     },
                 )
             }
@@ -950,6 +1001,23 @@ impl AccessControllerPackage {
                             rule_set: args.rule_set,
                             timed_recovery_delay_in_minutes: args.timed_recovery_delay_in_minutes,
                         },
+                        proposal_nonce: args.proposal_nonce,
+                    },
+                )
+            }
+            AccessControllerFn::BatchConfirmRecovery => {
+                let args = scrypto_decode::<AccessControllerBatchConfirmRecoveryMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::BatchConfirmRecovery(
+                    AccessControllerBatchConfirmRecoveryInvocation {
+                        receiver,
+                        proposer: args.proposer,
+                        confirmors: args.confirmors,
+                        proposal_to_confirm: RecoveryProposal {
+                            rule_set: args.rule_set,
+                            timed_recovery_delay_in_minutes: args.timed_recovery_delay_in_minutes,
+                        },
+                        proposal_nonce: args.proposal_nonce,
                     },
                 )
             }
@@ -963,6 +1031,7 @@ impl AccessControllerPackage {
                             rule_set: args.rule_set,
                             timed_recovery_delay_in_minutes: args.timed_recovery_delay_in_minutes,
                         },
+                        proposal_nonce: args.proposal_nonce,
                     },
                 )
             }
@@ -1009,6 +1078,193 @@ impl AccessControllerPackage {
                     },
                 )
             }
+            AccessControllerFn::ExtendTimedRecovery => {
+                let args = scrypto_decode::<AccessControllerExtendTimedRecoveryMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::ExtendTimedRecovery(
+                    AccessControllerExtendTimedRecoveryInvocation {
+                        receiver,
+                        additional_minutes: args.additional_minutes,
+                    },
+                )
+            }
+            AccessControllerFn::GetRoleRule => {
+                let args = scrypto_decode::<AccessControllerGetRoleRuleMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::GetRoleRule(AccessControllerGetRoleRuleInvocation {
+                    receiver,
+                    role: args.role,
+                })
+            }
+            AccessControllerFn::EndorseRecoveryRoleRecoveryProposal => {
+                let args = scrypto_decode::<
+                    AccessControllerEndorseRecoveryRoleRecoveryProposalMethodArgs,
+                >(args)
+                .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::EndorseRecoveryRoleRecoveryProposal(
+                    AccessControllerEndorseRecoveryRoleRecoveryProposalInvocation {
+                        receiver,
+                        proposal_to_endorse: RecoveryProposal {
+                            rule_set: args.rule_set,
+                            timed_recovery_delay_in_minutes: args.timed_recovery_delay_in_minutes,
+                        },
+                        endorser: args.endorser,
+                    },
+                )
+            }
+            AccessControllerFn::ExportState => {
+                scrypto_decode::<AccessControllerExportStateMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::ExportState(AccessControllerExportStateInvocation {
+                    receiver,
+                })
+            }
+            AccessControllerFn::GetControlledResourceManager => {
+                scrypto_decode::<AccessControllerGetControlledResourceManagerMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::GetControlledResourceManager(
+                    AccessControllerGetControlledResourceManagerInvocation { receiver },
+                )
+            }
+            AccessControllerFn::VerifyControlledAsset => {
+                let args = scrypto_decode::<AccessControllerVerifyControlledAssetMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::VerifyControlledAsset(
+                    AccessControllerVerifyControlledAssetInvocation {
+                        receiver,
+                        expected_resource: args.expected_resource,
+                        expected_amount: args.expected_amount,
+                    },
+                )
+            }
+            AccessControllerFn::GetControlledVaults => {
+                scrypto_decode::<AccessControllerGetControlledVaultsMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::GetControlledVaults(
+                    AccessControllerGetControlledVaultsInvocation { receiver },
+                )
+            }
+            AccessControllerFn::ValidateProposal => {
+                let args = scrypto_decode::<AccessControllerValidateProposalMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::ValidateProposal(
+                    AccessControllerValidateProposalInvocation {
+                        receiver,
+                        proposer: args.proposer,
+                        proposal: RecoveryProposal {
+                            rule_set: args.rule_set,
+                            timed_recovery_delay_in_minutes: args.timed_recovery_delay_in_minutes,
+                        },
+                    },
+                )
+            }
+            AccessControllerFn::TransferOwnership => {
+                let args = scrypto_decode::<AccessControllerTransferOwnershipMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::TransferOwnership(
+                    AccessControllerTransferOwnershipInvocation {
+                        receiver,
+                        rule_set: args.rule_set,
+                    },
+                )
+            }
+            AccessControllerFn::CheckRole => {
+                let args = scrypto_decode::<AccessControllerCheckRoleMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::CheckRole(AccessControllerCheckRoleInvocation {
+                    receiver,
+                    proof: args.proof,
+                    role: args.role,
+                })
+            }
+            AccessControllerFn::CanActAs => {
+                let args = scrypto_decode::<AccessControllerCanActAsMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::CanActAs(AccessControllerCanActAsInvocation {
+                    receiver,
+                    non_fungible_global_id: args.non_fungible_global_id,
+                    role: args.role,
+                })
+            }
+            AccessControllerFn::InitiateRecoveryAsDelegate => {
+                let args =
+                    scrypto_decode::<AccessControllerInitiateRecoveryAsDelegateMethodArgs>(args)
+                        .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::InitiateRecoveryAsDelegate(
+                    AccessControllerInitiateRecoveryAsDelegateInvocation {
+                        receiver,
+                        proposal: RecoveryProposal {
+                            rule_set: args.rule_set,
+                            timed_recovery_delay_in_minutes: args.timed_recovery_delay_in_minutes,
+                        },
+                    },
+                )
+            }
+            AccessControllerFn::AddRecoveryDelegate => {
+                let args = scrypto_decode::<AccessControllerAddRecoveryDelegateMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::AddRecoveryDelegate(
+                    AccessControllerAddRecoveryDelegateInvocation {
+                        receiver,
+                        delegate: args.delegate,
+                    },
+                )
+            }
+            AccessControllerFn::RemoveRecoveryDelegate => {
+                let args = scrypto_decode::<AccessControllerRemoveRecoveryDelegateMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::RemoveRecoveryDelegate(
+                    AccessControllerRemoveRecoveryDelegateInvocation {
+                        receiver,
+                        delegate: args.delegate,
+                    },
+                )
+            }
+            AccessControllerFn::GetConfig => {
+                scrypto_decode::<AccessControllerGetConfigMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::GetConfig(AccessControllerGetConfigInvocation {
+                    receiver,
+                })
+            }
+            AccessControllerFn::GetDefaultRule => {
+                scrypto_decode::<AccessControllerGetDefaultRuleMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::GetDefaultRule(
+                    AccessControllerGetDefaultRuleInvocation { receiver },
+                )
+            }
+            AccessControllerFn::Pause => {
+                scrypto_decode::<AccessControllerPauseMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::Pause(AccessControllerPauseInvocation { receiver })
+            }
+            AccessControllerFn::Resume => {
+                scrypto_decode::<AccessControllerResumeMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::Resume(AccessControllerResumeInvocation { receiver })
+            }
+            AccessControllerFn::GetTimingInfo => {
+                scrypto_decode::<AccessControllerGetTimingInfoMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::GetTimingInfo(AccessControllerGetTimingInfoInvocation {
+                    receiver,
+                })
+            }
+            AccessControllerFn::GetProposalAuthors => {
+                scrypto_decode::<AccessControllerGetProposalAuthorsMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::GetProposalAuthors(
+                    AccessControllerGetProposalAuthorsInvocation { receiver },
+                )
+            }
+            AccessControllerFn::GetTimeline => {
+                scrypto_decode::<AccessControllerGetTimelineMethodArgs>(args)
+                    .map_err(ResolveError::DecodeError)?;
+                AccessControllerInvocation::GetTimeline(AccessControllerGetTimelineInvocation {
+                    receiver,
+                })
+            }
         };
 
         Ok(invocation)
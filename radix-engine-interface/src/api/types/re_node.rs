@@ -3,7 +3,7 @@ use crate::model::*;
 use crate::*;
 
 // TODO: Remove and replace with real HeapRENodes
-#[derive(Debug, Clone, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+#[derive(Debug, Clone, ScryptoCategorize, ScryptoEncode, ScryptoDecode, ScryptoDescribe)]
 pub enum ScryptoRENode {
     Component(PackageAddress, String, Vec<u8>),
     KeyValueStore,
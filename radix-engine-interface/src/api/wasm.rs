@@ -1,6 +1,8 @@
+use crate::api::types::ScryptoRENode;
 use crate::api::Invocation;
-use crate::data::ScryptoDecode;
-use crate::model::CallTableInvocation;
+use crate::data::{ScryptoCustomTypeKind, ScryptoDecode, ScryptoSchema};
+use crate::model::{CallTableInvocation, ScryptoInvocation};
+use sbor::{generate_full_schema, GlobalTypeId, TypeAggregator};
 
 pub trait SerializableInvocation:
     Into<CallTableInvocation> + Invocation<Output = Self::ScryptoOutput>
@@ -8,6 +10,18 @@ pub trait SerializableInvocation:
     type ScryptoOutput: ScryptoDecode;
 }
 
+/// Generates the schema of the engine inputs which Scrypto encodes for the wasm engine, ie the
+/// Scrypto invocation and the RE node to create, so that clients in other languages can generate
+/// encoders for them. The types can be looked up with [`ScryptoSchema::index_by_name`].
+///
+/// Native invocations aren't covered, as they're yet to be given schemas.
+pub fn generate_engine_input_schema() -> ScryptoSchema {
+    let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind<GlobalTypeId>>::new();
+    aggregator.add_child_type_and_descendents::<ScryptoInvocation>();
+    aggregator.add_child_type_and_descendents::<ScryptoRENode>();
+    generate_full_schema(aggregator)
+}
+
 pub type BufferId = u32;
 
 #[repr(C)]
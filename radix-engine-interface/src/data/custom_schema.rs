@@ -1,10 +1,27 @@
 use super::*;
+use crate::crypto::{hash, Hash};
 use sbor::rust::collections::*;
 use sbor::*;
 
 pub type ScryptoTypeKind<L> = TypeKind<ScryptoCustomValueKind, ScryptoCustomTypeKind<L>, L>;
 pub type ScryptoSchema = Schema<ScryptoCustomTypeExtension>;
 
+/// Content addressing of schemas, so that structurally identical schemas can be cached and
+/// deduplicated.
+pub trait SchemaContentHash {
+    /// Hashes the canonical encoding of the schema's type kinds and type metadata. Two schemas
+    /// have the same content hash if and only if they are equal.
+    fn content_hash(&self) -> Hash;
+}
+
+impl SchemaContentHash for ScryptoSchema {
+    fn content_hash(&self) -> Hash {
+        let encoded = scrypto_encode(&(&self.type_kinds, &self.type_metadata))
+            .expect("Schema should always be encodable");
+        hash(encoded)
+    }
+}
+
 /// A schema for the values that a codec can decode / views as valid
 #[derive(Debug, Clone, PartialEq, Eq, Categorize, Encode, Decode)]
 pub enum ScryptoCustomTypeKind<L: SchemaTypeLink> {
@@ -144,6 +161,46 @@ impl CustomTypeExtension for ScryptoCustomTypeExtension {
 
 use well_known_scrypto_types::*;
 
+macro_rules! describe_scrypto_well_known_type {
+    ($type:ty, $well_known_index:ident) => {
+        impl Describe<ScryptoCustomTypeKind<GlobalTypeId>> for $type {
+            const TYPE_ID: GlobalTypeId = GlobalTypeId::well_known($well_known_index);
+        }
+    };
+}
+
+describe_scrypto_well_known_type!(crate::model::PackageAddress, PACKAGE_ADDRESS_ID);
+describe_scrypto_well_known_type!(crate::model::ComponentAddress, COMPONENT_ADDRESS_ID);
+describe_scrypto_well_known_type!(crate::model::ResourceAddress, RESOURCE_ADDRESS_ID);
+
+describe_scrypto_well_known_type!(types::Own, OWN_ID);
+
+describe_scrypto_well_known_type!(types::ManifestBlobRef, BLOB_ID);
+describe_scrypto_well_known_type!(types::ManifestBucket, BUCKET_ID);
+describe_scrypto_well_known_type!(types::ManifestProof, PROOF_ID);
+describe_scrypto_well_known_type!(types::ManifestExpression, EXPRESSION_ID);
+
+describe_scrypto_well_known_type!(crate::crypto::Hash, HASH_ID);
+describe_scrypto_well_known_type!(
+    crate::crypto::EcdsaSecp256k1PublicKey,
+    ECDSA_SECP256K1_PUBLIC_KEY_ID
+);
+describe_scrypto_well_known_type!(
+    crate::crypto::EcdsaSecp256k1Signature,
+    ECDSA_SECP256K1_SIGNATURE_ID
+);
+describe_scrypto_well_known_type!(
+    crate::crypto::EddsaEd25519PublicKey,
+    EDDSA_ED25519_PUBLIC_KEY_ID
+);
+describe_scrypto_well_known_type!(
+    crate::crypto::EddsaEd25519Signature,
+    EDDSA_ED25519_SIGNATURE_ID
+);
+describe_scrypto_well_known_type!(crate::math::Decimal, DECIMAL_ID);
+describe_scrypto_well_known_type!(crate::math::PreciseDecimal, PRECISE_DECIMAL_ID);
+describe_scrypto_well_known_type!(crate::model::NonFungibleLocalId, NON_FUNGIBLE_LOCAL_ID_ID);
+
 mod well_known_scrypto_types {
     use super::*;
 
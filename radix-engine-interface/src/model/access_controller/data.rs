@@ -1,5 +1,11 @@
 use crate::api::types::AccessRule;
+use crate::math::Decimal;
+use crate::model::{require, NonFungibleGlobalId, ResourceAddress, ResourceType};
+use crate::rule;
+use crate::time::Instant;
 use crate::*;
+use sbor::rust::collections::{BTreeMap, BTreeSet};
+use sbor::rust::vec::Vec;
 
 /// An enum of the roles in the Access Controller component
 #[derive(
@@ -59,6 +65,100 @@ pub struct RuleSet {
     pub confirmation_role: AccessRule,
 }
 
+impl RuleSet {
+    pub fn builder() -> RuleSetBuilder {
+        RuleSetBuilder::new()
+    }
+
+    /// Creates a rule set in which each role requires a proof of its own non-fungible badge.
+    pub fn from_badges(
+        primary: NonFungibleGlobalId,
+        recovery: NonFungibleGlobalId,
+        confirmation: NonFungibleGlobalId,
+    ) -> Self {
+        Self {
+            primary_role: rule!(require(primary)),
+            recovery_role: rule!(require(recovery)),
+            confirmation_role: rule!(require(confirmation)),
+        }
+    }
+}
+
+/// An error produced when building a [`RuleSet`] without setting the rule of all of the roles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleSetBuilderError {
+    MissingRoleRule(Role),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleSetBuilder {
+    primary_role: Option<AccessRule>,
+    recovery_role: Option<AccessRule>,
+    confirmation_role: Option<AccessRule>,
+}
+
+impl RuleSetBuilder {
+    pub fn new() -> Self {
+        Self {
+            primary_role: None,
+            recovery_role: None,
+            confirmation_role: None,
+        }
+    }
+
+    pub fn primary(mut self, rule: AccessRule) -> Self {
+        self.primary_role = Some(rule);
+        self
+    }
+
+    pub fn recovery(mut self, rule: AccessRule) -> Self {
+        self.recovery_role = Some(rule);
+        self
+    }
+
+    pub fn confirmation(mut self, rule: AccessRule) -> Self {
+        self.confirmation_role = Some(rule);
+        self
+    }
+
+    pub fn build(self) -> Result<RuleSet, RuleSetBuilderError> {
+        Ok(RuleSet {
+            primary_role: self
+                .primary_role
+                .ok_or(RuleSetBuilderError::MissingRoleRule(Role::Primary))?,
+            recovery_role: self
+                .recovery_role
+                .ok_or(RuleSetBuilderError::MissingRoleRule(Role::Recovery))?,
+            confirmation_role: self
+                .confirmation_role
+                .ok_or(RuleSetBuilderError::MissingRoleRule(Role::Confirmation))?,
+        })
+    }
+}
+
+/// The optional behaviours of an access controller which are chosen when it is created. The
+/// [`Default`] settings leave all of them disabled.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerSettings {
+    /// Whether a recovery proposal is prevented from being quick-confirmed by the entity that
+    /// proposed it.
+    pub prevent_self_confirmation: bool,
+
+    /// The amount of time (in minutes) between the primary role being locked and the lock taking
+    /// effect. When this is [`None`], locking the primary role takes effect immediately.
+    pub primary_role_lock_grace_period_in_minutes: Option<u32>,
+
+    /// The number of distinct endorsements that a recovery proposal of the recovery role needs
+    /// before it can be confirmed. When this is [`None`], no endorsements are needed.
+    pub recovery_endorsement_threshold: Option<u8>,
+
+    /// The minimum amount of time (in minutes) that must pass between two successful recoveries.
+    pub minimum_recovery_interval_in_minutes: Option<u32>,
+
+    /// Whether the proofs created by the access controller are one-shot.
+    pub one_shot_proofs: bool,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct RecoveryProposal {
     /// The set of rules being proposed for the different roles.
@@ -67,3 +167,227 @@ pub struct RecoveryProposal {
     /// The proposed delay of timed recoveries.
     pub timed_recovery_delay_in_minutes: Option<u32>,
 }
+
+/// The full logical state of an access controller at a point in time, as returned when exporting
+/// its state for backups and migrations.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerStateSnapshot {
+    /// The rule set currently applied to the access controller.
+    pub rule_set: RuleSet,
+
+    /// The resource address of the asset held in the first controlled vault.
+    pub controlled_asset_resource_address: ResourceAddress,
+
+    /// The amount of the asset held in the first controlled vault.
+    pub controlled_asset_amount: Decimal,
+
+    /// The resource address and amount of the asset held in each controlled vault, in the order
+    /// the vaults were created in.
+    pub controlled_assets: Vec<(ResourceAddress, Decimal)>,
+
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+    pub prevent_self_confirmation: bool,
+    pub primary_role_lock_grace_period_in_minutes: Option<u32>,
+    pub recovery_endorsement_threshold: Option<u8>,
+    pub minimum_recovery_interval_in_minutes: Option<u32>,
+    pub one_shot_proofs: bool,
+
+    /// The instant at which the last recovery proposal was confirmed, if any.
+    pub last_recovery_at: Option<Instant>,
+
+    /// Whether all recovery activity on the controller is paused.
+    pub is_paused: bool,
+
+    /// Whether the primary role is currently locked, which includes a pending lock whose grace
+    /// period has elapsed.
+    pub primary_role_locked: bool,
+
+    /// When a lock of the primary role is pending, the instant at which it takes or took effect.
+    pub primary_role_locks_at: Option<Instant>,
+
+    /// The recovery proposal of the primary role, if one is underway.
+    pub primary_role_recovery_proposal: Option<RecoveryProposal>,
+
+    /// The recovery proposal of the recovery role, if one is underway.
+    pub recovery_role_recovery_proposal: Option<RecoveryProposal>,
+
+    /// When the recovery proposal of the recovery role is timed, the instant after which it can be
+    /// confirmed.
+    pub timed_recovery_allowed_after: Option<Instant>,
+
+    /// The global ids of the non-fungibles which have endorsed the recovery proposal of the
+    /// recovery role.
+    pub recovery_proposal_endorsements: BTreeSet<NonFungibleGlobalId>,
+
+    /// The global ids of the non-fungibles whose holders may initiate recovery proposals in the
+    /// slot of the recovery role.
+    pub recovery_delegates: BTreeSet<NonFungibleGlobalId>,
+
+    /// The nonce which confirmations of the recovery proposal of each proposer must reference.
+    /// Proposers which have never had a proposal cancelled or confirmed are absent, with a nonce
+    /// of zero.
+    pub recovery_proposal_nonces: BTreeMap<Proposer, u64>,
+
+    /// The global id of the non-fungible which authored the recovery proposal of each proposer.
+    /// Proposers without a proposal underway, or whose author couldn't be singled out, are absent.
+    pub proposal_authors: BTreeMap<Proposer, NonFungibleGlobalId>,
+
+    /// The instant at which the recovery proposal of each proposer was initiated. Proposers
+    /// without a proposal underway are absent.
+    pub proposal_initiated_at: BTreeMap<Proposer, Instant>,
+}
+
+/// The outcome of validating whether a proposer could initiate a recovery on an access controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum ProposalValidationOutcome {
+    Accepted,
+    Rejected(ProposalRejectionReason),
+}
+
+/// The reasons for which initiating a recovery would be rejected by an access controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum ProposalRejectionReason {
+    /// All recovery activity on the controller is paused.
+    ControllerPaused,
+
+    /// The proposer already has a recovery proposal underway.
+    RecoveryAlreadyExistsForProposer,
+
+    /// The proposal shortens the delay of timed recoveries currently in effect.
+    DelayDowngradeNotAllowed,
+
+    /// Computing when a timed recovery would be allowed overflows the current time.
+    TimeOverflow,
+
+    /// The controller already has the maximum number of recovery proposals outstanding.
+    TooManyProposals,
+}
+
+/// Information on the resource manager of the asset held in an access controller's vault.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ControlledResourceManagerInfo {
+    pub resource_address: ResourceAddress,
+    pub resource_type: ResourceType,
+
+    /// Whether the resource manager's mint rule is anything other than deny all.
+    pub mintable: bool,
+
+    /// Whether the resource manager's burn rule is anything other than deny all.
+    pub burnable: bool,
+}
+
+/// The configuration of an access controller, read in a single call.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerConfig {
+    /// The rule set currently applied to the access controller.
+    pub rule_set: RuleSet,
+
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+
+    /// Whether the primary role is currently locked.
+    pub primary_role_locked: bool,
+
+    /// Whether recovery proposals of the recovery role can be timed-confirmed.
+    pub timed_recovery_enabled: bool,
+}
+
+/// The default access rule of an access controller, which applies to any method without a rule of
+/// its own, together with the rule that controls who may change the access rules.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerDefaultRule {
+    pub default_rule: AccessRule,
+    pub default_rule_mutability: AccessRule,
+}
+
+/// The timing of an access controller's recoveries relative to the current time, read in a single
+/// call. All durations are in whole minutes from the current time, and are zero for anything that
+/// is already allowed.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerTimingInfo {
+    /// The minutes until the minimum recovery interval since the last recovery elapses, after
+    /// which a recovery can be confirmed again.
+    pub minutes_until_recovery_allowed: u64,
+
+    /// For each proposer with a recovery underway, the minutes until its proposal could be
+    /// timed-confirmed, accounting for the minimum recovery interval. [`None`] when the proposal
+    /// can't be timed-confirmed at all.
+    pub minutes_until_timed_confirm: BTreeMap<Proposer, Option<u64>>,
+}
+
+/// The points in time which make up the timeline of an access controller's recoveries, e.g. for a
+/// wallet to draw. Unlike [`AccessControllerTimingInfo`], these are absolute instants.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerTimeline {
+    /// The instant until which no recovery can be confirmed, as the minimum recovery interval since
+    /// the last recovery hasn't elapsed yet. [`None`] when a recovery can be confirmed right away.
+    pub cooldown_until: Option<Instant>,
+
+    /// The timeline of the recovery proposal of each proposer with a recovery underway.
+    pub proposals: BTreeMap<Proposer, AccessControllerProposalTimeline>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerProposalTimeline {
+    /// The instant at which the proposal was initiated, or [`None`] if it wasn't recorded.
+    pub initiated_at: Option<Instant>,
+
+    /// The earliest instant at which the proposal could be timed-confirmed, accounting for the
+    /// cooldown. [`None`] when the proposal can't be timed-confirmed at all.
+    pub earliest_timed_confirm_at: Option<Instant>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{AccessRuleNode, NonFungibleLocalId, ProofRule, SoftResourceOrNonFungible};
+
+    #[test]
+    fn rule_set_builder_sets_all_role_rules() {
+        let rule_set = RuleSet::builder()
+            .primary(AccessRule::AllowAll)
+            .recovery(AccessRule::DenyAll)
+            .confirmation(AccessRule::AllowAll)
+            .build()
+            .unwrap();
+
+        assert_eq!(rule_set.primary_role, AccessRule::AllowAll);
+        assert_eq!(rule_set.recovery_role, AccessRule::DenyAll);
+        assert_eq!(rule_set.confirmation_role, AccessRule::AllowAll);
+    }
+
+    #[test]
+    fn rule_set_builder_fails_when_a_role_rule_is_missing() {
+        let result = RuleSet::builder()
+            .primary(AccessRule::AllowAll)
+            .confirmation(AccessRule::AllowAll)
+            .build();
+
+        assert_eq!(
+            result,
+            Err(RuleSetBuilderError::MissingRoleRule(Role::Recovery))
+        );
+    }
+
+    #[test]
+    fn rule_set_from_badges_requires_each_role_badge() {
+        let badge = |id: u64| {
+            NonFungibleGlobalId::new(
+                ResourceAddress::Normal([id as u8; 26]),
+                NonFungibleLocalId::integer(id),
+            )
+        };
+        let (primary, recovery, confirmation) = (badge(1), badge(2), badge(3));
+
+        let rule_set =
+            RuleSet::from_badges(primary.clone(), recovery.clone(), confirmation.clone());
+
+        let requires = |badge: NonFungibleGlobalId| {
+            AccessRule::Protected(AccessRuleNode::ProofRule(ProofRule::Require(
+                SoftResourceOrNonFungible::StaticNonFungible(badge),
+            )))
+        };
+        assert_eq!(rule_set.primary_role, requires(primary));
+        assert_eq!(rule_set.recovery_role, requires(recovery));
+        assert_eq!(rule_set.confirmation_role, requires(confirmation));
+    }
+}
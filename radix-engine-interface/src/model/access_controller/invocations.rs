@@ -1,8 +1,12 @@
+use sbor::rust::collections::BTreeMap;
 use sbor::rust::fmt::Debug;
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
 
-use crate::api::types::BucketId;
+use crate::api::types::{BucketId, VaultId};
 use crate::api::wasm::*;
 use crate::api::*;
+use crate::math::Decimal;
 use crate::model::*;
 use crate::*;
 
@@ -12,9 +16,29 @@ use crate::*;
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct AccessControllerCreateGlobalInvocation {
-    pub controlled_asset: BucketId,
+    /// The assets to control, each of which is put in a vault of its own. There must be at least
+    /// one, and no bucket can be given more than once.
+    pub controlled_assets: Vec<BucketId>,
     pub rule_set: RuleSet,
     pub timed_recovery_delay_in_minutes: Option<u32>,
+    pub settings: AccessControllerSettings,
+}
+
+impl AccessControllerCreateGlobalInvocation {
+    /// Creates the invocation for an access controller which controls a single asset.
+    pub fn with_single_asset(
+        controlled_asset: BucketId,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+        settings: AccessControllerSettings,
+    ) -> Self {
+        Self {
+            controlled_assets: vec![controlled_asset],
+            rule_set,
+            timed_recovery_delay_in_minutes,
+            settings,
+        }
+    }
 }
 
 impl Invocation for AccessControllerCreateGlobalInvocation {
@@ -38,6 +62,8 @@ impl Into<CallTableInvocation> for AccessControllerCreateGlobalInvocation {
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct AccessControllerCreateProofMethodArgs;
 
+/// Creates a proof of the first controlled asset, which is the asset given first when the
+/// controller was created.
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct AccessControllerCreateProofInvocation {
     pub receiver: ComponentAddress,
@@ -57,6 +83,33 @@ impl Into<CallTableInvocation> for AccessControllerCreateProofInvocation {
     }
 }
 
+//=================================
+// Access Controller Create Proofs
+//=================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerCreateProofsMethodArgs;
+
+/// Creates a proof of each of the controlled assets, in the order their vaults were created in.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerCreateProofsInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerCreateProofsInvocation {
+    type Output = Vec<Proof>;
+}
+
+impl SerializableInvocation for AccessControllerCreateProofsInvocation {
+    type ScryptoOutput = Vec<Proof>;
+}
+
+impl Into<CallTableInvocation> for AccessControllerCreateProofsInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::CreateProofs(self)).into()
+    }
+}
+
 //================================================
 // Access Controller Initiate Recovery As Primary
 //================================================
@@ -131,12 +184,14 @@ impl Into<CallTableInvocation> for AccessControllerInitiateRecoveryAsRecoveryInv
 pub struct AccessControllerQuickConfirmPrimaryRoleRecoveryProposalMethodArgs {
     pub rule_set: RuleSet,
     pub timed_recovery_delay_in_minutes: Option<u32>,
+    pub proposal_nonce: u64,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation {
     pub receiver: ComponentAddress,
     pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
 }
 
 impl Invocation for AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation {
@@ -166,12 +221,14 @@ impl Into<CallTableInvocation>
 pub struct AccessControllerQuickConfirmRecoveryRoleRecoveryProposalMethodArgs {
     pub rule_set: RuleSet,
     pub timed_recovery_delay_in_minutes: Option<u32>,
+    pub proposal_nonce: u64,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation {
     pub receiver: ComponentAddress,
     pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
 }
 
 impl Invocation for AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation {
@@ -193,6 +250,43 @@ impl Into<CallTableInvocation>
     }
 }
 
+//=========================================
+// Access Controller Batch Confirm Recovery
+//=========================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerBatchConfirmRecoveryMethodArgs {
+    pub proposer: Proposer,
+    pub confirmors: Vec<Role>,
+    pub rule_set: RuleSet,
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+    pub proposal_nonce: u64,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerBatchConfirmRecoveryInvocation {
+    pub receiver: ComponentAddress,
+    pub proposer: Proposer,
+    pub confirmors: Vec<Role>,
+    pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
+}
+
+impl Invocation for AccessControllerBatchConfirmRecoveryInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerBatchConfirmRecoveryInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for AccessControllerBatchConfirmRecoveryInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::BatchConfirmRecovery(self))
+            .into()
+    }
+}
+
 //=================================
 // Access Controller Timed Confirm
 //=================================
@@ -201,12 +295,14 @@ impl Into<CallTableInvocation>
 pub struct AccessControllerTimedConfirmRecoveryMethodArgs {
     pub rule_set: RuleSet,
     pub timed_recovery_delay_in_minutes: Option<u32>,
+    pub proposal_nonce: u64,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct AccessControllerTimedConfirmRecoveryInvocation {
     pub receiver: ComponentAddress,
     pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
 }
 
 impl Invocation for AccessControllerTimedConfirmRecoveryInvocation {
@@ -365,3 +461,626 @@ impl Into<CallTableInvocation> for AccessControllerStopTimedRecoveryInvocation {
             .into()
     }
 }
+
+//=========================================
+// Access Controller Extend Timed Recovery
+//=========================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerExtendTimedRecoveryMethodArgs {
+    pub additional_minutes: i64,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerExtendTimedRecoveryInvocation {
+    pub receiver: ComponentAddress,
+    pub additional_minutes: i64,
+}
+
+impl Invocation for AccessControllerExtendTimedRecoveryInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerExtendTimedRecoveryInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for AccessControllerExtendTimedRecoveryInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::ExtendTimedRecovery(self))
+            .into()
+    }
+}
+
+//=================================
+// Access Controller Get Role Rule
+//=================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetRoleRuleMethodArgs {
+    pub role: Role,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetRoleRuleInvocation {
+    pub receiver: ComponentAddress,
+    pub role: Role,
+}
+
+impl Invocation for AccessControllerGetRoleRuleInvocation {
+    type Output = AccessRule;
+}
+
+impl SerializableInvocation for AccessControllerGetRoleRuleInvocation {
+    type ScryptoOutput = AccessRule;
+}
+
+impl Into<CallTableInvocation> for AccessControllerGetRoleRuleInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::GetRoleRule(self)).into()
+    }
+}
+
+//============================================
+// Access Controller Endorse Recovery Proposal
+//============================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerEndorseRecoveryRoleRecoveryProposalMethodArgs {
+    pub rule_set: RuleSet,
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+    pub endorser: NonFungibleGlobalId,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerEndorseRecoveryRoleRecoveryProposalInvocation {
+    pub receiver: ComponentAddress,
+    pub proposal_to_endorse: RecoveryProposal,
+    pub endorser: NonFungibleGlobalId,
+}
+
+impl Invocation for AccessControllerEndorseRecoveryRoleRecoveryProposalInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerEndorseRecoveryRoleRecoveryProposalInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for AccessControllerEndorseRecoveryRoleRecoveryProposalInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(
+            AccessControllerInvocation::EndorseRecoveryRoleRecoveryProposal(self),
+        )
+        .into()
+    }
+}
+
+//===============================
+// Access Controller Export State
+//===============================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerExportStateMethodArgs;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerExportStateInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerExportStateInvocation {
+    type Output = AccessControllerStateSnapshot;
+}
+
+impl SerializableInvocation for AccessControllerExportStateInvocation {
+    type ScryptoOutput = AccessControllerStateSnapshot;
+}
+
+impl Into<CallTableInvocation> for AccessControllerExportStateInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::ExportState(self)).into()
+    }
+}
+
+//==================================================
+// Access Controller Get Controlled Resource Manager
+//==================================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetControlledResourceManagerMethodArgs;
+
+/// Describes the resource manager of the first controlled asset, which is the asset given first
+/// when the controller was created.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetControlledResourceManagerInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerGetControlledResourceManagerInvocation {
+    type Output = ControlledResourceManagerInfo;
+}
+
+impl SerializableInvocation for AccessControllerGetControlledResourceManagerInvocation {
+    type ScryptoOutput = ControlledResourceManagerInfo;
+}
+
+impl Into<CallTableInvocation> for AccessControllerGetControlledResourceManagerInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(
+            AccessControllerInvocation::GetControlledResourceManager(self),
+        )
+        .into()
+    }
+}
+
+//===========================================
+// Access Controller Verify Controlled Asset
+//===========================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerVerifyControlledAssetMethodArgs {
+    pub expected_resource: ResourceAddress,
+    pub expected_amount: Decimal,
+}
+
+/// Checks that the controlled vaults hold exactly `expected_amount` of `expected_resource` in
+/// total. Fails verification when none of the vaults holds `expected_resource`.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerVerifyControlledAssetInvocation {
+    pub receiver: ComponentAddress,
+    pub expected_resource: ResourceAddress,
+    pub expected_amount: Decimal,
+}
+
+impl Invocation for AccessControllerVerifyControlledAssetInvocation {
+    type Output = bool;
+}
+
+impl SerializableInvocation for AccessControllerVerifyControlledAssetInvocation {
+    type ScryptoOutput = bool;
+}
+
+impl Into<CallTableInvocation> for AccessControllerVerifyControlledAssetInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::VerifyControlledAsset(self))
+            .into()
+    }
+}
+
+//=========================================
+// Access Controller Get Controlled Vaults
+//=========================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetControlledVaultsMethodArgs;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetControlledVaultsInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerGetControlledVaultsInvocation {
+    type Output = Vec<VaultId>;
+}
+
+impl SerializableInvocation for AccessControllerGetControlledVaultsInvocation {
+    type ScryptoOutput = Vec<VaultId>;
+}
+
+impl Into<CallTableInvocation> for AccessControllerGetControlledVaultsInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::GetControlledVaults(self))
+            .into()
+    }
+}
+
+//====================================
+// Access Controller Validate Proposal
+//====================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerValidateProposalMethodArgs {
+    pub proposer: Proposer,
+    pub rule_set: RuleSet,
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerValidateProposalInvocation {
+    pub receiver: ComponentAddress,
+    pub proposer: Proposer,
+    pub proposal: RecoveryProposal,
+}
+
+impl Invocation for AccessControllerValidateProposalInvocation {
+    type Output = ProposalValidationOutcome;
+}
+
+impl SerializableInvocation for AccessControllerValidateProposalInvocation {
+    type ScryptoOutput = ProposalValidationOutcome;
+}
+
+impl Into<CallTableInvocation> for AccessControllerValidateProposalInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::ValidateProposal(self))
+            .into()
+    }
+}
+
+//=====================================
+// Access Controller Transfer Ownership
+//=====================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerTransferOwnershipMethodArgs {
+    pub rule_set: RuleSet,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerTransferOwnershipInvocation {
+    pub receiver: ComponentAddress,
+    pub rule_set: RuleSet,
+}
+
+impl Invocation for AccessControllerTransferOwnershipInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerTransferOwnershipInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for AccessControllerTransferOwnershipInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::TransferOwnership(self))
+            .into()
+    }
+}
+
+//=============================
+// Access Controller Check Role
+//=============================
+
+#[derive(Debug, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerCheckRoleMethodArgs {
+    pub proof: Proof,
+    pub role: Role,
+}
+
+#[derive(Debug, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerCheckRoleInvocation {
+    pub receiver: ComponentAddress,
+    pub proof: Proof,
+    pub role: Role,
+}
+
+impl Clone for AccessControllerCheckRoleInvocation {
+    fn clone(&self) -> Self {
+        Self {
+            receiver: self.receiver,
+            proof: Proof(self.proof.0),
+            role: self.role,
+        }
+    }
+}
+
+impl Invocation for AccessControllerCheckRoleInvocation {
+    type Output = (bool, Proof);
+}
+
+impl SerializableInvocation for AccessControllerCheckRoleInvocation {
+    type ScryptoOutput = (bool, Proof);
+}
+
+impl Into<CallTableInvocation> for AccessControllerCheckRoleInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::CheckRole(self)).into()
+    }
+}
+
+//===============================
+// Access Controller Can Act As
+//===============================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerCanActAsMethodArgs {
+    pub non_fungible_global_id: NonFungibleGlobalId,
+    pub role: Role,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerCanActAsInvocation {
+    pub receiver: ComponentAddress,
+    pub non_fungible_global_id: NonFungibleGlobalId,
+    pub role: Role,
+}
+
+impl Invocation for AccessControllerCanActAsInvocation {
+    type Output = bool;
+}
+
+impl SerializableInvocation for AccessControllerCanActAsInvocation {
+    type ScryptoOutput = bool;
+}
+
+impl Into<CallTableInvocation> for AccessControllerCanActAsInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::CanActAs(self)).into()
+    }
+}
+
+//=================================================
+// Access Controller Initiate Recovery As Delegate
+//=================================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerInitiateRecoveryAsDelegateMethodArgs {
+    pub rule_set: RuleSet,
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerInitiateRecoveryAsDelegateInvocation {
+    pub receiver: ComponentAddress,
+    pub proposal: RecoveryProposal,
+}
+
+impl Invocation for AccessControllerInitiateRecoveryAsDelegateInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerInitiateRecoveryAsDelegateInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for AccessControllerInitiateRecoveryAsDelegateInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::InitiateRecoveryAsDelegate(
+            self,
+        ))
+        .into()
+    }
+}
+
+//=========================================
+// Access Controller Add Recovery Delegate
+//=========================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerAddRecoveryDelegateMethodArgs {
+    pub delegate: NonFungibleGlobalId,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerAddRecoveryDelegateInvocation {
+    pub receiver: ComponentAddress,
+    pub delegate: NonFungibleGlobalId,
+}
+
+impl Invocation for AccessControllerAddRecoveryDelegateInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerAddRecoveryDelegateInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for AccessControllerAddRecoveryDelegateInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::AddRecoveryDelegate(self))
+            .into()
+    }
+}
+
+//============================================
+// Access Controller Remove Recovery Delegate
+//============================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerRemoveRecoveryDelegateMethodArgs {
+    pub delegate: NonFungibleGlobalId,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerRemoveRecoveryDelegateInvocation {
+    pub receiver: ComponentAddress,
+    pub delegate: NonFungibleGlobalId,
+}
+
+impl Invocation for AccessControllerRemoveRecoveryDelegateInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerRemoveRecoveryDelegateInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for AccessControllerRemoveRecoveryDelegateInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::RemoveRecoveryDelegate(self))
+            .into()
+    }
+}
+
+//==============================
+// Access Controller Get Config
+//==============================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetConfigMethodArgs;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetConfigInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerGetConfigInvocation {
+    type Output = AccessControllerConfig;
+}
+
+impl SerializableInvocation for AccessControllerGetConfigInvocation {
+    type ScryptoOutput = AccessControllerConfig;
+}
+
+impl Into<CallTableInvocation> for AccessControllerGetConfigInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::GetConfig(self)).into()
+    }
+}
+
+//====================================
+// Access Controller Get Default Rule
+//====================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetDefaultRuleMethodArgs;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetDefaultRuleInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerGetDefaultRuleInvocation {
+    type Output = AccessControllerDefaultRule;
+}
+
+impl SerializableInvocation for AccessControllerGetDefaultRuleInvocation {
+    type ScryptoOutput = AccessControllerDefaultRule;
+}
+
+impl Into<CallTableInvocation> for AccessControllerGetDefaultRuleInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::GetDefaultRule(self)).into()
+    }
+}
+
+//=========================
+// Access Controller Pause
+//=========================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerPauseMethodArgs;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerPauseInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerPauseInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerPauseInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for AccessControllerPauseInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::Pause(self)).into()
+    }
+}
+
+//==========================
+// Access Controller Resume
+//==========================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerResumeMethodArgs;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerResumeInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerResumeInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for AccessControllerResumeInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for AccessControllerResumeInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::Resume(self)).into()
+    }
+}
+
+//===================================
+// Access Controller Get Timing Info
+//===================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetTimingInfoMethodArgs;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetTimingInfoInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerGetTimingInfoInvocation {
+    type Output = AccessControllerTimingInfo;
+}
+
+impl SerializableInvocation for AccessControllerGetTimingInfoInvocation {
+    type ScryptoOutput = AccessControllerTimingInfo;
+}
+
+impl Into<CallTableInvocation> for AccessControllerGetTimingInfoInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::GetTimingInfo(self)).into()
+    }
+}
+
+//=======================================
+// Access Controller Get Proposal Authors
+//=======================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetProposalAuthorsMethodArgs;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetProposalAuthorsInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerGetProposalAuthorsInvocation {
+    type Output = BTreeMap<Proposer, Option<NonFungibleGlobalId>>;
+}
+
+impl SerializableInvocation for AccessControllerGetProposalAuthorsInvocation {
+    type ScryptoOutput = BTreeMap<Proposer, Option<NonFungibleGlobalId>>;
+}
+
+impl Into<CallTableInvocation> for AccessControllerGetProposalAuthorsInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::GetProposalAuthors(self))
+            .into()
+    }
+}
+
+//================================
+// Access Controller Get Timeline
+//================================
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetTimelineMethodArgs;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AccessControllerGetTimelineInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for AccessControllerGetTimelineInvocation {
+    type Output = AccessControllerTimeline;
+}
+
+impl SerializableInvocation for AccessControllerGetTimelineInvocation {
+    type ScryptoOutput = AccessControllerTimeline;
+}
+
+impl Into<CallTableInvocation> for AccessControllerGetTimelineInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AccessController(AccessControllerInvocation::GetTimeline(self)).into()
+    }
+}
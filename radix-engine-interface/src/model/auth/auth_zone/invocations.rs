@@ -82,6 +82,9 @@ pub struct AuthZoneCreateProofByAmountInvocation {
     pub receiver: AuthZoneStackId,
     pub amount: Decimal,
     pub resource_address: ResourceAddress,
+    /// Whether a zero `amount` creates a presence proof rather than failing. A presence proof never satisfies an
+    /// access rule; see [`ProofValidationMode::ValidatePresence`].
+    pub allow_zero_amount: bool,
 }
 
 impl Invocation for AuthZoneCreateProofByAmountInvocation {
@@ -157,6 +160,25 @@ impl Into<CallTableInvocation> for AuthZoneDrainInvocation {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AuthZoneListInvocation {
+    pub receiver: AuthZoneStackId,
+}
+
+impl Invocation for AuthZoneListInvocation {
+    type Output = Vec<(ResourceAddress, Decimal)>;
+}
+
+impl SerializableInvocation for AuthZoneListInvocation {
+    type ScryptoOutput = Vec<(ResourceAddress, Decimal)>;
+}
+
+impl Into<CallTableInvocation> for AuthZoneListInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::AuthZoneStack(AuthZoneStackInvocation::List(self)).into()
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct AuthZoneAssertAccessRuleInvocation {
     pub receiver: AuthZoneStackId,
@@ -45,6 +45,26 @@ impl Into<CallTableInvocation> for ComponentGlobalizeWithOwnerInvocation {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ComponentGlobalizeWithAccessRulesInvocation {
+    pub component_id: ComponentId,
+    pub access_rules: AccessRules,
+}
+
+impl Invocation for ComponentGlobalizeWithAccessRulesInvocation {
+    type Output = ComponentAddress;
+}
+
+impl SerializableInvocation for ComponentGlobalizeWithAccessRulesInvocation {
+    type ScryptoOutput = ComponentAddress;
+}
+
+impl Into<CallTableInvocation> for ComponentGlobalizeWithAccessRulesInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Component(ComponentInvocation::GlobalizeWithAccessRules(self)).into()
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct ComponentSetRoyaltyConfigInvocation {
     /// TODO: change to component id, after `borrow_component` returns component id
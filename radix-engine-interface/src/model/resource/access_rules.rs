@@ -226,6 +226,25 @@ impl AccessRules {
     pub fn get_default_auth_mutability(&self) -> &AccessRule {
         &self.default_auth_mutability
     }
+
+    /// Merges `other` into these access rules, returning the union of both.
+    ///
+    /// Method, group and mutability entries are merged key by key: an entry present in only one
+    /// of the two is kept as is, and an entry present in both is taken from `other`. The rule of a
+    /// method and its mutability are merged independently of one another. Group references are
+    /// resolved against the merged groups, so a method of `self` which refers to a group
+    /// overridden in `other` is governed by the rule from `other`.
+    ///
+    /// The default rule and default mutability are not keyed entries and are kept from `self`.
+    pub fn merge(mut self, other: AccessRules) -> AccessRules {
+        self.method_auth.extend(other.method_auth);
+        self.grouped_auth.extend(other.grouped_auth);
+        self.method_auth_mutability
+            .extend(other.method_auth_mutability);
+        self.grouped_auth_mutability
+            .extend(other.grouped_auth_mutability);
+        self
+    }
 }
 
 pub fn package_access_rules_from_owner_badge(owner_badge: &NonFungibleGlobalId) -> AccessRules {
@@ -293,3 +312,55 @@ pub fn resource_access_rules_from_owner_badge(
     );
     access_rules
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_takes_overlapping_entries_from_other_and_keeps_the_rest() {
+        let mut base = AccessRules::new().default(AccessRule::AllowAll, AccessRule::DenyAll);
+        base.set_access_rule_and_mutability(
+            AccessRuleKey::ScryptoMethod("shared".to_string()),
+            AccessRule::DenyAll,
+            AccessRule::DenyAll,
+        );
+        base.set_access_rule_and_mutability(
+            AccessRuleKey::ScryptoMethod("base_only".to_string()),
+            AccessRule::DenyAll,
+            AccessRule::AllowAll,
+        );
+        base.set_method_access_rule_to_group(
+            AccessRuleKey::ScryptoMethod("grouped".to_string()),
+            "group".to_string(),
+        );
+        base.set_group_access_rule("group".to_string(), AccessRule::DenyAll);
+
+        let mut overrides = AccessRules::new();
+        overrides.set_access_rule_and_mutability(
+            AccessRuleKey::ScryptoMethod("shared".to_string()),
+            AccessRule::AllowAll,
+            AccessRule::AllowAll,
+        );
+        overrides.set_method_access_rule(
+            AccessRuleKey::ScryptoMethod("other_only".to_string()),
+            AccessRule::AllowAll,
+        );
+        overrides.set_group_access_rule("group".to_string(), AccessRule::AllowAll);
+
+        let merged = base.merge(overrides);
+
+        let key = |method: &str| AccessRuleKey::ScryptoMethod(method.to_string());
+        assert_eq!(merged.get(&key("shared")), &AccessRule::AllowAll);
+        assert_eq!(merged.get_mutability(&key("shared")), &AccessRule::AllowAll);
+        assert_eq!(merged.get(&key("base_only")), &AccessRule::DenyAll);
+        assert_eq!(
+            merged.get_mutability(&key("base_only")),
+            &AccessRule::AllowAll
+        );
+        assert_eq!(merged.get(&key("other_only")), &AccessRule::AllowAll);
+        assert_eq!(merged.get(&key("grouped")), &AccessRule::AllowAll);
+        assert_eq!(merged.get_default(), &AccessRule::AllowAll);
+        assert_eq!(merged.get_default_auth_mutability(), &AccessRule::DenyAll);
+    }
+}
@@ -1,7 +1,8 @@
 use sbor::rust::collections::BTreeSet;
-#[cfg(not(feature = "alloc"))]
 use sbor::rust::fmt;
 use sbor::rust::fmt::Debug;
+use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
 use sbor::*;
 
 use crate::abi::*;
@@ -9,8 +10,9 @@ use crate::api::types::*;
 use crate::api::wasm::*;
 use crate::api::*;
 use crate::data::types::Own;
-use crate::data::ScryptoCustomValueKind;
+use crate::data::{scrypto_encode, ScryptoCustomValueKind};
 use crate::math::*;
+use crate::{ScryptoCategorize, ScryptoDecode, ScryptoEncode};
 
 #[derive(Debug, Clone, Eq, PartialEq, Categorize, Encode, Decode)]
 pub struct ProofGetAmountInvocation {
@@ -88,8 +90,116 @@ impl Into<CallTableInvocation> for ProofCloneInvocation {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Categorize, Encode, Decode)]
+pub struct ProofMakeOneShotInvocation {
+    pub receiver: ProofId,
+}
+
+impl Invocation for ProofMakeOneShotInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for ProofMakeOneShotInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for ProofMakeOneShotInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Proof(ProofInvocation::MakeOneShot(self)).into()
+    }
+}
+
+/// Creates a new proof of the same resource and amount with its non-fungible ids hidden.
+///
+/// The ids of such a proof are not listed, can't satisfy non-fungible auth rules and can't be
+/// composed into new proofs.
+#[derive(Debug, Clone, Eq, PartialEq, Categorize, Encode, Decode)]
+pub struct ProofIntoResourceProofInvocation {
+    pub receiver: ProofId,
+}
+
+impl Invocation for ProofIntoResourceProofInvocation {
+    type Output = Proof;
+}
+
+impl SerializableInvocation for ProofIntoResourceProofInvocation {
+    type ScryptoOutput = Proof;
+}
+
+impl Into<CallTableInvocation> for ProofIntoResourceProofInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Proof(ProofInvocation::IntoResourceProof(self)).into()
+    }
+}
+
+/// Checks the proof against an `AccessRule` stored under the given key of a key value store, returning whether the
+/// rule is satisfied. The rule is read and evaluated by the engine, so it is never loaded into the caller's memory.
+#[derive(Debug, Clone, Eq, PartialEq, Categorize, Encode, Decode)]
+pub struct ProofValidateAgainstStoredRuleInvocation {
+    pub receiver: ProofId,
+    pub kv_store_id: KeyValueStoreId,
+    pub key: Vec<u8>,
+}
+
+impl Invocation for ProofValidateAgainstStoredRuleInvocation {
+    type Output = bool;
+}
+
+impl SerializableInvocation for ProofValidateAgainstStoredRuleInvocation {
+    type ScryptoOutput = bool;
+}
+
+impl Into<CallTableInvocation> for ProofValidateAgainstStoredRuleInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Proof(ProofInvocation::ValidateAgainstStoredRule(self)).into()
+    }
+}
+
+/// Checks the proof alone against the rule of each role of an access controller `RuleSet`, returning the roles whose
+/// rule it satisfies. The rules are evaluated by the engine in the same way as the access controller's own auth.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ProofSatisfiedRolesInvocation {
+    pub receiver: ProofId,
+    pub rule_set: RuleSet,
+}
+
+impl Invocation for ProofSatisfiedRolesInvocation {
+    type Output = Vec<Role>;
+}
+
+impl SerializableInvocation for ProofSatisfiedRolesInvocation {
+    type ScryptoOutput = Vec<Role>;
+}
+
+impl Into<CallTableInvocation> for ProofSatisfiedRolesInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Proof(ProofInvocation::SatisfiedRoles(self)).into()
+    }
+}
+
+/// Returns whether the proof is a zero-amount presence proof; see [`ProofValidationMode::ValidatePresence`].
+#[derive(Debug, Clone, Eq, PartialEq, Categorize, Encode, Decode)]
+pub struct ProofIsPresenceInvocation {
+    pub receiver: ProofId,
+}
+
+impl Invocation for ProofIsPresenceInvocation {
+    type Output = bool;
+}
+
+impl SerializableInvocation for ProofIsPresenceInvocation {
+    type ScryptoOutput = bool;
+}
+
+impl Into<CallTableInvocation> for ProofIsPresenceInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Proof(ProofInvocation::IsPresence(self)).into()
+    }
+}
+
 // TODO: Evaluate if we should have a ProofValidationModeBuilder to construct more complex validation modes.
 /// Specifies the validation mode that should be used for validating a `Proof`.
+#[derive(Debug, Clone)]
 pub enum ProofValidationMode {
     /// Specifies that the `Proof` should be validated against a single `ResourceAddress`.
     ValidateResourceAddress(ResourceAddress),
@@ -107,6 +217,144 @@ pub enum ProofValidationMode {
 
     /// Specifies that the `Proof` should be validated for the amount of resources that it contains.
     ValidateContainsAmount(ResourceAddress, Decimal),
+
+    /// Specifies that the `Proof` should be validated against a single resource address and to contain at least the
+    /// given number of distinct `NonFungibleLocalId`s, regardless of which ones they are.
+    ValidateMinNonFungibleCount(ResourceAddress, usize),
+
+    /// Specifies that the `Proof` should be of the resource held in the vault of the given access controller.
+    ValidateControlledBy(ComponentAddress),
+
+    /// Specifies that the `Proof` should be of a resource whose `symbol` metadata matches the given symbol and that it
+    /// should contain at least the given amount. Both constraints are always checked. Use
+    /// [`AmountWithSymbolValidationBuilder`] to construct this mode.
+    ValidateContainsAmountWithSymbol { symbol: String, amount: Decimal },
+
+    /// Specifies that the `Proof` should be validated against a single resource address and that its resource should
+    /// have every one of the given behaviors, such as being recallable or having a restricted withdraw rule.
+    ValidateResourceBehavior(ResourceAddress, BehaviorFlags),
+
+    /// Specifies that the `Proof` should be of the given resource, whatever its amount, which accepts the zero-amount
+    /// presence proofs created by `create_presence_proof`.
+    ///
+    /// A presence proof only shows that its creator can reach some container of the resource, and anyone can create
+    /// an empty vault of any resource, so it proves nothing about balance. It therefore never satisfies an access
+    /// rule, and every other validation mode rejects it with [`ProofValidationError::PresenceProofNotAllowed`]. This
+    /// mode should never be used to grant access that depends on holding the resource.
+    ValidatePresence(ResourceAddress),
+
+    /// Specifies that the `Proof` should be validated against a single resource address and that its resource should
+    /// not currently be frozen. Resources which don't support freezing are treated as not frozen.
+    ValidateNotFrozen(ResourceAddress),
+
+    /// Specifies that the `Proof` should be validated against a single resource address and that a field of the data
+    /// of every non-fungible it contains should equal an expected value. The field is located by its position in the
+    /// mutable or immutable part of the data, and the expected value is held encoded. Use
+    /// [`NonFungibleDataFieldValidationBuilder`] to construct this mode.
+    ValidateNonFungibleDataField {
+        resource_address: ResourceAddress,
+        field: String,
+        is_mutable: bool,
+        index: usize,
+        expected: Vec<u8>,
+    },
+}
+
+/// Builds a [`ProofValidationMode::ValidateContainsAmountWithSymbol`], for flows that identify a token by its symbol
+/// rather than by its resource address.
+#[derive(Debug, Clone)]
+pub struct AmountWithSymbolValidationBuilder {
+    symbol: String,
+    amount: Decimal,
+}
+
+impl AmountWithSymbolValidationBuilder {
+    /// Starts building a validation for a `Proof` of the resource with the given `symbol` metadata. Unless a minimum
+    /// amount is set, any amount of the resource is accepted.
+    pub fn new<S: Into<String>>(symbol: S) -> Self {
+        Self {
+            symbol: symbol.into(),
+            amount: Decimal::zero(),
+        }
+    }
+
+    /// Sets the minimum amount of the resource that the `Proof` should contain.
+    pub fn min_amount(mut self, amount: Decimal) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    pub fn build(self) -> ProofValidationMode {
+        ProofValidationMode::ValidateContainsAmountWithSymbol {
+            symbol: self.symbol,
+            amount: self.amount,
+        }
+    }
+}
+
+impl From<AmountWithSymbolValidationBuilder> for ProofValidationMode {
+    fn from(builder: AmountWithSymbolValidationBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Builds a [`ProofValidationMode::ValidateNonFungibleDataField`], for the common case of checking that a field of the
+/// `NonFungibleData` of a `Proof`'s non-fungibles equals some value.
+#[derive(Debug, Clone)]
+pub struct NonFungibleDataFieldValidationBuilder {
+    resource_address: ResourceAddress,
+    field: String,
+    expected: Vec<u8>,
+}
+
+impl NonFungibleDataFieldValidationBuilder {
+    /// Starts building a validation for a `Proof` of the given non-fungible resource, whose data should have the given
+    /// field equal to `expected`.
+    ///
+    /// # Panics
+    /// If `expected` can't be encoded.
+    pub fn new<S: Into<String>, V: crate::data::ScryptoEncode>(
+        resource_address: ResourceAddress,
+        field: S,
+        expected: &V,
+    ) -> Self {
+        Self {
+            resource_address,
+            field: field.into(),
+            expected: scrypto_encode(expected).expect("Failed to encode expected field value"),
+        }
+    }
+
+    /// Builds the validation mode, locating the field in the data schema of `T`.
+    ///
+    /// # Panics
+    /// If `T` has no field with the given name.
+    pub fn build<T: NonFungibleData>(self) -> ProofValidationMode {
+        let (is_mutable, index) = field_index(T::immutable_data_schema(), &self.field)
+            .map(|index| (false, index))
+            .or_else(|| {
+                field_index(T::mutable_data_schema(), &self.field).map(|index| (true, index))
+            })
+            .unwrap_or_else(|| panic!("Non-fungible data has no field named {}", self.field));
+
+        ProofValidationMode::ValidateNonFungibleDataField {
+            resource_address: self.resource_address,
+            field: self.field,
+            is_mutable,
+            index,
+            expected: self.expected,
+        }
+    }
+}
+
+fn field_index(schema: Type, field: &str) -> Option<usize> {
+    match schema {
+        Type::Struct {
+            fields: Fields::Named { named },
+            ..
+        } => named.iter().position(|(name, _)| name == field),
+        _ => None,
+    }
 }
 
 impl From<ResourceAddress> for ProofValidationMode {
@@ -121,6 +369,41 @@ impl From<NonFungibleGlobalId> for ProofValidationMode {
     }
 }
 
+/// An engine query issued while validating a `Proof`, reported to the query hook of
+/// `ScryptoProof::validate_with_query_hook` so that the cost of a validation mode can be measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofValidationQuery {
+    /// The resource address of the `Proof` was read.
+    ResourceAddress,
+
+    /// The `NonFungibleLocalId`s contained in the `Proof` were read.
+    NonFungibleLocalIds,
+
+    /// The amount of resources contained in the `Proof` was read.
+    Amount,
+
+    /// The divisibility of the `Proof`'s resource was read from its resource manager.
+    Divisibility,
+
+    /// The resource controlled by an access controller was read from the access controller.
+    ControlledResource,
+
+    /// The metadata of the `Proof`'s resource was read from its resource manager.
+    Metadata,
+
+    /// The behavior of the `Proof`'s resource was read from its resource manager.
+    Behavior,
+
+    /// The frozen status of the `Proof`'s resource was read from its resource manager.
+    FrozenStatus,
+
+    /// The data of the non-fungibles contained in the `Proof` was read from its resource manager.
+    NonFungibleData,
+
+    /// Whether the `Proof` is a presence proof was checked, from the amount of resources it contains.
+    Presence,
+}
+
 /// Represents an error when validating proof.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ProofValidationError {
@@ -129,12 +412,33 @@ pub enum ProofValidationError {
     DoesNotContainOneNonFungible,
     NonFungibleLocalIdNotFound,
     InvalidAmount(Decimal),
+    AmountExceedsDivisibility { amount: Decimal, divisibility: u8 },
+    NotControlledBy(ComponentAddress),
+    EmptyProof,
+    InsufficientNonFungibleCount { required: usize, actual: usize },
+    AmountWithSymbolNotMet(AmountWithSymbolFailure),
+    StoredRuleNotSatisfied,
+    BehaviorMismatch(BehaviorFlags),
+    ResourceFrozen(ResourceAddress),
+    InsufficientTotalAmount { required: Decimal, actual: Decimal },
+    DataFieldMismatch { field: String },
+    PresenceProofNotAllowed,
+}
+
+/// The constraints of a [`ProofValidationMode::ValidateContainsAmountWithSymbol`] that a `Proof` did not meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmountWithSymbolFailure {
+    /// The `symbol` metadata of the `Proof`'s resource is missing or differs from the expected one.
+    SymbolMismatch,
+    /// The `Proof` contains less than the required amount.
+    AmountShortfall,
+    /// Neither the symbol nor the amount constraint was met.
+    SymbolMismatchAndAmountShortfall,
 }
 
 #[cfg(not(feature = "alloc"))]
 impl std::error::Error for ProofValidationError {}
 
-#[cfg(not(feature = "alloc"))]
 impl fmt::Display for ProofValidationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
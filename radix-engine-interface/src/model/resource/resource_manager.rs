@@ -1,5 +1,6 @@
 use sbor::rust::collections::{BTreeMap, BTreeSet};
 use sbor::rust::fmt;
+use sbor::rust::ops::BitOr;
 use sbor::rust::string::String;
 use sbor::rust::vec::Vec;
 use sbor::*;
@@ -61,6 +62,83 @@ pub enum ResourceMethodAuthKey {
     Recall,
 }
 
+/// A set of behaviors of a resource, each derived from the access rule of one of its
+/// [`ResourceMethodAuthKey`]s.
+///
+/// Withdrawing and depositing are enabled for everyone by default, so their flags are set when the
+/// access rule is anything but `AllowAll`. Every other flag is set when its access rule is anything
+/// but `DenyAll`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    ScryptoCategorize,
+    ScryptoEncode,
+    ScryptoDecode,
+    LegacyDescribe,
+)]
+pub struct BehaviorFlags(u8);
+
+impl BehaviorFlags {
+    pub const MINTABLE: Self = Self(1 << 0);
+    pub const BURNABLE: Self = Self(1 << 1);
+    pub const UPDATEABLE_NON_FUNGIBLE_DATA: Self = Self(1 << 2);
+    pub const UPDATEABLE_METADATA: Self = Self(1 << 3);
+    pub const RESTRICTED_WITHDRAW: Self = Self(1 << 4);
+    pub const RESTRICTED_DEPOSIT: Self = Self(1 << 5);
+    pub const RECALLABLE: Self = Self(1 << 6);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    pub fn for_method_auth_key(key: ResourceMethodAuthKey) -> Self {
+        match key {
+            ResourceMethodAuthKey::Mint => Self::MINTABLE,
+            ResourceMethodAuthKey::Burn => Self::BURNABLE,
+            ResourceMethodAuthKey::UpdateNonFungibleData => Self::UPDATEABLE_NON_FUNGIBLE_DATA,
+            ResourceMethodAuthKey::UpdateMetadata => Self::UPDATEABLE_METADATA,
+            ResourceMethodAuthKey::Withdraw => Self::RESTRICTED_WITHDRAW,
+            ResourceMethodAuthKey::Deposit => Self::RESTRICTED_DEPOSIT,
+            ResourceMethodAuthKey::Recall => Self::RECALLABLE,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns whether every flag of `other` is also set in `self`.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Returns the flags of `self` which are not set in `other`.
+    pub fn difference(&self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl BitOr for BehaviorFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct ResourceManagerCreateNonFungibleInvocation {
     pub resource_address: Option<[u8; 26]>, // TODO: Clean this up
@@ -392,6 +470,47 @@ impl Into<CallTableInvocation> for ResourceManagerGetResourceTypeInvocation {
     }
 }
 
+/// Reads the [`BehaviorFlags`] of a resource from the access rules of its resource manager.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ResourceManagerGetBehaviorInvocation {
+    pub receiver: ResourceAddress,
+}
+
+impl Invocation for ResourceManagerGetBehaviorInvocation {
+    type Output = BehaviorFlags;
+}
+
+impl SerializableInvocation for ResourceManagerGetBehaviorInvocation {
+    type ScryptoOutput = BehaviorFlags;
+}
+
+impl Into<CallTableInvocation> for ResourceManagerGetBehaviorInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::ResourceManager(ResourceInvocation::GetBehavior(self)).into()
+    }
+}
+
+/// Reads whether a resource is frozen, which is when its withdraw rule denies everyone but can still be updated. A
+/// resource whose withdraw rule is locked doesn't support freezing, so it is never reported as frozen.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ResourceManagerIsFrozenInvocation {
+    pub receiver: ResourceAddress,
+}
+
+impl Invocation for ResourceManagerIsFrozenInvocation {
+    type Output = bool;
+}
+
+impl SerializableInvocation for ResourceManagerIsFrozenInvocation {
+    type ScryptoOutput = bool;
+}
+
+impl Into<CallTableInvocation> for ResourceManagerIsFrozenInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::ResourceManager(ResourceInvocation::IsFrozen(self)).into()
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct ResourceManagerGetTotalSupplyInvocation {
     pub receiver: ResourceAddress,
@@ -195,6 +195,9 @@ impl Into<CallTableInvocation> for VaultCreateProofInvocation {
 pub struct VaultCreateProofByAmountInvocation {
     pub receiver: VaultId,
     pub amount: Decimal,
+    /// Whether a zero `amount` creates a presence proof rather than failing. A presence proof never satisfies an
+    /// access rule; see [`crate::model::ProofValidationMode::ValidatePresence`].
+    pub allow_zero_amount: bool,
 }
 
 impl Invocation for VaultCreateProofByAmountInvocation {
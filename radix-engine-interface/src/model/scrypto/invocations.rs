@@ -4,17 +4,30 @@ use crate::api::Invocation;
 use crate::model::{CallTableInvocation, ComponentAddress, PackageAddress};
 use crate::*;
 use radix_engine_interface::data::ScryptoValue;
-use sbor::rust::string::String;
+use sbor::rust::fmt;
+use sbor::rust::string::{String, ToString};
 use sbor::rust::vec::Vec;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    ScryptoCategorize,
+    ScryptoEncode,
+    ScryptoDecode,
+    ScryptoDescribe,
+)]
 pub enum ScryptoReceiver {
     Global(ComponentAddress),
     Component(ComponentId),
 }
 
 /// Scrypto function/method invocation.
-#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+#[derive(
+    Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode, ScryptoDescribe,
+)]
 pub struct ScryptoInvocation {
     pub package_address: PackageAddress,
     pub blueprint_name: String,
@@ -25,6 +38,35 @@ pub struct ScryptoInvocation {
 
 impl Invocation for ScryptoInvocation {
     type Output = ScryptoValue;
+
+    fn fn_identifier(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Summarizes the invocation for logging, showing the size of the encoded arguments rather than
+/// the raw bytes.
+impl fmt::Display for ScryptoInvocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.receiver {
+            Some(receiver) => write!(
+                f,
+                "InvokeMethod {{ receiver: {:?}, fn_ident: {}::{}, args: {} bytes }}",
+                receiver,
+                self.blueprint_name,
+                self.fn_name,
+                self.args.len()
+            ),
+            None => write!(
+                f,
+                "InvokeFunction {{ package_address: {:?}, fn_ident: {}::{}, args: {} bytes }}",
+                self.package_address,
+                self.blueprint_name,
+                self.fn_name,
+                self.args.len()
+            ),
+        }
+    }
 }
 
 impl SerializableInvocation for ScryptoInvocation {
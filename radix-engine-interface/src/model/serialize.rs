@@ -50,6 +50,17 @@ impl NativeInvocation {
                     validator_init.initial_stake.0 = next_id;
                 }
             }
+            NativeInvocation::AccessController(AccessControllerInvocation::CreateGlobal(
+                invocation,
+            )) => {
+                for bucket_id in &mut invocation.controlled_assets {
+                    *bucket_id = bucket_replacements
+                        .remove(&ManifestBucket(*bucket_id))
+                        .ok_or(ReplaceManifestValuesError::BucketNotFound(ManifestBucket(
+                            *bucket_id,
+                        )))?;
+                }
+            }
             _ => {} // TODO: Expand this
         }
         Ok(())
@@ -106,6 +117,7 @@ pub enum LoggerInvocation {
 pub enum ComponentInvocation {
     Globalize(ComponentGlobalizeInvocation),
     GlobalizeWithOwner(ComponentGlobalizeWithOwnerInvocation),
+    GlobalizeWithAccessRules(ComponentGlobalizeWithAccessRulesInvocation),
     SetRoyaltyConfig(ComponentSetRoyaltyConfigInvocation),
     ClaimRoyalty(ComponentClaimRoyaltyInvocation),
 }
@@ -147,6 +159,7 @@ pub enum AuthZoneStackInvocation {
     CreateProofByIds(AuthZoneCreateProofByIdsInvocation),
     Clear(AuthZoneClearInvocation),
     Drain(AuthZoneDrainInvocation),
+    List(AuthZoneListInvocation),
     AssertAuthRule(AuthZoneAssertAccessRuleInvocation),
 }
 
@@ -170,6 +183,8 @@ pub enum ResourceInvocation {
     UpdateVaultAuth(ResourceManagerUpdateVaultAuthInvocation),
     LockVaultAuth(ResourceManagerSetVaultAuthMutabilityInvocation),
     GetTotalSupply(ResourceManagerGetTotalSupplyInvocation),
+    GetBehavior(ResourceManagerGetBehaviorInvocation),
+    IsFrozen(ResourceManagerIsFrozenInvocation),
     UpdateNonFungibleData(ResourceManagerUpdateNonFungibleDataInvocation),
     GetNonFungible(ResourceManagerGetNonFungibleInvocation),
     NonFungibleExists(ResourceManagerNonFungibleExistsInvocation),
@@ -205,9 +220,14 @@ pub enum VaultInvocation {
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub enum ProofInvocation {
     Clone(ProofCloneInvocation),
+    MakeOneShot(ProofMakeOneShotInvocation),
+    IntoResourceProof(ProofIntoResourceProofInvocation),
     GetAmount(ProofGetAmountInvocation),
     GetNonFungibleLocalIds(ProofGetNonFungibleLocalIdsInvocation),
     GetResourceAddress(ProofGetResourceAddressInvocation),
+    ValidateAgainstStoredRule(ProofValidateAgainstStoredRuleInvocation),
+    SatisfiedRoles(ProofSatisfiedRolesInvocation),
+    IsPresence(ProofIsPresenceInvocation),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
@@ -227,6 +247,7 @@ pub enum AccessControllerInvocation {
     CreateGlobal(AccessControllerCreateGlobalInvocation),
 
     CreateProof(AccessControllerCreateProofInvocation),
+    CreateProofs(AccessControllerCreateProofsInvocation),
 
     InitiateRecoveryAsPrimary(AccessControllerInitiateRecoveryAsPrimaryInvocation),
     InitiateRecoveryAsRecovery(AccessControllerInitiateRecoveryAsRecoveryInvocation),
@@ -237,6 +258,7 @@ pub enum AccessControllerInvocation {
     QuickConfirmRecoveryRoleRecoveryProposal(
         AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation,
     ),
+    BatchConfirmRecovery(AccessControllerBatchConfirmRecoveryInvocation),
 
     TimedConfirmRecovery(AccessControllerTimedConfirmRecoveryInvocation),
 
@@ -249,6 +271,39 @@ pub enum AccessControllerInvocation {
     UnlockPrimaryRole(AccessControllerUnlockPrimaryRoleInvocation),
 
     StopTimedRecovery(AccessControllerStopTimedRecoveryInvocation),
+    ExtendTimedRecovery(AccessControllerExtendTimedRecoveryInvocation),
+
+    GetRoleRule(AccessControllerGetRoleRuleInvocation),
+
+    EndorseRecoveryRoleRecoveryProposal(
+        AccessControllerEndorseRecoveryRoleRecoveryProposalInvocation,
+    ),
+
+    ExportState(AccessControllerExportStateInvocation),
+
+    GetControlledResourceManager(AccessControllerGetControlledResourceManagerInvocation),
+    VerifyControlledAsset(AccessControllerVerifyControlledAssetInvocation),
+    GetControlledVaults(AccessControllerGetControlledVaultsInvocation),
+
+    ValidateProposal(AccessControllerValidateProposalInvocation),
+
+    TransferOwnership(AccessControllerTransferOwnershipInvocation),
+
+    CheckRole(AccessControllerCheckRoleInvocation),
+    CanActAs(AccessControllerCanActAsInvocation),
+
+    InitiateRecoveryAsDelegate(AccessControllerInitiateRecoveryAsDelegateInvocation),
+    AddRecoveryDelegate(AccessControllerAddRecoveryDelegateInvocation),
+    RemoveRecoveryDelegate(AccessControllerRemoveRecoveryDelegateInvocation),
+
+    GetConfig(AccessControllerGetConfigInvocation),
+    GetDefaultRule(AccessControllerGetDefaultRuleInvocation),
+
+    Pause(AccessControllerPauseInvocation),
+    Resume(AccessControllerResumeInvocation),
+    GetTimingInfo(AccessControllerGetTimingInfoInvocation),
+    GetProposalAuthors(AccessControllerGetProposalAuthorsInvocation),
+    GetTimeline(AccessControllerGetTimelineInvocation),
 }
 
 impl NativeInvocation {
@@ -258,6 +313,7 @@ impl NativeInvocation {
             NativeInvocation::Component(invocation) => match invocation {
                 ComponentInvocation::Globalize(..) => {}
                 ComponentInvocation::GlobalizeWithOwner(..) => {}
+                ComponentInvocation::GlobalizeWithAccessRules(..) => {}
                 ComponentInvocation::SetRoyaltyConfig(invocation) => {
                     refs.insert(invocation.receiver);
                 }
@@ -295,6 +351,7 @@ impl NativeInvocation {
                 AuthZoneStackInvocation::CreateProofByIds(..) => {}
                 AuthZoneStackInvocation::Clear(..) => {}
                 AuthZoneStackInvocation::Drain(..) => {}
+                AuthZoneStackInvocation::List(..) => {}
                 AuthZoneStackInvocation::AssertAuthRule(..) => {}
             },
             NativeInvocation::Proof(proof_method) => match proof_method {
@@ -302,6 +359,11 @@ impl NativeInvocation {
                 ProofInvocation::GetNonFungibleLocalIds(..) => {}
                 ProofInvocation::GetResourceAddress(..) => {}
                 ProofInvocation::Clone(..) => {}
+                ProofInvocation::MakeOneShot(..) => {}
+                ProofInvocation::IntoResourceProof(..) => {}
+                ProofInvocation::ValidateAgainstStoredRule(..) => {}
+                ProofInvocation::SatisfiedRoles(..) => {}
+                ProofInvocation::IsPresence(..) => {}
             },
             NativeInvocation::Vault(vault_method) => match vault_method {
                 VaultInvocation::Take(..) => {}
@@ -402,6 +464,16 @@ impl NativeInvocation {
                         invocation.receiver,
                     )));
                 }
+                ResourceInvocation::GetBehavior(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Resource(
+                        invocation.receiver,
+                    )));
+                }
+                ResourceInvocation::IsFrozen(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Resource(
+                        invocation.receiver,
+                    )));
+                }
                 ResourceInvocation::UpdateNonFungibleData(invocation) => {
                     refs.insert(RENodeId::Global(GlobalAddress::Resource(
                         invocation.receiver,
@@ -537,6 +609,9 @@ impl NativeInvocation {
                 AccessControllerInvocation::CreateProof(
                     AccessControllerCreateProofInvocation { receiver, .. },
                 )
+                | AccessControllerInvocation::CreateProofs(
+                    AccessControllerCreateProofsInvocation { receiver, .. },
+                )
                 | AccessControllerInvocation::InitiateRecoveryAsPrimary(
                     AccessControllerInitiateRecoveryAsPrimaryInvocation { receiver, .. },
                 )
@@ -555,6 +630,9 @@ impl NativeInvocation {
                         ..
                     },
                 )
+                | AccessControllerInvocation::BatchConfirmRecovery(
+                    AccessControllerBatchConfirmRecoveryInvocation { receiver, .. },
+                )
                 | AccessControllerInvocation::TimedConfirmRecovery(
                     AccessControllerTimedConfirmRecoveryInvocation { receiver, .. },
                 )
@@ -576,6 +654,76 @@ impl NativeInvocation {
                 )
                 | AccessControllerInvocation::StopTimedRecovery(
                     AccessControllerStopTimedRecoveryInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::ExtendTimedRecovery(
+                    AccessControllerExtendTimedRecoveryInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::GetRoleRule(
+                    AccessControllerGetRoleRuleInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::EndorseRecoveryRoleRecoveryProposal(
+                    AccessControllerEndorseRecoveryRoleRecoveryProposalInvocation {
+                        receiver, ..
+                    },
+                )
+                | AccessControllerInvocation::ExportState(
+                    AccessControllerExportStateInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::GetControlledResourceManager(
+                    AccessControllerGetControlledResourceManagerInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::VerifyControlledAsset(
+                    AccessControllerVerifyControlledAssetInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::GetControlledVaults(
+                    AccessControllerGetControlledVaultsInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::ValidateProposal(
+                    AccessControllerValidateProposalInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::TransferOwnership(
+                    AccessControllerTransferOwnershipInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::CheckRole(AccessControllerCheckRoleInvocation {
+                    receiver,
+                    ..
+                })
+                | AccessControllerInvocation::CanActAs(AccessControllerCanActAsInvocation {
+                    receiver,
+                    ..
+                })
+                | AccessControllerInvocation::InitiateRecoveryAsDelegate(
+                    AccessControllerInitiateRecoveryAsDelegateInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::AddRecoveryDelegate(
+                    AccessControllerAddRecoveryDelegateInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::RemoveRecoveryDelegate(
+                    AccessControllerRemoveRecoveryDelegateInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::GetConfig(AccessControllerGetConfigInvocation {
+                    receiver,
+                    ..
+                })
+                | AccessControllerInvocation::GetDefaultRule(
+                    AccessControllerGetDefaultRuleInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::Pause(AccessControllerPauseInvocation {
+                    receiver,
+                    ..
+                })
+                | AccessControllerInvocation::Resume(AccessControllerResumeInvocation {
+                    receiver,
+                    ..
+                })
+                | AccessControllerInvocation::GetTimingInfo(
+                    AccessControllerGetTimingInfoInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::GetProposalAuthors(
+                    AccessControllerGetProposalAuthorsInvocation { receiver, .. },
+                )
+                | AccessControllerInvocation::GetTimeline(
+                    AccessControllerGetTimelineInvocation { receiver, .. },
                 ) => {
                     refs.insert(RENodeId::Global(GlobalAddress::Component(*receiver)));
                 }
@@ -0,0 +1,90 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use radix_engine_interface::data::*;
+use radix_engine_interface::math::Decimal;
+use radix_engine_interface::model::ResourceAddress;
+use radix_engine_interface::ScryptoDescribe;
+use sbor::*;
+
+fn assert_describes_as_custom_type<T: Describe<ScryptoCustomTypeKind<GlobalTypeId>>>(
+    expected_name: &str,
+    expected_custom_type_kind: ScryptoCustomTypeKind<LocalTypeIndex>,
+) {
+    let (type_index, schema) =
+        generate_full_schema_from_single_type::<T, ScryptoCustomTypeExtension>();
+
+    // Custom types are well known, so they shouldn't add any novel types to the schema
+    assert!(matches!(type_index, LocalTypeIndex::WellKnown(..)));
+    assert!(schema.type_kinds.is_empty());
+
+    let type_data = schema.resolve(type_index).unwrap();
+    assert_eq!(type_data.metadata.type_name, expected_name);
+    assert_eq!(
+        type_data.kind.into_owned(),
+        TypeKind::Custom(expected_custom_type_kind)
+    );
+}
+
+#[test]
+fn resource_address_describes_as_custom_type() {
+    assert_describes_as_custom_type::<ResourceAddress>(
+        "ResourceAddress",
+        ScryptoCustomTypeKind::ResourceAddress,
+    );
+}
+
+#[test]
+fn decimal_describes_as_custom_type() {
+    assert_describes_as_custom_type::<Decimal>("Decimal", ScryptoCustomTypeKind::Decimal);
+}
+
+mod original {
+    use super::*;
+
+    #[derive(ScryptoDescribe)]
+    pub struct Sample {
+        pub amount: Decimal,
+        pub resource_address: ResourceAddress,
+    }
+}
+
+mod identical {
+    use super::*;
+
+    #[derive(ScryptoDescribe)]
+    pub struct Sample {
+        pub amount: Decimal,
+        pub resource_address: ResourceAddress,
+    }
+}
+
+mod renamed_field {
+    use super::*;
+
+    #[derive(ScryptoDescribe)]
+    pub struct Sample {
+        pub quantity: Decimal,
+        pub resource_address: ResourceAddress,
+    }
+}
+
+fn schema_of<T: Describe<ScryptoCustomTypeKind<GlobalTypeId>>>() -> ScryptoSchema {
+    generate_full_schema_from_single_type::<T, ScryptoCustomTypeExtension>().1
+}
+
+#[test]
+fn structurally_identical_schemas_have_equal_content_hashes() {
+    let schema = schema_of::<original::Sample>();
+    let identical_schema = schema_of::<identical::Sample>();
+
+    assert_eq!(schema, identical_schema);
+    assert_eq!(schema.content_hash(), identical_schema.content_hash());
+}
+
+#[test]
+fn changing_a_field_changes_the_content_hash() {
+    let schema = schema_of::<original::Sample>();
+    let renamed_field_schema = schema_of::<renamed_field::Sample>();
+
+    assert_ne!(schema.content_hash(), renamed_field_schema.content_hash());
+}
@@ -0,0 +1,39 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use radix_engine_interface::api::wasm::generate_engine_input_schema;
+use radix_engine_interface::data::*;
+use sbor::*;
+
+fn variant_count(schema: &ScryptoSchema, type_name: &str) -> usize {
+    let type_index = schema
+        .index_by_name(type_name)
+        .unwrap_or_else(|| panic!("Schema is missing {}", type_name));
+    match schema.resolve(type_index).unwrap().kind.into_owned() {
+        TypeKind::Enum { variants } => variants.len(),
+        _ => panic!("{} is not an enum", type_name),
+    }
+}
+
+#[test]
+fn engine_input_schema_has_an_entry_per_variant() {
+    let schema = generate_engine_input_schema();
+
+    assert_eq!(variant_count(&schema, "ScryptoRENode"), 2);
+    assert_eq!(variant_count(&schema, "ScryptoReceiver"), 2);
+}
+
+#[test]
+fn engine_input_schema_describes_scrypto_invocation_fields() {
+    let schema = generate_engine_input_schema();
+
+    let type_index = schema.index_by_name("ScryptoInvocation").unwrap();
+    let type_data = schema.resolve(type_index).unwrap();
+    let TypeKind::Tuple { field_types } = type_data.kind.into_owned() else {
+        panic!("ScryptoInvocation is not a tuple");
+    };
+    assert_eq!(field_types.len(), 5);
+    assert_eq!(
+        field_types[0],
+        LocalTypeIndex::WellKnown(VALUE_KIND_PACKAGE_ADDRESS)
+    );
+}
@@ -0,0 +1,42 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use radix_engine_interface::constants::{FAUCET_COMPONENT, RADIX_TOKEN};
+use radix_engine_interface::math::Decimal;
+use radix_engine_interface::model::{AmountWithSymbolFailure, BehaviorFlags, ProofValidationError};
+use sbor::rust::format;
+
+#[test]
+fn proof_validation_error_display_matches_debug() {
+    let errors = [
+        ProofValidationError::InvalidResourceAddress(RADIX_TOKEN),
+        ProofValidationError::ResourceAddressDoesNotBelongToList,
+        ProofValidationError::DoesNotContainOneNonFungible,
+        ProofValidationError::NonFungibleLocalIdNotFound,
+        ProofValidationError::InvalidAmount(Decimal::ONE),
+        ProofValidationError::AmountExceedsDivisibility {
+            amount: Decimal::ONE,
+            divisibility: 0,
+        },
+        ProofValidationError::NotControlledBy(FAUCET_COMPONENT),
+        ProofValidationError::EmptyProof,
+        ProofValidationError::InsufficientNonFungibleCount {
+            required: 3,
+            actual: 2,
+        },
+        ProofValidationError::AmountWithSymbolNotMet(AmountWithSymbolFailure::SymbolMismatch),
+        ProofValidationError::StoredRuleNotSatisfied,
+        ProofValidationError::BehaviorMismatch(BehaviorFlags::RECALLABLE),
+        ProofValidationError::ResourceFrozen(RADIX_TOKEN),
+        ProofValidationError::InsufficientTotalAmount {
+            required: Decimal::ONE,
+            actual: Decimal::ZERO,
+        },
+        ProofValidationError::DataFieldMismatch {
+            field: "tier".into(),
+        },
+    ];
+
+    for error in errors {
+        assert_eq!(format!("{}", error), format!("{:?}", error));
+    }
+}
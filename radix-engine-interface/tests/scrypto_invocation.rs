@@ -0,0 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use radix_engine_interface::constants::{ACCOUNT_PACKAGE, FAUCET_COMPONENT};
+use radix_engine_interface::model::{ScryptoInvocation, ScryptoReceiver};
+use sbor::rust::format;
+use sbor::rust::string::ToString;
+use sbor::rust::vec;
+
+#[test]
+fn method_invocation_display_summarizes_fn_ident_and_args_size() {
+    let invocation = ScryptoInvocation {
+        package_address: ACCOUNT_PACKAGE,
+        blueprint_name: "Account".to_string(),
+        fn_name: "balance".to_string(),
+        receiver: Some(ScryptoReceiver::Global(FAUCET_COMPONENT)),
+        args: vec![0u8; 42],
+    };
+
+    let rendered = format!("{}", invocation);
+
+    assert!(rendered.starts_with("InvokeMethod"));
+    assert!(rendered.contains(&format!("{:?}", FAUCET_COMPONENT)));
+    assert!(rendered.contains("fn_ident: Account::balance"));
+    assert!(rendered.contains("args: 42 bytes"));
+}
@@ -21,6 +21,7 @@ fn bench_transfer(c: &mut Criterion) {
         wasm_engine: DefaultWasmEngine::default(),
         wasm_instrumenter: WasmInstrumenter::default(),
         wasm_metering_config: WasmMeteringConfig::V0,
+        fee_reserve_check: None,
     };
     let mut substate_store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
 
@@ -1,7 +1,10 @@
 use crate::engine::system_api::LockInfo;
 use crate::engine::*;
 use crate::fee::FeeReserve;
-use crate::model::{SubstateRef, SubstateRefMut};
+use crate::model::{
+    AuthZoneError, BucketError, BucketSubstate, ProofError, ProofSubstate, RuntimeSubstate,
+    SubstateRef, SubstateRefMut,
+};
 use crate::types::*;
 use radix_engine_interface::api::types::{
     GlobalAddress, LockHandle, NonFungibleStoreOffset, RENodeId, SubstateId, SubstateOffset,
@@ -81,6 +84,16 @@ impl RENodeRefData {
 
 // TODO: reduce fields visibility
 
+/// Number of substates a call frame has read and written, not including the substates accessed
+/// by the frames of the invocations it made.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode,
+)]
+pub struct SubstateAccessCounts {
+    pub reads: u32,
+    pub writes: u32,
+}
+
 /// A call frame is the basic unit that forms a transaction call stack, which keeps track of the
 /// owned objects by this function.
 pub struct CallFrame {
@@ -99,6 +112,8 @@ pub struct CallFrame {
 
     next_lock_handle: LockHandle,
     locks: HashMap<LockHandle, SubstateLock>,
+
+    substate_access_counts: SubstateAccessCounts,
 }
 
 impl CallFrame {
@@ -269,6 +284,7 @@ impl CallFrame {
 
         Ok(LockInfo {
             offset: substate_lock.substate_pointer.2.clone(),
+            flags: substate_lock.flags,
         })
     }
 
@@ -288,9 +304,20 @@ impl CallFrame {
             owned_root_nodes: HashMap::new(),
             next_lock_handle: 0u32,
             locks: HashMap::new(),
+            substate_access_counts: SubstateAccessCounts::default(),
         }
     }
 
+    /// Returns the actor of this frame, so that invocations made on its behalf can reuse the
+    /// already resolved function identifier and receiver.
+    pub fn current_actor_context(&self) -> ResolvedActor {
+        self.actor.clone()
+    }
+
+    pub fn substate_access_counts(&self) -> SubstateAccessCounts {
+        self.substate_access_counts
+    }
+
     pub fn new_child_from_parent(
         parent: &mut CallFrame,
         actor: ResolvedActor,
@@ -317,6 +344,7 @@ impl CallFrame {
             owned_root_nodes: owned_heap_nodes,
             next_lock_handle: 0u32,
             locks: HashMap::new(),
+            substate_access_counts: SubstateAccessCounts::default(),
         };
 
         Ok(frame)
@@ -367,6 +395,41 @@ impl CallFrame {
         Ok(())
     }
 
+    /// Checks that every reference this frame holds to a heap node still points to a node on the
+    /// heap, which would otherwise indicate an executor losing track of a node it moved out.
+    pub fn check_dangling_refs(&self, heap: &Heap) -> Result<(), CallFrameError> {
+        let mut node_ids: Vec<&RENodeId> = self
+            .node_refs
+            .iter()
+            .filter(|(_, ref_data)| ref_data.location == RENodeLocation::Heap)
+            .map(|(node_id, _)| node_id)
+            .collect();
+        node_ids.sort(); // Required to make sure the reported node is deterministic
+
+        for node_id in node_ids {
+            if !heap.contains_node(node_id) {
+                return Err(CallFrameError::DanglingReference(*node_id));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that this frame owns no nodes, which an executor may use to assert that it has moved
+    /// or dropped everything it created before reaching a particular point. Unlike the checks on
+    /// frame teardown, this can be called at any point during execution.
+    pub fn assert_no_owned_values(&self) -> Result<(), RuntimeError> {
+        if self.owned_root_nodes.is_empty() {
+            return Ok(());
+        }
+
+        let mut node_ids = self.owned_nodes();
+        node_ids.sort(); // Required to make sure the reported nodes are deterministic
+        Err(RuntimeError::CallFrameError(
+            CallFrameError::UnexpectedOwnedNodes(node_ids),
+        ))
+    }
+
     fn take_node_internal(&mut self, node_id: RENodeId) -> Result<(), CallFrameError> {
         match self.owned_root_nodes.remove(&node_id) {
             None => Err(CallFrameError::RENodeNotOwned(node_id)),
@@ -450,6 +513,97 @@ impl CallFrame {
         Ok(node)
     }
 
+    /// Splits `amount` off an owned bucket into a new bucket node, which is also owned by this
+    /// frame. The original bucket keeps the remainder.
+    pub fn split_bucket_node(
+        &mut self,
+        heap: &mut Heap,
+        node_id: RENodeId,
+        new_node_id: RENodeId,
+        amount: Decimal,
+    ) -> Result<(), RuntimeError> {
+        if !self.owned_root_nodes.contains_key(&node_id) {
+            return Err(RuntimeError::CallFrameError(
+                CallFrameError::RENodeNotOwned(node_id),
+            ));
+        }
+
+        let offset = SubstateOffset::Bucket(BucketOffset::Bucket);
+        let taken = heap
+            .get_substate_mut(node_id, &offset)?
+            .bucket()
+            .take(amount)
+            .map_err(|e| {
+                RuntimeError::ApplicationError(ApplicationError::BucketError(
+                    BucketError::ResourceOperationError(e),
+                ))
+            })?;
+
+        let mut substates = HashMap::new();
+        substates.insert(offset, RuntimeSubstate::Bucket(BucketSubstate::new(taken)));
+        heap.create_node(new_node_id, HeapRENode { substates });
+        self.owned_root_nodes.insert(new_node_id, 0u32);
+
+        Ok(())
+    }
+
+    /// Moves an owned proof onto the current auth zone for the duration of `scope`, then takes it
+    /// back off and drops it - also when `scope` fails, in which case the error of `scope` is
+    /// returned rather than any error of the cleanup. The proof taken back off is the one holding
+    /// the locks of the pushed proof, so proofs pushed by `scope` itself are left in place.
+    pub fn with_pushed_proof<T, F>(
+        &mut self,
+        heap: &mut Heap,
+        auth_zone_id: RENodeId,
+        proof_id: ProofId,
+        scope: F,
+    ) -> Result<T, RuntimeError>
+    where
+        F: FnOnce(&mut Self, &mut Heap) -> Result<T, RuntimeError>,
+    {
+        let proof_node_id = RENodeId::Proof(proof_id);
+        let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
+        self.get_node_visibility(auth_zone_id)?;
+        heap.get_substate(auth_zone_id, &offset)?;
+
+        let mut proof: ProofSubstate = self.remove_node(heap, proof_node_id)?.into();
+        if proof.is_one_shot_used() {
+            proof.drop();
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::AuthZoneError(AuthZoneError::ProofError(
+                    ProofError::OneShotProofAlreadyUsed,
+                )),
+            ));
+        }
+        proof.change_to_unrestricted();
+        proof.mark_one_shot_used();
+
+        let locks = proof.locks();
+        let index = {
+            let mut substate_mut = heap.get_substate_mut(auth_zone_id, &offset)?;
+            let auth_zone = substate_mut.auth_zone_stack().cur_auth_zone_mut();
+            auth_zone.push(proof);
+            auth_zone.proofs().len() - 1
+        };
+
+        let rtn = scope(self, heap);
+
+        let cleanup = heap
+            .get_substate_mut(auth_zone_id, &offset)
+            .and_then(|mut substate_mut| {
+                substate_mut
+                    .auth_zone_stack()
+                    .cur_auth_zone_mut()
+                    .remove_proof_with_locks(index, &locks)
+                    .ok_or(CallFrameError::PushedProofNotInAuthZone(proof_node_id))
+            })
+            .map(|mut proof| proof.drop());
+
+        let output = rtn?;
+        cleanup?;
+        Ok(output)
+    }
+
     fn get_substate<'f, 'p, 's, R: FeeReserve>(
         &self,
         heap: &'f mut Heap,
@@ -480,6 +634,7 @@ impl CallFrame {
             .map_err(RuntimeError::KernelError)?
             .clone();
 
+        self.substate_access_counts.reads += 1;
         self.get_substate(heap, track, node_location, node_id, &offset)
     }
 
@@ -504,6 +659,7 @@ impl CallFrame {
             )));
         }
 
+        self.substate_access_counts.writes += 1;
         let ref_mut = match node_location {
             RENodeLocation::Heap => heap.get_substate_mut(node_id, &offset).unwrap(),
             RENodeLocation::Store => track.get_substate_mut(node_id, &offset),
@@ -549,4 +705,282 @@ impl CallFrame {
         node_ids.sort(); // Required to make sure returned vector is deterministic
         node_ids
     }
+
+    /// Lists the nodes this frame holds a reference to (not including owned nodes), along with
+    /// whether each one lives on the heap or in the store.
+    pub fn references(&self) -> Vec<(RENodeId, RENodeLocation)> {
+        let mut references: Vec<(RENodeId, RENodeLocation)> = self
+            .node_refs
+            .iter()
+            .map(|(node_id, ref_data)| (*node_id, ref_data.location))
+            .collect();
+        references.sort_by_key(|(node_id, _)| *node_id);
+        references
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{AuthZoneStackSubstate, Resource};
+
+    /// Sets up a root frame owning a bucket of 10 XRD, a proof of the bucket and an empty auth
+    /// zone, returning the ids of the three nodes.
+    fn setup_proof_and_auth_zone(
+        heap: &mut Heap,
+        frame: &mut CallFrame,
+    ) -> (RENodeId, ProofId, RENodeId) {
+        let bucket_id = 1u32;
+        let proof_id = 2u32;
+        let auth_zone_id = RENodeId::AuthZoneStack(3u32);
+
+        let mut bucket = BucketSubstate::new(Resource::new_fungible(RADIX_TOKEN, 18, dec!("10")));
+        let proof = bucket.create_proof(bucket_id).unwrap();
+        let auth_zone = AuthZoneStackSubstate::new(vec![], BTreeSet::new(), BTreeSet::new());
+        for (node_id, offset, substate) in [
+            (
+                RENodeId::Bucket(bucket_id),
+                SubstateOffset::Bucket(BucketOffset::Bucket),
+                RuntimeSubstate::Bucket(bucket),
+            ),
+            (
+                RENodeId::Proof(proof_id),
+                SubstateOffset::Proof(ProofOffset::Proof),
+                RuntimeSubstate::Proof(proof),
+            ),
+            (
+                auth_zone_id,
+                SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack),
+                RuntimeSubstate::AuthZoneStack(auth_zone),
+            ),
+        ] {
+            let mut substates = HashMap::new();
+            substates.insert(offset, substate);
+            heap.create_node(node_id, HeapRENode { substates });
+            frame.owned_root_nodes.insert(node_id, 0u32);
+        }
+
+        (RENodeId::Bucket(bucket_id), proof_id, auth_zone_id)
+    }
+
+    fn auth_zone_proof_count(heap: &mut Heap, auth_zone_id: RENodeId) -> usize {
+        let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
+        heap.get_substate_mut(auth_zone_id, &offset)
+            .unwrap()
+            .auth_zone_stack()
+            .cur_auth_zone_mut()
+            .proofs()
+            .len()
+    }
+
+    fn bucket_is_locked(heap: &mut Heap, bucket_id: RENodeId) -> bool {
+        let offset = SubstateOffset::Bucket(BucketOffset::Bucket);
+        heap.get_substate(bucket_id, &offset)
+            .unwrap()
+            .bucket()
+            .is_locked()
+    }
+
+    #[test]
+    fn pushed_proof_is_removed_after_scope_regardless_of_outcome() {
+        let scope_error =
+            RuntimeError::CallFrameError(CallFrameError::RENodeNotVisible(RENodeId::Bucket(9u32)));
+        for fail in [false, true] {
+            let mut heap = Heap::new();
+            let mut frame = CallFrame::new_root();
+            let (bucket_id, proof_id, auth_zone_id) =
+                setup_proof_and_auth_zone(&mut heap, &mut frame);
+
+            let rtn = frame.with_pushed_proof(&mut heap, auth_zone_id, proof_id, |_, heap| {
+                assert_eq!(auth_zone_proof_count(heap, auth_zone_id), 1);
+                if fail {
+                    Err(scope_error.clone())
+                } else {
+                    Ok(())
+                }
+            });
+
+            if fail {
+                assert_eq!(rtn, Err(scope_error.clone()));
+            } else {
+                assert_eq!(rtn, Ok(()));
+            }
+            assert_eq!(auth_zone_proof_count(&mut heap, auth_zone_id), 0);
+            assert!(!bucket_is_locked(&mut heap, bucket_id));
+            assert!(!frame.owned_nodes().contains(&RENodeId::Proof(proof_id)));
+        }
+    }
+
+    #[test]
+    fn error_of_scope_takes_precedence_over_error_of_cleanup() {
+        let mut heap = Heap::new();
+        let mut frame = CallFrame::new_root();
+        let (_, proof_id, auth_zone_id) = setup_proof_and_auth_zone(&mut heap, &mut frame);
+        let scope_error =
+            RuntimeError::CallFrameError(CallFrameError::RENodeNotVisible(RENodeId::Bucket(9u32)));
+
+        let rtn = frame.with_pushed_proof(&mut heap, auth_zone_id, proof_id, |_, heap| {
+            let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
+            let mut substate_mut = heap.get_substate_mut(auth_zone_id, &offset).unwrap();
+            let auth_zone = substate_mut.auth_zone_stack().cur_auth_zone_mut();
+            auth_zone.pop().unwrap().drop();
+            Err(scope_error.clone())
+        });
+
+        assert_eq!(rtn, Err(scope_error));
+    }
+
+    #[test]
+    fn proofs_pushed_by_scope_are_left_on_auth_zone() {
+        let mut heap = Heap::new();
+        let mut frame = CallFrame::new_root();
+        let (_, proof_id, auth_zone_id) = setup_proof_and_auth_zone(&mut heap, &mut frame);
+        let mut other_bucket =
+            BucketSubstate::new(Resource::new_fungible(RADIX_TOKEN, 18, dec!("5")));
+        let other_proof = other_bucket.create_proof(4u32).unwrap();
+        let other_locks = other_proof.locks();
+
+        frame
+            .with_pushed_proof(&mut heap, auth_zone_id, proof_id, |_, heap| {
+                let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
+                let mut substate_mut = heap.get_substate_mut(auth_zone_id, &offset).unwrap();
+                let auth_zone = substate_mut.auth_zone_stack().cur_auth_zone_mut();
+                auth_zone.push(other_proof);
+                Ok(())
+            })
+            .unwrap();
+
+        let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
+        let mut substate_mut = heap.get_substate_mut(auth_zone_id, &offset).unwrap();
+        let proofs = substate_mut.auth_zone_stack().cur_auth_zone_mut().proofs();
+        assert_eq!(proofs.len(), 1);
+        assert_eq!(proofs[0].locks(), other_locks);
+    }
+
+    #[test]
+    fn split_bucket_node_leaves_remainder_in_original_bucket() {
+        let mut heap = Heap::new();
+        let mut frame = CallFrame::new_root();
+        let bucket_id = RENodeId::Bucket(1u32);
+        let split_id = RENodeId::Bucket(2u32);
+        let offset = SubstateOffset::Bucket(BucketOffset::Bucket);
+        let mut substates = HashMap::new();
+        substates.insert(
+            offset.clone(),
+            RuntimeSubstate::Bucket(BucketSubstate::new(Resource::new_fungible(
+                RADIX_TOKEN,
+                18,
+                dec!("10"),
+            ))),
+        );
+        heap.create_node(bucket_id, HeapRENode { substates });
+        frame.owned_root_nodes.insert(bucket_id, 0u32);
+
+        frame
+            .split_bucket_node(&mut heap, bucket_id, split_id, dec!("3"))
+            .unwrap();
+
+        assert_eq!(
+            heap.get_substate(bucket_id, &offset)
+                .unwrap()
+                .bucket()
+                .total_amount(),
+            dec!("7")
+        );
+        assert_eq!(
+            heap.get_substate(split_id, &offset)
+                .unwrap()
+                .bucket()
+                .total_amount(),
+            dec!("3")
+        );
+        let mut owned = frame.owned_nodes();
+        owned.sort();
+        assert_eq!(owned, vec![bucket_id, split_id]);
+    }
+
+    #[test]
+    fn reference_to_removed_heap_node_is_reported_as_dangling() {
+        let mut heap = Heap::new();
+        let mut frame = CallFrame::new_root();
+        let bucket_id = RENodeId::Bucket(1u32);
+        heap.create_node(
+            bucket_id,
+            HeapRENode {
+                substates: HashMap::new(),
+            },
+        );
+        frame.node_refs.insert(
+            bucket_id,
+            RENodeRefData::new(RENodeLocation::Heap, RENodeVisibilityOrigin::Normal),
+        );
+        assert_eq!(frame.check_dangling_refs(&heap), Ok(()));
+
+        heap.remove_node(bucket_id).unwrap();
+
+        assert_eq!(
+            frame.check_dangling_refs(&heap),
+            Err(CallFrameError::DanglingReference(bucket_id))
+        );
+    }
+
+    #[test]
+    fn child_frame_created_with_parent_actor_context_has_same_context() {
+        let mut parent = CallFrame::new_root();
+        parent.actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::TimedConfirmRecovery),
+            ResolvedReceiver::derefed(
+                RENodeId::AccessController([1u8; 36]),
+                RENodeId::Global(GlobalAddress::Component(FAUCET_COMPONENT)),
+                0u32,
+            ),
+        );
+
+        let context = parent.current_actor_context();
+        let child =
+            CallFrame::new_child_from_parent(&mut parent, context, CallFrameUpdate::empty())
+                .unwrap();
+
+        assert_eq!(child.current_actor_context(), parent.actor);
+        assert_eq!(child.depth, parent.depth + 1);
+    }
+
+    #[test]
+    fn references_report_whether_nodes_are_on_heap_or_in_store() {
+        let mut frame = CallFrame::new_root();
+        let component_id = RENodeId::Global(GlobalAddress::Component(FAUCET_COMPONENT));
+        let bucket_id = RENodeId::Bucket(1u32);
+        frame.add_stored_ref(component_id, RENodeVisibilityOrigin::Normal);
+        frame.node_refs.insert(
+            bucket_id,
+            RENodeRefData::new(RENodeLocation::Heap, RENodeVisibilityOrigin::Normal),
+        );
+        frame.owned_root_nodes.insert(RENodeId::Bucket(2u32), 0u32);
+
+        let mut expected = vec![
+            (component_id, RENodeLocation::Store),
+            (bucket_id, RENodeLocation::Heap),
+        ];
+        expected.sort_by_key(|(node_id, _)| *node_id);
+        assert_eq!(frame.references(), expected);
+    }
+
+    #[test]
+    fn assert_no_owned_values_reports_owned_nodes() {
+        let mut frame = CallFrame::new_root();
+        assert_eq!(frame.assert_no_owned_values(), Ok(()));
+
+        frame.owned_root_nodes.insert(RENodeId::Bucket(2u32), 0u32);
+        frame.owned_root_nodes.insert(RENodeId::Bucket(1u32), 0u32);
+
+        assert_eq!(
+            frame.assert_no_owned_values(),
+            Err(RuntimeError::CallFrameError(
+                CallFrameError::UnexpectedOwnedNodes(vec![
+                    RENodeId::Bucket(1u32),
+                    RENodeId::Bucket(2u32)
+                ])
+            ))
+        );
+    }
 }
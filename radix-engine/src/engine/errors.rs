@@ -7,7 +7,7 @@ use sbor::*;
 
 use crate::model::*;
 use crate::types::*;
-use crate::wasm::WasmRuntimeError;
+use crate::wasm::{WasmRuntimeError, WasmTrapKind};
 
 use super::TrackError;
 
@@ -60,6 +60,9 @@ pub enum RuntimeError {
 
     /// An error occurred within application logic, like the RE models.
     ApplicationError(ApplicationError),
+
+    /// The WASM code of a blueprint trapped.
+    WasmTrap { kind: WasmTrapKind },
 }
 
 impl From<KernelError> for RuntimeError {
@@ -100,6 +103,7 @@ impl CanBeAbortion for RuntimeError {
             RuntimeError::InterpreterError(_) => None,
             RuntimeError::ModuleError(err) => err.abortion(),
             RuntimeError::ApplicationError(_) => None,
+            RuntimeError::WasmTrap { .. } => None,
         }
     }
 }
@@ -139,6 +143,7 @@ pub enum KernelError {
     InvalidOwnership(SubstateOffset, RENodeId),
     InvalidOverwrite,
     InvalidId(RENodeId),
+    InvalidSubstateFieldPath(Vec<usize>),
 
     // Actor Constraints
     InvalidDropNodeVisibility {
@@ -174,6 +179,13 @@ pub enum CallFrameError {
     RENodeNotVisible(RENodeId),
     RENodeNotOwned(RENodeId),
     MovingLockedRENode(RENodeId),
+    /// A reference to a heap node which no longer exists on the heap.
+    DanglingReference(RENodeId),
+    /// Nodes owned by a frame which was expected to own none, sorted by id.
+    UnexpectedOwnedNodes(Vec<RENodeId>),
+    /// A proof pushed onto the auth zone for the duration of a scope which was no longer there at
+    /// the end of the scope.
+    PushedProofNotInAuthZone(RENodeId),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Categorize)]
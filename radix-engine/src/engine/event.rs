@@ -1,7 +1,8 @@
-use crate::model::SysCallTrace;
+use crate::model::{SubstateAccessTrace, SysCallTrace};
 use crate::types::*;
 
 #[derive(Debug, Clone, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub enum TrackedEvent {
     SysCallTrace(SysCallTrace),
+    SubstateAccessTrace(SubstateAccessTrace),
 }
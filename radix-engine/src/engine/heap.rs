@@ -86,6 +86,10 @@ impl Heap {
         }
     }
 
+    pub fn contains_node(&self, node_id: &RENodeId) -> bool {
+        self.nodes.contains_key(node_id)
+    }
+
     pub fn create_node(&mut self, node_id: RENodeId, node: HeapRENode) {
         self.nodes.insert(node_id, node);
     }
@@ -220,11 +220,10 @@ impl IdAllocator {
     }
 
     pub fn new_access_controller_address(&mut self) -> Result<ComponentAddress, IdAllocationError> {
-        let mut data = self.transaction_hash.to_vec();
-        data.extend(self.next()?.to_le_bytes());
-
-        Ok(ComponentAddress::AccessController(
-            hash(data).lower_26_bytes(),
+        let next_id = self.next_entity_id(EntityType::AccessControllerComponent)?;
+        Ok(predict_access_controller_address(
+            &self.transaction_hash,
+            next_id,
         ))
     }
 
@@ -293,3 +292,12 @@ impl IdAllocator {
         self.next_id()
     }
 }
+
+/// Computes the address of the `index`-th access controller (counting from zero) created by the
+/// transaction with the given hash. This is the derivation used by the [`IdAllocator`], so clients
+/// can know the address of a controller before the transaction creating it is committed.
+pub fn predict_access_controller_address(transaction_hash: &Hash, index: u32) -> ComponentAddress {
+    let mut data = transaction_hash.to_vec();
+    data.extend(index.to_le_bytes());
+    ComponentAddress::AccessController(hash(data).lower_26_bytes())
+}
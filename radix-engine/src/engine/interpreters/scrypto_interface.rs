@@ -6,6 +6,7 @@ use crate::model::MetadataSubstate;
 use crate::model::{
     AccessRulesChainSubstate, ComponentInfoSubstate, ComponentRoyaltyAccumulatorSubstate,
     ComponentRoyaltyConfigSubstate, ComponentStateSubstate, KeyValueStore, RuntimeSubstate,
+    SubstateRef,
 };
 use crate::types::BTreeMap;
 use crate::wasm::WasmEngine;
@@ -15,9 +16,11 @@ use radix_engine_interface::api::types::{
 use radix_engine_interface::api::{EngineApi, Invokable};
 use radix_engine_interface::constants::RADIX_TOKEN;
 use radix_engine_interface::data::types::Own;
+use radix_engine_interface::data::{scrypto_encode, IndexedScryptoValue};
 use radix_engine_interface::model::{
     AccessRule, AccessRuleKey, AccessRules, ResourceManagerCreateVaultInvocation, RoyaltyConfig,
 };
+use sbor::path::SborPath;
 use sbor::rust::string::ToString;
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
@@ -100,6 +103,14 @@ where
         Ok(())
     }
 
+    fn sys_drop_nodes(&mut self, node_ids: Vec<RENodeId>) -> Result<(), RuntimeError> {
+        // The errors of a failed drop identify the node, so the first one is returned as is
+        for node_id in node_ids {
+            self.drop_node(node_id)?;
+        }
+        Ok(())
+    }
+
     fn sys_get_visible_nodes(&mut self) -> Result<Vec<RENodeId>, RuntimeError> {
         self.get_visible_nodes()
     }
@@ -125,10 +136,81 @@ where
             .map(|substate_ref| substate_ref.to_scrypto_value().into_vec())
     }
 
+    fn sys_read_field(
+        &mut self,
+        lock_handle: LockHandle,
+        path: Vec<usize>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        let substate_ref = self.get_ref(lock_handle)?;
+        // Component state and key value store entries hold their value as raw bytes, which is
+        // where the path is meant to point into.
+        let value = match substate_ref {
+            SubstateRef::ComponentState(state) => IndexedScryptoValue::from_slice(&state.raw).ok(),
+            SubstateRef::KeyValueStoreEntry(entry) => entry
+                .0
+                .as_ref()
+                .and_then(|raw| IndexedScryptoValue::from_slice(raw).ok()),
+            substate_ref => Some(substate_ref.to_scrypto_value()),
+        };
+
+        let field = value.as_ref().and_then(|value| {
+            SborPath::new(path.clone())
+                .get_from_value(value.as_value())
+                .map(|field| scrypto_encode(field).expect("Failed to encode substate field"))
+        });
+
+        field.ok_or(RuntimeError::KernelError(
+            KernelError::InvalidSubstateFieldPath(path),
+        ))
+    }
+
     fn sys_write(&mut self, lock_handle: LockHandle, buffer: Vec<u8>) -> Result<(), RuntimeError> {
         let offset = self.get_lock_info(lock_handle)?.offset;
         let substate = RuntimeSubstate::decode_from_buffer(&offset, &buffer)?;
+        self.overwrite_substate(lock_handle, substate)
+    }
+
+    fn sys_write_batch(&mut self, writes: Vec<(LockHandle, Vec<u8>)>) -> Result<(), RuntimeError> {
+        // All writes are checked before any is applied, so that a failing write does not leave the
+        // writes before it applied.
+        let mut substates = Vec::with_capacity(writes.len());
+        for (lock_handle, buffer) in writes {
+            let lock_info = self.get_lock_info(lock_handle)?;
+            if !lock_info.flags.contains(LockFlags::MUTABLE) {
+                return Err(RuntimeError::KernelError(KernelError::LockNotMutable(
+                    lock_handle,
+                )));
+            }
+            let substate = RuntimeSubstate::decode_from_buffer(&lock_info.offset, &buffer)?;
+            check_overwritable(&substate)?;
+            substates.push((lock_handle, substate));
+        }
+
+        for (lock_handle, substate) in substates {
+            self.overwrite_substate(lock_handle, substate)?;
+        }
+
+        Ok(())
+    }
+
+    fn sys_drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), RuntimeError> {
+        self.drop_lock(lock_handle)
+    }
+}
+
+impl<'g, 's, W, R, M> Kernel<'g, 's, W, R, M>
+where
+    W: WasmEngine,
+    R: FeeReserve,
+    M: BaseModule<R>,
+{
+    fn overwrite_substate(
+        &mut self,
+        lock_handle: LockHandle,
+        substate: RuntimeSubstate,
+    ) -> Result<(), RuntimeError> {
         let mut substate_mut = self.get_ref_mut(lock_handle)?;
+        check_overwritable(&substate)?;
 
         match substate {
             RuntimeSubstate::ComponentState(next) => *substate_mut.component_state() = next,
@@ -138,13 +220,19 @@ where
             RuntimeSubstate::NonFungible(next) => {
                 *substate_mut.non_fungible() = next;
             }
-            _ => return Err(RuntimeError::KernelError(KernelError::InvalidOverwrite)),
+            _ => unreachable!("checked by check_overwritable"),
         }
 
         Ok(())
     }
+}
 
-    fn sys_drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), RuntimeError> {
-        self.drop_lock(lock_handle)
+/// Checks that the substate is of a kind which blueprints are allowed to overwrite.
+fn check_overwritable(substate: &RuntimeSubstate) -> Result<(), RuntimeError> {
+    match substate {
+        RuntimeSubstate::ComponentState(..)
+        | RuntimeSubstate::KeyValueStoreEntry(..)
+        | RuntimeSubstate::NonFungible(..) => Ok(()),
+        _ => Err(RuntimeError::KernelError(KernelError::InvalidOverwrite)),
     }
 }
@@ -1,6 +1,10 @@
 use crate::engine::*;
+use crate::fee::FeeReserveError;
 use crate::types::*;
-use crate::wasm::{WasmEngine, WasmInstance, WasmInstrumenter, WasmMeteringConfig, WasmRuntime};
+use crate::wasm::{
+    wasm_invoke_error_into_runtime_error, InstrumentationStats, WasmEngine, WasmInstance,
+    WasmInstrumenter, WasmMeteringConfig, WasmRuntime,
+};
 use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::api::{ActorApi, ComponentApi, EngineApi, InvokableModel};
 use radix_engine_interface::data::{match_schema_with_value, ScryptoValue};
@@ -49,6 +53,10 @@ impl Executor for ScryptoExecutor {
             .vm()
             .create_instance(self.package_address, &package.code);
 
+        // The runtime borrows the api for the whole invocation, so this is the last point at which
+        // the invocation can be vetoed.
+        api.on_wasm_invocation()?;
+
         let output = {
             let mut runtime: Box<dyn WasmRuntime> = Box::new(RadixEngineWasmRuntime::new(api));
 
@@ -68,7 +76,9 @@ impl Executor for ScryptoExecutor {
                     .expect("Failed to allocate buffer"),
             );
 
-            instance.invoke_export(&self.export_name, input, &mut runtime)?
+            instance
+                .invoke_export(&self.export_name, input, &mut runtime)
+                .map_err(wasm_invoke_error_into_runtime_error)?
         };
         let output = IndexedScryptoValue::from_vec(output).map_err(|e| {
             RuntimeError::InterpreterError(InterpreterError::InvalidScryptoReturn(e))
@@ -98,12 +108,18 @@ impl Executor for ScryptoExecutor {
     }
 }
 
+/// A check of the remaining cost units of the fee reserve, run before every WASM invocation. An
+/// error aborts the invocation.
+pub type FeeReserveCheck = fn(remaining_cost_units: u32) -> Result<(), FeeReserveError>;
+
 pub struct ScryptoInterpreter<W: WasmEngine> {
     pub wasm_engine: W,
     /// WASM Instrumenter
     pub wasm_instrumenter: WasmInstrumenter,
     /// WASM metering config
     pub wasm_metering_config: WasmMeteringConfig,
+    /// Optional fee reserve check, for custom metering policies
+    pub fee_reserve_check: Option<FeeReserveCheck>,
 }
 
 impl<W: WasmEngine + Default> Default for ScryptoInterpreter<W> {
@@ -112,6 +128,7 @@ impl<W: WasmEngine + Default> Default for ScryptoInterpreter<W> {
             wasm_engine: W::default(),
             wasm_instrumenter: WasmInstrumenter::default(),
             wasm_metering_config: WasmMeteringConfig::default(),
+            fee_reserve_check: None,
         }
     }
 }
@@ -123,6 +140,14 @@ impl<W: WasmEngine> ScryptoInterpreter<W> {
                 .instrument(package_address, code, self.wasm_metering_config);
         self.wasm_engine.instantiate(&instrumented_code)
     }
+
+    /// Reports the overhead that instrumenting the code with this interpreter's metering config
+    /// adds, without instantiating it or touching the instrumented code cache.
+    pub fn instrumentation_stats(&self, code: &[u8]) -> InstrumentationStats {
+        self.wasm_instrumenter
+            .instrument_with_stats(code, self.wasm_metering_config.parameters())
+            .1
+    }
 }
 
 #[cfg(test)]
@@ -120,6 +120,15 @@ where
         Ok(())
     }
 
+    fn drop_nodes(&mut self, node_ids: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>> {
+        let node_ids =
+            scrypto_decode::<Vec<RENodeId>>(&node_ids).map_err(WasmRuntimeError::InvalidNodeId)?;
+
+        self.api.sys_drop_nodes(node_ids)?;
+
+        Ok(())
+    }
+
     fn lock_substate(
         &mut self,
         node_id: Vec<u8>,
@@ -145,6 +154,19 @@ where
         self.allocate_buffer(substate)
     }
 
+    fn read_substate_field(
+        &mut self,
+        handle: LockHandle,
+        path: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let path = scrypto_decode::<Vec<usize>>(&path)
+            .map_err(WasmRuntimeError::InvalidSubstateFieldPath)?;
+
+        let field = self.api.sys_read_field(handle, path)?;
+
+        self.allocate_buffer(field)
+    }
+
     fn write_substate(
         &mut self,
         handle: LockHandle,
@@ -155,6 +177,18 @@ where
         Ok(())
     }
 
+    fn write_substate_batch(
+        &mut self,
+        writes: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        let writes = scrypto_decode::<Vec<(LockHandle, Vec<u8>)>>(&writes)
+            .map_err(WasmRuntimeError::InvalidSubstateWriteBatch)?;
+
+        self.api.sys_write_batch(writes)?;
+
+        Ok(())
+    }
+
     fn unlock_substate(&mut self, handle: LockHandle) -> Result<(), InvokeError<WasmRuntimeError>> {
         self.api.sys_drop_lock(handle)?;
 
@@ -234,6 +268,10 @@ impl WasmRuntime for NopWasmRuntime {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn drop_nodes(&mut self, node_ids: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn lock_substate(
         &mut self,
         node_id: Vec<u8>,
@@ -247,6 +285,14 @@ impl WasmRuntime for NopWasmRuntime {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn read_substate_field(
+        &mut self,
+        handle: u32,
+        path: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn write_substate(
         &mut self,
         handle: u32,
@@ -255,6 +301,13 @@ impl WasmRuntime for NopWasmRuntime {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
 
+    fn write_substate_batch(
+        &mut self,
+        writes: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
+    }
+
     fn unlock_substate(&mut self, handle: u32) -> Result<(), InvokeError<WasmRuntimeError>> {
         Err(InvokeError::SelfError(WasmRuntimeError::NotImplemented))
     }
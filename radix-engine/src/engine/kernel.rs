@@ -15,7 +15,7 @@ use transaction::model::AuthZoneParams;
 use crate::engine::node_move_module::NodeMoveModule;
 use crate::engine::system_api::LockInfo;
 use crate::engine::*;
-use crate::fee::FeeReserve;
+use crate::fee::{ExecutionFeeReserve, FeeReserve};
 use crate::model::*;
 use crate::types::*;
 use crate::wasm::*;
@@ -52,6 +52,13 @@ pub struct Kernel<
     module: &'g mut M,
 }
 
+/// The part of a call frame's lifecycle deferred by [`Kernel::run_no_teardown`].
+#[must_use]
+pub struct PendingTeardown {
+    update: CallFrameUpdate,
+    derefed_lock: Option<LockHandle>,
+}
+
 impl<'g, 's, W, R, M> Kernel<'g, 's, W, R, M>
 where
     W: WasmEngine,
@@ -340,8 +347,27 @@ where
         &mut self,
         executor: X,
         actor: ResolvedActor,
-        mut call_frame_update: CallFrameUpdate,
+        call_frame_update: CallFrameUpdate,
     ) -> Result<X::Output, RuntimeError> {
+        let (output, pending_teardown) =
+            self.run_no_teardown(executor, actor, call_frame_update)?;
+        self.teardown(pending_teardown)?;
+        Ok(output)
+    }
+
+    /// Runs an executor in a new call frame like `run` does, but stops before the frame is popped so that it can be
+    /// inspected through [`Kernel::current_frame`], e.g. when stepping through a transaction.
+    ///
+    /// The auth zone frame of the callee is kept and the nodes it still owns are neither moved upstream nor dropped
+    /// until [`Kernel::teardown`] is called with the returned [`PendingTeardown`]. Nothing else may be executed in
+    /// the meantime, and a pending teardown which is never finalized leaves the frame on the stack, skipping the
+    /// resource leak check on its owned nodes.
+    pub fn run_no_teardown<X: Executor>(
+        &mut self,
+        executor: X,
+        actor: ResolvedActor,
+        mut call_frame_update: CallFrameUpdate,
+    ) -> Result<(X::Output, PendingTeardown), RuntimeError> {
         let derefed_lock = if let Some(ResolvedReceiver {
             derefed_from: Some((_, derefed_lock)),
             ..
@@ -430,6 +456,27 @@ where
             self.execute_in_mode(ExecutionMode::NodeMoveModule, |system_api| {
                 NodeMoveModule::on_call_frame_exit(&update, system_api)
             })?;
+        }
+
+        Ok((
+            output,
+            PendingTeardown {
+                update,
+                derefed_lock,
+            },
+        ))
+    }
+
+    /// Finalizes a call frame left in place by [`Kernel::run_no_teardown`]: pops its auth zone frame, moves the
+    /// returned nodes upstream, drops the remaining owned nodes and restores the parent frame.
+    pub fn teardown(&mut self, pending_teardown: PendingTeardown) -> Result<(), RuntimeError> {
+        let PendingTeardown {
+            update,
+            derefed_lock,
+        } = pending_teardown;
+
+        // Call Frame post-processing
+        {
             self.execute_in_mode(ExecutionMode::AuthModule, |system_api| {
                 AuthModule::on_call_frame_exit(system_api)
             })?;
@@ -437,6 +484,9 @@ where
             // Auto-drop locks again in case module forgot to drop
             self.current_frame
                 .drop_all_locks(&mut self.heap, &mut self.track)?;
+
+            #[cfg(debug_assertions)]
+            self.current_frame.check_dangling_refs(&self.heap)?;
         }
 
         // Call Frame Pop
@@ -456,7 +506,11 @@ where
                 .drop_lock(&mut self.heap, &mut self.track, derefed_lock)?;
         }
 
-        Ok(output)
+        Ok(())
+    }
+
+    pub fn current_frame(&self) -> &CallFrame {
+        &self.current_frame
     }
 
     pub fn node_method_deref(
@@ -648,6 +702,19 @@ where
 
         Ok(())
     }
+
+    fn on_wasm_invocation(&mut self) -> Result<(), RuntimeError> {
+        if let Some(fee_reserve_check) = self.scrypto_interpreter.fee_reserve_check {
+            let remaining_cost_units = self.track.fee_reserve().remaining_cost_units();
+            fee_reserve_check(remaining_cost_units).map_err(|e| {
+                RuntimeError::ModuleError(ModuleError::CostingError(CostingError::FeeReserveError(
+                    e,
+                )))
+            })?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'g, 's, W, R, M> ResolverApi for Kernel<'g, 's, W, R, M>
@@ -783,6 +850,30 @@ where
         Ok(fee)
     }
 
+    fn get_actor_context(&mut self) -> Result<ResolvedActor, RuntimeError> {
+        self.module
+            .pre_sys_call(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                SysCallInput::ReadActorContext,
+            )
+            .map_err(RuntimeError::ModuleError)?;
+
+        let actor = self.current_frame.current_actor_context();
+
+        self.module
+            .post_sys_call(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                SysCallOutput::ReadActorContext,
+            )
+            .map_err(RuntimeError::ModuleError)?;
+
+        Ok(actor)
+    }
+
     fn get_visible_nodes(&mut self) -> Result<Vec<RENodeId>, RuntimeError> {
         self.module
             .pre_sys_call(
@@ -1258,3 +1349,80 @@ where
         Ok(self.current_frame.actor.identifier.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fee::{FeeTable, SystemLoanFeeReserve};
+    use crate::ledger::TypedInMemorySubstateStore;
+    use crate::transaction::ExecutionConfig;
+
+    struct CreateEmptyBucketExecutor;
+
+    impl Executor for CreateEmptyBucketExecutor {
+        type Output = ();
+
+        fn execute<Y, W>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+        where
+            Y: SystemApi
+                + EngineApi<RuntimeError>
+                + InvokableModel<RuntimeError>
+                + ActorApi<RuntimeError>
+                + ComponentApi<RuntimeError>
+                + VmApi<W>,
+            W: WasmEngine,
+        {
+            let node_id = api.allocate_node_id(RENodeType::Bucket)?;
+            api.create_node(
+                node_id,
+                RENodeInit::Bucket(BucketSubstate::new(Resource::new_empty(
+                    RADIX_TOKEN,
+                    ResourceType::Fungible { divisibility: 18 },
+                ))),
+            )?;
+            Ok(((), CallFrameUpdate::empty()))
+        }
+    }
+
+    #[test]
+    fn frame_run_without_teardown_can_be_inspected_then_torn_down() {
+        let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+        let substate_store = TypedInMemorySubstateStore::new();
+        let mut track = Track::new(
+            &substate_store,
+            SystemLoanFeeReserve::no_fee(),
+            FeeTable::new(),
+        );
+        let mut id_allocator = IdAllocator::new(Hash([0u8; 32]), BTreeSet::new());
+        let mut module = KernelModule::new(&ExecutionConfig::default());
+        let mut kernel = Kernel::new(
+            AuthZoneParams {
+                initial_proofs: vec![],
+                virtualizable_proofs_resource_addresses: BTreeSet::new(),
+            },
+            &mut id_allocator,
+            &mut track,
+            &scrypto_interpreter,
+            &mut module,
+        );
+        let root_owned_nodes = kernel.current_frame().owned_nodes().len();
+
+        let ((), pending_teardown) = kernel
+            .run_no_teardown(
+                CreateEmptyBucketExecutor,
+                ResolvedActor::function(NativeFn::Bucket(BucketFn::CreateProof)),
+                CallFrameUpdate::empty(),
+            )
+            .unwrap();
+
+        assert_eq!(kernel.current_frame().depth, 1);
+        let owned_nodes = kernel.current_frame().owned_nodes();
+        assert_eq!(owned_nodes.len(), 1);
+        assert!(matches!(owned_nodes[0], RENodeId::Bucket(..)));
+
+        kernel.teardown(pending_teardown).unwrap();
+
+        assert_eq!(kernel.current_frame().depth, 0);
+        assert_eq!(kernel.current_frame().owned_nodes().len(), root_owned_nodes);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::engine::*;
+use crate::model::{Resource, RuntimeSubstate, SubstateRef, SubstateRefMut};
+use crate::types::*;
+use radix_engine_interface::api::types::{
+    LockHandle, RENodeId, RENodeType, ScryptoRENode, SubstateId, SubstateOffset, VaultId,
+};
+use radix_engine_interface::api::{EngineApi, Invocation, Invokable, InvokableModel};
+
+/// A substate operation performed against a [`MockTrack`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockTrackOperation {
+    Acquire {
+        node_id: RENodeId,
+        offset: SubstateOffset,
+        flags: LockFlags,
+    },
+    Read(LockHandle),
+    Write(LockHandle),
+    Release(LockHandle),
+}
+
+/// An in-memory stand-in for the kernel and [`Track`], for unit testing executors without a full
+/// engine. Only substate locking, reading and writing are supported, and every such operation is
+/// recorded in order so that tests can assert the exact substate access pattern of an executor.
+/// Any other system call panics.
+#[derive(Default)]
+pub struct MockTrack {
+    substates: BTreeMap<SubstateId, RuntimeSubstate>,
+    locks: BTreeMap<LockHandle, (SubstateId, LockFlags)>,
+    next_lock_handle: LockHandle,
+    operations: Vec<MockTrackOperation>,
+}
+
+impl MockTrack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert_substate<S: Into<RuntimeSubstate>>(
+        &mut self,
+        node_id: RENodeId,
+        offset: SubstateOffset,
+        substate: S,
+    ) {
+        self.substates
+            .insert(SubstateId(node_id, offset), substate.into());
+    }
+
+    pub fn substate(&self, node_id: RENodeId, offset: SubstateOffset) -> Option<SubstateRef> {
+        self.substates
+            .get(&SubstateId(node_id, offset))
+            .map(|substate| substate.to_ref())
+    }
+
+    /// The substate operations performed so far, in the order they were performed.
+    pub fn operations(&self) -> &[MockTrackOperation] {
+        &self.operations
+    }
+
+    /// Whether any lock is still held.
+    pub fn has_open_locks(&self) -> bool {
+        !self.locks.is_empty()
+    }
+
+    fn substate_id(&self, lock_handle: LockHandle) -> Result<&SubstateId, RuntimeError> {
+        self.locks
+            .get(&lock_handle)
+            .map(|(substate_id, _)| substate_id)
+            .ok_or(RuntimeError::KernelError(KernelError::LockDoesNotExist(
+                lock_handle,
+            )))
+    }
+}
+
+impl SystemApi for MockTrack {
+    fn consume_cost_units(&mut self, _units: u32) -> Result<(), RuntimeError> {
+        Ok(())
+    }
+
+    fn lock_fee(
+        &mut self,
+        _vault_id: VaultId,
+        _fee: Resource,
+        _contingent: bool,
+    ) -> Result<Resource, RuntimeError> {
+        unimplemented!("MockTrack does not support locking fees")
+    }
+
+    fn get_actor_context(&mut self) -> Result<ResolvedActor, RuntimeError> {
+        unimplemented!("MockTrack has no actor")
+    }
+
+    fn get_visible_nodes(&mut self) -> Result<Vec<RENodeId>, RuntimeError> {
+        unimplemented!("MockTrack has no call frame")
+    }
+
+    fn get_visible_node_data(
+        &mut self,
+        _node_id: RENodeId,
+    ) -> Result<RENodeVisibilityOrigin, RuntimeError> {
+        unimplemented!("MockTrack has no call frame")
+    }
+
+    fn drop_node(&mut self, _node_id: RENodeId) -> Result<HeapRENode, RuntimeError> {
+        unimplemented!("MockTrack has no heap")
+    }
+
+    fn allocate_node_id(&mut self, _node_type: RENodeType) -> Result<RENodeId, RuntimeError> {
+        unimplemented!("MockTrack has no heap")
+    }
+
+    fn create_node(
+        &mut self,
+        _node_id: RENodeId,
+        _re_node: RENodeInit,
+    ) -> Result<(), RuntimeError> {
+        unimplemented!("MockTrack has no heap")
+    }
+
+    fn lock_substate(
+        &mut self,
+        node_id: RENodeId,
+        offset: SubstateOffset,
+        flags: LockFlags,
+    ) -> Result<LockHandle, RuntimeError> {
+        let substate_id = SubstateId(node_id, offset.clone());
+        if !self.substates.contains_key(&substate_id) {
+            return Err(RuntimeError::KernelError(KernelError::TrackError(
+                TrackError::NotFound(substate_id),
+            )));
+        }
+
+        let lock_handle = self.next_lock_handle;
+        self.next_lock_handle += 1;
+        self.locks.insert(lock_handle, (substate_id, flags));
+        self.operations.push(MockTrackOperation::Acquire {
+            node_id,
+            offset,
+            flags,
+        });
+        Ok(lock_handle)
+    }
+
+    fn get_lock_info(&mut self, lock_handle: LockHandle) -> Result<LockInfo, RuntimeError> {
+        let (substate_id, flags) =
+            self.locks
+                .get(&lock_handle)
+                .ok_or(RuntimeError::KernelError(KernelError::LockDoesNotExist(
+                    lock_handle,
+                )))?;
+        Ok(LockInfo {
+            offset: substate_id.1.clone(),
+            flags: *flags,
+        })
+    }
+
+    fn drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), RuntimeError> {
+        self.locks
+            .remove(&lock_handle)
+            .ok_or(RuntimeError::KernelError(KernelError::LockDoesNotExist(
+                lock_handle,
+            )))?;
+        self.operations
+            .push(MockTrackOperation::Release(lock_handle));
+        Ok(())
+    }
+
+    fn get_ref(&mut self, lock_handle: LockHandle) -> Result<SubstateRef, RuntimeError> {
+        let substate_id = self.substate_id(lock_handle)?.clone();
+        self.operations.push(MockTrackOperation::Read(lock_handle));
+        Ok(self
+            .substates
+            .get(&substate_id)
+            .expect("Locked substate is missing")
+            .to_ref())
+    }
+
+    fn get_ref_mut(&mut self, lock_handle: LockHandle) -> Result<SubstateRefMut, RuntimeError> {
+        let (substate_id, flags) =
+            self.locks
+                .get(&lock_handle)
+                .cloned()
+                .ok_or(RuntimeError::KernelError(KernelError::LockDoesNotExist(
+                    lock_handle,
+                )))?;
+        if !flags.contains(LockFlags::MUTABLE) {
+            return Err(RuntimeError::KernelError(KernelError::LockNotMutable(
+                lock_handle,
+            )));
+        }
+        self.operations.push(MockTrackOperation::Write(lock_handle));
+        Ok(self
+            .substates
+            .get_mut(&substate_id)
+            .expect("Locked substate is missing")
+            .to_ref_mut())
+    }
+}
+
+impl EngineApi<RuntimeError> for MockTrack {
+    fn sys_create_node(&mut self, _node: ScryptoRENode) -> Result<RENodeId, RuntimeError> {
+        unimplemented!("MockTrack has no heap")
+    }
+
+    fn sys_drop_node(&mut self, _node_id: RENodeId) -> Result<(), RuntimeError> {
+        unimplemented!("MockTrack has no heap")
+    }
+
+    fn sys_drop_nodes(&mut self, _node_ids: Vec<RENodeId>) -> Result<(), RuntimeError> {
+        unimplemented!("MockTrack has no heap")
+    }
+
+    fn sys_get_visible_nodes(&mut self) -> Result<Vec<RENodeId>, RuntimeError> {
+        unimplemented!("MockTrack has no call frame")
+    }
+
+    fn sys_lock_substate(
+        &mut self,
+        _node_id: RENodeId,
+        _offset: SubstateOffset,
+        _mutable: bool,
+    ) -> Result<LockHandle, RuntimeError> {
+        unimplemented!("MockTrack does not support encoded substate access")
+    }
+
+    fn sys_read(&mut self, _lock_handle: LockHandle) -> Result<Vec<u8>, RuntimeError> {
+        unimplemented!("MockTrack does not support encoded substate access")
+    }
+
+    fn sys_read_field(
+        &mut self,
+        _lock_handle: LockHandle,
+        _path: Vec<usize>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        unimplemented!("MockTrack does not support encoded substate access")
+    }
+
+    fn sys_write(
+        &mut self,
+        _lock_handle: LockHandle,
+        _buffer: Vec<u8>,
+    ) -> Result<(), RuntimeError> {
+        unimplemented!("MockTrack does not support encoded substate access")
+    }
+
+    fn sys_write_batch(&mut self, _writes: Vec<(LockHandle, Vec<u8>)>) -> Result<(), RuntimeError> {
+        unimplemented!("MockTrack does not support encoded substate access")
+    }
+
+    fn sys_drop_lock(&mut self, _lock_handle: LockHandle) -> Result<(), RuntimeError> {
+        unimplemented!("MockTrack does not support encoded substate access")
+    }
+}
+
+impl<N: Invocation> Invokable<N, RuntimeError> for MockTrack {
+    fn invoke(&mut self, _invocation: N) -> Result<N::Output, RuntimeError> {
+        unimplemented!("MockTrack does not support invocations")
+    }
+}
+
+impl InvokableModel<RuntimeError> for MockTrack {}
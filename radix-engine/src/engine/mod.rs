@@ -6,6 +6,8 @@ mod heap;
 mod id_allocator;
 mod interpreters;
 mod kernel;
+#[cfg(test)]
+mod mock_track;
 mod modules;
 mod node;
 mod node_properties;
@@ -20,6 +22,8 @@ pub use heap::*;
 pub use id_allocator::*;
 pub use interpreters::*;
 pub use kernel::*;
+#[cfg(test)]
+pub use mock_track::*;
 pub use modules::*;
 pub use node::*;
 pub use node_properties::*;
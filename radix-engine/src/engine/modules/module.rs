@@ -12,6 +12,7 @@ pub enum SysCallInput<'a> {
         depth: usize,
     },
     ReadOwnedNodes,
+    ReadActorContext,
     DropNode {
         node_id: &'a RENodeId,
     },
@@ -41,6 +42,7 @@ pub enum SysCallInput<'a> {
 pub enum SysCallOutput<'a> {
     Invoke { rtn: &'a dyn Debug },
     ReadOwnedNodes,
+    ReadActorContext,
     DropNode { node: &'a HeapRENode },
     CreateNode { node_id: &'a RENodeId },
     LockSubstate { lock_handle: LockHandle },
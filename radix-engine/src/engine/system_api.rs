@@ -29,6 +29,7 @@ impl LockFlags {
 
 pub struct LockInfo {
     pub offset: SubstateOffset,
+    pub flags: LockFlags,
 }
 
 pub trait SystemApi {
@@ -41,6 +42,9 @@ pub trait SystemApi {
         contingent: bool,
     ) -> Result<Resource, RuntimeError>;
 
+    /// Retrieves the resolved actor of the current frame
+    fn get_actor_context(&mut self) -> Result<ResolvedActor, RuntimeError>;
+
     /// Retrieves all nodes referenceable by the current frame
     fn get_visible_nodes(&mut self) -> Result<Vec<RENodeId>, RuntimeError>;
 
@@ -81,6 +85,8 @@ pub trait SystemApi {
 
 pub trait VmApi<W: WasmEngine> {
     fn on_wasm_instantiation(&mut self, code: &[u8]) -> Result<(), RuntimeError>;
+    /// Called just before invoking an export of a WASM instance.
+    fn on_wasm_invocation(&mut self) -> Result<(), RuntimeError>;
     fn vm(&mut self) -> &ScryptoInterpreter<W>;
 }
 
@@ -67,6 +67,9 @@ pub trait ExecutionFeeReserve {
         fee: Resource,
         contingent: bool,
     ) -> Result<Resource, FeeReserveError>;
+
+    /// The number of cost units which can still be consumed before the cost unit limit is reached.
+    fn remaining_cost_units(&self) -> u32;
 }
 
 pub trait FinalizingFeeReserve {
@@ -358,6 +361,11 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
 
         Ok(fee)
     }
+
+    fn remaining_cost_units(&self) -> u32 {
+        self.cost_unit_limit
+            .saturating_sub(self.cost_units_consumed)
+    }
 }
 
 impl FinalizingFeeReserve for SystemLoanFeeReserve {
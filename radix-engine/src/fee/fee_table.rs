@@ -12,6 +12,8 @@ pub enum SystemApiCostingEntry {
      * RENode
      */
     ReadOwnedNodes,
+    /// Reads the actor of the current call frame.
+    ReadActorContext,
     /// Creates a RENode.
     CreateNode {
         size: u32,
@@ -107,6 +109,7 @@ impl FeeTable {
                     AuthZoneStackFn::CreateProofByIds => self.fixed_high,
                     AuthZoneStackFn::Clear => self.fixed_high,
                     AuthZoneStackFn::Drain => self.fixed_high,
+                    AuthZoneStackFn::List => self.fixed_high,
                     AuthZoneStackFn::AssertAccessRule => self.fixed_high,
                 }
             }
@@ -150,6 +153,11 @@ impl FeeTable {
                 ProofFn::GetNonFungibleLocalIds => self.fixed_low,
                 ProofFn::GetResourceAddress => self.fixed_low,
                 ProofFn::Clone => self.fixed_low,
+                ProofFn::MakeOneShot => self.fixed_low,
+                ProofFn::IntoResourceProof => self.fixed_low,
+                ProofFn::ValidateAgainstStoredRule => self.fixed_medium,
+                ProofFn::SatisfiedRoles => self.fixed_medium,
+                ProofFn::IsPresence => self.fixed_low,
             },
             NativeFn::ResourceManager(resource_manager_ident) => match resource_manager_ident {
                 ResourceManagerFn::CreateNonFungible => self.fixed_high, // TODO: more investigation about fungibility
@@ -167,6 +175,8 @@ impl FeeTable {
                 ResourceManagerFn::MintFungible => self.fixed_high,
                 ResourceManagerFn::GetResourceType => self.fixed_low,
                 ResourceManagerFn::GetTotalSupply => self.fixed_low,
+                ResourceManagerFn::GetBehavior => self.fixed_low,
+                ResourceManagerFn::IsFrozen => self.fixed_low,
                 ResourceManagerFn::UpdateNonFungibleData => self.fixed_medium,
                 ResourceManagerFn::NonFungibleExists => self.fixed_low,
                 ResourceManagerFn::GetNonFungible => self.fixed_medium,
@@ -200,6 +210,7 @@ impl FeeTable {
             NativeFn::Component(method_ident) => match method_ident {
                 ComponentFn::Globalize => self.fixed_high,
                 ComponentFn::GlobalizeWithOwner => self.fixed_high,
+                ComponentFn::GlobalizeWithAccessRules => self.fixed_high,
                 ComponentFn::SetRoyaltyConfig => self.fixed_medium,
                 ComponentFn::ClaimRoyalty => self.fixed_medium,
             },
@@ -237,12 +248,14 @@ impl FeeTable {
                 AccessControllerFn::CreateGlobal => self.fixed_low,
 
                 AccessControllerFn::CreateProof => self.fixed_low,
+                AccessControllerFn::CreateProofs => self.fixed_low,
 
                 AccessControllerFn::InitiateRecoveryAsPrimary => self.fixed_low,
                 AccessControllerFn::InitiateRecoveryAsRecovery => self.fixed_low,
 
                 AccessControllerFn::QuickConfirmPrimaryRoleRecoveryProposal => self.fixed_low,
                 AccessControllerFn::QuickConfirmRecoveryRoleRecoveryProposal => self.fixed_low,
+                AccessControllerFn::BatchConfirmRecovery => self.fixed_low,
 
                 AccessControllerFn::TimedConfirmRecovery => self.fixed_low,
 
@@ -253,6 +266,37 @@ impl FeeTable {
                 AccessControllerFn::UnlockPrimaryRole => self.fixed_low,
 
                 AccessControllerFn::StopTimedRecovery => self.fixed_low,
+                AccessControllerFn::ExtendTimedRecovery => self.fixed_low,
+
+                AccessControllerFn::GetRoleRule => self.fixed_low,
+
+                AccessControllerFn::EndorseRecoveryRoleRecoveryProposal => self.fixed_low,
+
+                AccessControllerFn::ExportState => self.fixed_low,
+
+                AccessControllerFn::GetControlledResourceManager => self.fixed_low,
+                AccessControllerFn::VerifyControlledAsset => self.fixed_low,
+                AccessControllerFn::GetControlledVaults => self.fixed_low,
+
+                AccessControllerFn::ValidateProposal => self.fixed_low,
+
+                AccessControllerFn::TransferOwnership => self.fixed_low,
+
+                AccessControllerFn::CheckRole => self.fixed_low,
+                AccessControllerFn::CanActAs => self.fixed_low,
+
+                AccessControllerFn::InitiateRecoveryAsDelegate => self.fixed_low,
+                AccessControllerFn::AddRecoveryDelegate => self.fixed_low,
+                AccessControllerFn::RemoveRecoveryDelegate => self.fixed_low,
+
+                AccessControllerFn::GetConfig => self.fixed_low,
+                AccessControllerFn::GetDefaultRule => self.fixed_low,
+
+                AccessControllerFn::Pause => self.fixed_low,
+                AccessControllerFn::Resume => self.fixed_low,
+                AccessControllerFn::GetTimingInfo => self.fixed_low,
+                AccessControllerFn::GetProposalAuthors => self.fixed_low,
+                AccessControllerFn::GetTimeline => self.fixed_low,
             },
         }
     }
@@ -264,6 +308,7 @@ impl FeeTable {
             }
 
             SystemApiCostingEntry::ReadOwnedNodes => self.fixed_low,
+            SystemApiCostingEntry::ReadActorContext => self.fixed_low,
             SystemApiCostingEntry::CreateNode { .. } => self.fixed_medium,
             SystemApiCostingEntry::DropNode { .. } => self.fixed_medium,
 
@@ -4,15 +4,25 @@ use crate::engine::{deref_and_update, ApplicationError, Executor, LockFlags, REN
 use crate::engine::{
     CallFrameUpdate, ExecutableInvocation, ResolvedActor, ResolverApi, RuntimeError, SystemApi,
 };
-use crate::model::{AccessRulesChainSubstate, GlobalAddressSubstate};
+use crate::model::{
+    convert_contextless, AccessRulesChainSubstate, AuthZoneStackSubstate, GlobalAddressSubstate,
+};
 use crate::wasm::WasmEngine;
 use native_sdk::resource::{SysBucket, Vault};
 use radix_engine_interface::api::types::*;
 use radix_engine_interface::constants::{CLOCK, PACKAGE_TOKEN};
 use radix_engine_interface::data::scrypto_encode;
+use radix_engine_interface::math::Decimal;
+use radix_engine_interface::time::Instant;
 use radix_engine_interface::*;
 use radix_engine_interface::{api::*, rule};
+use sbor::rust::collections::{BTreeMap, BTreeSet, HashSet};
+use sbor::rust::format;
 
+/// Errors raised by the access controller's state machine when an authorized caller attempts an
+/// operation which is invalid in the current state of the controller. Callers who fail the access
+/// rules of a method never reach the state machine, and instead fail with the auth module's
+/// [`AuthError::Unauthorized`](crate::engine::AuthError::Unauthorized).
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub enum AccessControllerError {
     /// Occurs when some action requires that the primary role is unlocked to happen.
@@ -41,6 +51,92 @@ pub enum AccessControllerError {
         expected: RecoveryProposal,
         found: RecoveryProposal,
     },
+
+    /// Occurs when self-confirmation is prevented on the controller and a recovery proposal is
+    /// quick-confirmed with a proof of a non-fungible that was used to propose it.
+    SelfConfirmationNotAllowed { proposer: Proposer },
+
+    /// Occurs when a recovery proposal is endorsed with a non-fungible which is not present in the
+    /// auth zone of the caller.
+    EndorserNotPresentInAuthZone { endorser: NonFungibleGlobalId },
+
+    /// Occurs when a recovery proposal is endorsed with a non-fungible which doesn't satisfy the
+    /// rule of the recovery role on its own.
+    EndorserDoesNotSatisfyRecoveryRole { endorser: NonFungibleGlobalId },
+
+    /// Occurs when a non-fungible attempts to endorse a recovery proposal which it has already
+    /// endorsed.
+    RecoveryProposalAlreadyEndorsed { endorser: NonFungibleGlobalId },
+
+    /// Occurs when confirming a recovery proposal of the recovery role which has not yet collected
+    /// the number of endorsements required by the controller.
+    RecoveryEndorsementThresholdNotMet { endorsements: u8, threshold: u8 },
+
+    /// Occurs when confirming a recovery proposal before the controller's minimum interval since
+    /// the previous recovery has elapsed.
+    RecoveryCooldownActive { recovery_allowed_at: Instant },
+
+    /// Occurs when a recovery is initiated as a delegate by a caller whose auth zone holds none of
+    /// the controller's recovery delegates.
+    CallerIsNotARecoveryDelegate,
+
+    /// Occurs when adding a recovery delegate which is already a delegate of the controller.
+    RecoveryDelegateAlreadyExists { delegate: NonFungibleGlobalId },
+
+    /// Occurs when removing a recovery delegate which is not a delegate of the controller.
+    RecoveryDelegateNotFound { delegate: NonFungibleGlobalId },
+
+    /// Occurs when initiating a recovery while the controller already has the maximum number of
+    /// recovery proposals outstanding.
+    TooManyProposals,
+
+    /// Occurs when a recovery proposal would shorten the delay of timed recoveries below the delay
+    /// that the controller currently enforces.
+    DelayDowngradeNotAllowed { active: u32, proposed: u32 },
+
+    /// Occurs when initiating, confirming, endorsing or cancelling a recovery while the controller
+    /// is paused.
+    ControllerPaused,
+
+    /// Occurs when confirming a recovery proposal with a nonce other than the current one of its
+    /// proposer, such as one made for a proposal which has since been cancelled.
+    StaleProposal { expected: u64, found: u64 },
+
+    /// Occurs when an operation is attempted which the controller can't transition to from its
+    /// current state, such as extending a timed recovery when none is underway.
+    InvalidStateTransition,
+
+    /// Occurs when a timed recovery is "extended" by a number of minutes which is not positive, as
+    /// the delay of a timed recovery can only ever be lengthened.
+    TimedRecoveryCanOnlyBeExtended { additional_minutes: i64 },
+
+    /// Occurs when an access controller is created without any asset to control.
+    NoControlledAssets,
+
+    /// Occurs when an access controller is created with the same bucket given as a controlled
+    /// asset more than once.
+    DuplicateControlledAsset(BucketId),
+}
+
+/// The way in which a change of the rule set was confirmed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmationKind {
+    Quick,
+    Timed,
+    /// All three roles jointly transferred ownership of the controller.
+    OwnershipTransfer,
+}
+
+/// Logged by the access controller whenever a confirmed recovery proposal or an ownership transfer
+/// replaces its rule set.
+///
+/// The engine has no application event system yet, so this is written to the application logs at
+/// [`Level::Info`] using its [`Debug`] representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSetChanged {
+    pub old: RuleSet,
+    pub new: RuleSet,
+    pub via: ConfirmationKind,
 }
 
 impl From<AccessControllerError> for RuntimeError {
@@ -63,9 +159,23 @@ impl ExecutableInvocation for AccessControllerCreateGlobalInvocation {
     where
         Self: Sized,
     {
+        let mut controlled_assets = HashSet::new();
+        for bucket_id in &self.controlled_assets {
+            if !controlled_assets.insert(*bucket_id) {
+                return Err(AccessControllerError::DuplicateControlledAsset(*bucket_id).into());
+            }
+        }
+
         let actor =
             ResolvedActor::function(NativeFn::AccessController(AccessControllerFn::CreateGlobal));
-        let call_frame_update = CallFrameUpdate::move_node(RENodeId::Bucket(self.controlled_asset));
+        let call_frame_update = CallFrameUpdate {
+            nodes_to_move: self
+                .controlled_assets
+                .iter()
+                .map(|bucket_id| RENodeId::Bucket(*bucket_id))
+                .collect(),
+            node_refs_to_copy: HashSet::new(),
+        };
 
         Ok((actor, call_frame_update, self))
     }
@@ -81,21 +191,30 @@ impl Executor for AccessControllerCreateGlobalInvocation {
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
-        // Creating a new vault and putting in it the controlled asset
-        let vault = {
-            let controlled_asset = Bucket(self.controlled_asset);
+        if self.controlled_assets.is_empty() {
+            return Err(AccessControllerError::NoControlledAssets.into());
+        }
+
+        // Creating a new vault for each controlled asset and putting the asset in it
+        let mut vaults = Vec::new();
+        for controlled_asset in self.controlled_assets {
+            let controlled_asset = Bucket(controlled_asset);
 
             let mut vault = controlled_asset
                 .sys_resource_address(api)
                 .and_then(|resource_address| Vault::sys_new(resource_address, api))?;
             vault.sys_put(controlled_asset, api)?;
 
-            vault
-        };
+            vaults.push(vault.0);
+        }
 
         // Constructing the Access Controller RENode and Substates
         let access_controller = RENodeInit::AccessController(
-            AccessControllerSubstate::new(vault.0, self.timed_recovery_delay_in_minutes),
+            AccessControllerSubstate::new(
+                vaults,
+                self.timed_recovery_delay_in_minutes,
+                self.settings,
+            ),
             AccessRulesChainSubstate {
                 access_rules_chain: [access_rules_from_rule_set(self.rule_set)].into(),
             },
@@ -172,6 +291,68 @@ impl Executor for AccessControllerCreateProofExecutable {
     }
 }
 
+//=================================
+// Access Controller Create Proofs
+//=================================
+
+pub struct AccessControllerCreateProofsExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerCreateProofsInvocation {
+    type Exec = AccessControllerCreateProofsExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::CreateProofs),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerCreateProofsExecutable {
+    type Output = Vec<Proof>;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let proofs = transition(
+            self.receiver,
+            api,
+            AccessControllerCreateProofsStateMachineInput,
+        )?;
+        let call_frame_update = CallFrameUpdate {
+            nodes_to_move: proofs
+                .iter()
+                .map(|proof| RENodeId::Proof(proof.0))
+                .collect(),
+            node_refs_to_copy: HashSet::new(),
+        };
+
+        Ok((proofs, call_frame_update))
+    }
+}
+
 //=====================================
 // Access Controller Initiate Recovery
 //=====================================
@@ -221,11 +402,20 @@ impl Executor for AccessControllerInitiateRecoveryAsPrimaryExecutable {
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        let proposer_non_fungible_global_ids = auth_zone_non_fungible_global_ids(api)?;
+        let author = proposal_author(
+            api,
+            self.receiver,
+            Role::Primary,
+            &proposer_non_fungible_global_ids,
+        )?;
         transition_mut(
             self.receiver,
             api,
             AccessControllerInitiateRecoveryAsPrimaryStateMachineInput {
                 proposal: self.proposal,
+                proposer_non_fungible_global_ids,
+                author,
             },
         )?;
 
@@ -278,11 +468,20 @@ impl Executor for AccessControllerInitiateRecoveryAsRecoveryExecutable {
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        let proposer_non_fungible_global_ids = auth_zone_non_fungible_global_ids(api)?;
+        let author = proposal_author(
+            api,
+            self.receiver,
+            Role::Recovery,
+            &proposer_non_fungible_global_ids,
+        )?;
         transition_mut(
             self.receiver,
             api,
             AccessControllerInitiateRecoveryAsRecoveryStateMachineInput {
                 proposal: self.proposal,
+                proposer_non_fungible_global_ids,
+                author,
             },
         )?;
 
@@ -297,6 +496,7 @@ impl Executor for AccessControllerInitiateRecoveryAsRecoveryExecutable {
 pub struct AccessControllerQuickConfirmPrimaryRoleRecoveryProposalExecutable {
     pub receiver: RENodeId,
     pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
 }
 
 impl ExecutableInvocation for AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation {
@@ -310,6 +510,7 @@ impl ExecutableInvocation for AccessControllerQuickConfirmPrimaryRoleRecoveryPro
         Self: Sized,
     {
         let mut call_frame_update = CallFrameUpdate::empty();
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(CLOCK)));
         let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
         let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
 
@@ -321,6 +522,7 @@ impl ExecutableInvocation for AccessControllerQuickConfirmPrimaryRoleRecoveryPro
         let executor = Self::Exec {
             receiver: resolved_receiver.receiver,
             proposal_to_confirm: self.proposal_to_confirm,
+            proposal_nonce: self.proposal_nonce,
         };
 
         Ok((actor, call_frame_update, executor))
@@ -337,18 +539,22 @@ impl Executor for AccessControllerQuickConfirmPrimaryRoleRecoveryProposalExecuta
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        let confirmor_non_fungible_global_ids = auth_zone_non_fungible_global_ids(api)?;
         let recovery_proposal = transition_mut(
             self.receiver,
             api,
             AccessControllerQuickConfirmPrimaryRoleRecoveryProposalStateMachineInput {
                 proposal_to_confirm: self.proposal_to_confirm,
+                proposal_nonce: self.proposal_nonce,
+                confirmor_non_fungible_global_ids,
             },
         )?;
 
-        update_access_rules(
+        apply_rule_set(
             api,
             self.receiver,
-            access_rules_from_rule_set(recovery_proposal.rule_set),
+            recovery_proposal.rule_set,
+            ConfirmationKind::Quick,
         )?;
 
         Ok(((), CallFrameUpdate::empty()))
@@ -358,6 +564,7 @@ impl Executor for AccessControllerQuickConfirmPrimaryRoleRecoveryProposalExecuta
 pub struct AccessControllerQuickConfirmRecoveryRoleRecoveryProposalExecutable {
     pub receiver: RENodeId,
     pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
 }
 
 impl ExecutableInvocation for AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInvocation {
@@ -371,6 +578,7 @@ impl ExecutableInvocation for AccessControllerQuickConfirmRecoveryRoleRecoveryPr
         Self: Sized,
     {
         let mut call_frame_update = CallFrameUpdate::empty();
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(CLOCK)));
         let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
         let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
 
@@ -384,6 +592,7 @@ impl ExecutableInvocation for AccessControllerQuickConfirmRecoveryRoleRecoveryPr
         let executor = Self::Exec {
             receiver: resolved_receiver.receiver,
             proposal_to_confirm: self.proposal_to_confirm,
+            proposal_nonce: self.proposal_nonce,
         };
 
         Ok((actor, call_frame_update, executor))
@@ -400,18 +609,136 @@ impl Executor for AccessControllerQuickConfirmRecoveryRoleRecoveryProposalExecut
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        let confirmor_non_fungible_global_ids = auth_zone_non_fungible_global_ids(api)?;
         let recovery_proposal = transition_mut(
             self.receiver,
             api,
             AccessControllerQuickConfirmRecoveryRoleRecoveryProposalStateMachineInput {
                 proposal_to_confirm: self.proposal_to_confirm,
+                proposal_nonce: self.proposal_nonce,
+                confirmor_non_fungible_global_ids,
             },
         )?;
 
-        update_access_rules(
+        apply_rule_set(
+            api,
+            self.receiver,
+            recovery_proposal.rule_set,
+            ConfirmationKind::Quick,
+        )?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+//==========================================
+// Access Controller Batch Confirm Recovery
+//==========================================
+
+pub struct AccessControllerBatchConfirmRecoveryExecutable {
+    pub receiver: RENodeId,
+    pub proposer: Proposer,
+    pub confirmors: Vec<Role>,
+    pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
+}
+
+impl ExecutableInvocation for AccessControllerBatchConfirmRecoveryInvocation {
+    type Exec = AccessControllerBatchConfirmRecoveryExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(CLOCK)));
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::BatchConfirmRecovery),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            proposer: self.proposer,
+            confirmors: self.confirmors,
+            proposal_to_confirm: self.proposal_to_confirm,
+            proposal_nonce: self.proposal_nonce,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerBatchConfirmRecoveryExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        // Every confirmor is checked before the proposal is confirmed, so that the batch either
+        // confirms the proposal or leaves the controller and its rules untouched
+        let proposer_role = match self.proposer {
+            Proposer::Primary => Role::Primary,
+            Proposer::Recovery => Role::Recovery,
+        };
+        let rule_set = current_rule_set(api, self.receiver)?;
+        let mut confirmed_by = BTreeSet::new();
+        for confirmor in self.confirmors {
+            let role_rule = match confirmor {
+                Role::Primary => &rule_set.primary_role,
+                Role::Recovery => &rule_set.recovery_role,
+                Role::Confirmation => &rule_set.confirmation_role,
+            };
+            if confirmor == proposer_role
+                || !confirmed_by.insert(confirmor)
+                || !auth_zone_satisfies(api, role_rule)?
+            {
+                return Err(AccessControllerError::InvalidStateTransition.into());
+            }
+        }
+        if confirmed_by.is_empty() {
+            return Err(AccessControllerError::InvalidStateTransition.into());
+        }
+
+        // A proposal is consumed by its confirmation, so a single transition confirms it on
+        // behalf of all confirmors
+        let confirmor_non_fungible_global_ids = auth_zone_non_fungible_global_ids(api)?;
+        let recovery_proposal = match self.proposer {
+            Proposer::Primary => transition_mut(
+                self.receiver,
+                api,
+                AccessControllerQuickConfirmPrimaryRoleRecoveryProposalStateMachineInput {
+                    proposal_to_confirm: self.proposal_to_confirm,
+                    proposal_nonce: self.proposal_nonce,
+                    confirmor_non_fungible_global_ids,
+                },
+            )?,
+            Proposer::Recovery => transition_mut(
+                self.receiver,
+                api,
+                AccessControllerQuickConfirmRecoveryRoleRecoveryProposalStateMachineInput {
+                    proposal_to_confirm: self.proposal_to_confirm,
+                    proposal_nonce: self.proposal_nonce,
+                    confirmor_non_fungible_global_ids,
+                },
+            )?,
+        };
+
+        apply_rule_set(
             api,
             self.receiver,
-            access_rules_from_rule_set(recovery_proposal.rule_set),
+            recovery_proposal.rule_set,
+            ConfirmationKind::Quick,
         )?;
 
         Ok(((), CallFrameUpdate::empty()))
@@ -425,6 +752,7 @@ impl Executor for AccessControllerQuickConfirmRecoveryRoleRecoveryProposalExecut
 pub struct AccessControllerTimedConfirmRecoveryExecutable {
     pub receiver: RENodeId,
     pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
 }
 
 impl ExecutableInvocation for AccessControllerTimedConfirmRecoveryInvocation {
@@ -450,6 +778,7 @@ impl ExecutableInvocation for AccessControllerTimedConfirmRecoveryInvocation {
         let executor = Self::Exec {
             receiver: resolved_receiver.receiver,
             proposal_to_confirm: self.proposal_to_confirm,
+            proposal_nonce: self.proposal_nonce,
         };
 
         Ok((actor, call_frame_update, executor))
@@ -471,14 +800,16 @@ impl Executor for AccessControllerTimedConfirmRecoveryExecutable {
             api,
             AccessControllerTimedConfirmRecoveryStateMachineInput {
                 proposal_to_confirm: self.proposal_to_confirm,
+                proposal_nonce: self.proposal_nonce,
             },
         )?;
 
         // Update the access rules
-        update_access_rules(
+        apply_rule_set(
             api,
             self.receiver,
-            access_rules_from_rule_set(recovery_proposal.rule_set),
+            recovery_proposal.rule_set,
+            ConfirmationKind::Timed,
         )?;
 
         Ok(((), CallFrameUpdate::empty()))
@@ -760,6 +1091,129 @@ impl Executor for AccessControllerStopTimedRecoveryExecutable {
     }
 }
 
+//=========================================
+// Access Controller Extend Timed Recovery
+//=========================================
+
+pub struct AccessControllerExtendTimedRecoveryExecutable {
+    pub receiver: RENodeId,
+    pub additional_minutes: i64,
+}
+
+impl ExecutableInvocation for AccessControllerExtendTimedRecoveryInvocation {
+    type Exec = AccessControllerExtendTimedRecoveryExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::ExtendTimedRecovery),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            additional_minutes: self.additional_minutes,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerExtendTimedRecoveryExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        transition_mut(
+            self.receiver,
+            api,
+            AccessControllerExtendTimedRecoveryStateMachineInput {
+                additional_minutes: self.additional_minutes,
+            },
+        )?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+//=================================
+// Access Controller Get Role Rule
+//=================================
+
+pub struct AccessControllerGetRoleRuleExecutable {
+    pub receiver: RENodeId,
+    pub role: Role,
+}
+
+impl ExecutableInvocation for AccessControllerGetRoleRuleInvocation {
+    type Exec = AccessControllerGetRoleRuleExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::GetRoleRule),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            role: self.role,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerGetRoleRuleExecutable {
+    type Output = AccessRule;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+
+        let access_rule = {
+            let substate = api.get_ref(handle)?;
+            let access_rules_chain = substate.access_rules_chain();
+            access_rules_chain.access_rules_chain[0]
+                .get_group(role_group_name(self.role))
+                .clone()
+        };
+
+        api.drop_lock(handle)?;
+
+        Ok((access_rule, CallFrameUpdate::empty()))
+    }
+}
+
 fn access_rule_or(access_rules: Vec<AccessRule>) -> AccessRule {
     let mut rule_nodes = Vec::new();
     for access_rule in access_rules.into_iter() {
@@ -772,25 +1226,1341 @@ fn access_rule_or(access_rules: Vec<AccessRule>) -> AccessRule {
     AccessRule::Protected(AccessRuleNode::AnyOf(rule_nodes))
 }
 
-//=========
-// Helpers
-//=========
+fn access_rule_and(access_rules: Vec<AccessRule>) -> AccessRule {
+    let mut rule_nodes = Vec::new();
+    for access_rule in access_rules.into_iter() {
+        match access_rule {
+            AccessRule::AllowAll => {}
+            AccessRule::DenyAll => return AccessRule::DenyAll,
+            AccessRule::Protected(rule_node) => rule_nodes.push(rule_node),
+        }
+    }
+    AccessRule::Protected(AccessRuleNode::AllOf(rule_nodes))
+}
 
-fn access_rules_from_rule_set(rule_set: RuleSet) -> AccessRules {
-    let mut access_rules = AccessRules::new();
+//============================================
+// Access Controller Endorse Recovery Proposal
+//============================================
 
-    // Primary Role Rules
-    let primary_group = "primary";
-    access_rules.set_group_access_rule(primary_group.into(), rule_set.primary_role.clone());
-    access_rules.set_method_access_rule_to_group(
-        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::CreateProof)),
-        primary_group.into(),
-    );
-    access_rules.set_method_access_rule_to_group(
-        AccessRuleKey::Native(NativeFn::AccessController(
-            AccessControllerFn::InitiateRecoveryAsPrimary,
-        )),
-        primary_group.into(),
+pub struct AccessControllerEndorseRecoveryRoleRecoveryProposalExecutable {
+    pub receiver: RENodeId,
+    pub proposal_to_endorse: RecoveryProposal,
+    pub endorser: NonFungibleGlobalId,
+}
+
+impl ExecutableInvocation for AccessControllerEndorseRecoveryRoleRecoveryProposalInvocation {
+    type Exec = AccessControllerEndorseRecoveryRoleRecoveryProposalExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::EndorseRecoveryRoleRecoveryProposal),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            proposal_to_endorse: self.proposal_to_endorse,
+            endorser: self.endorser,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerEndorseRecoveryRoleRecoveryProposalExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        // Endorsements are counted per non-fungible, so the caller must be able to prove that
+        // they hold the non-fungible they are endorsing with.
+        if !auth_zone_non_fungible_global_ids(api)?.contains(&self.endorser) {
+            return Err(AccessControllerError::EndorserNotPresentInAuthZone {
+                endorser: self.endorser,
+            }
+            .into());
+        }
+
+        // Any non-fungible can be minted by the caller, so only those which could act as the
+        // recovery role by themselves count as endorsements.
+        let recovery_role = current_rule_set(api, self.receiver)?.recovery_role;
+        if AuthZoneStackSubstate::check_non_fungible_global_id(
+            self.endorser.clone(),
+            &convert_contextless(&recovery_role),
+        )
+        .is_err()
+        {
+            return Err(AccessControllerError::EndorserDoesNotSatisfyRecoveryRole {
+                endorser: self.endorser,
+            }
+            .into());
+        }
+
+        transition_mut(
+            self.receiver,
+            api,
+            AccessControllerEndorseRecoveryRoleRecoveryProposalStateMachineInput {
+                proposal_to_endorse: self.proposal_to_endorse,
+                endorser: self.endorser,
+            },
+        )?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+//===============================
+// Access Controller Export State
+//===============================
+
+pub struct AccessControllerExportStateExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerExportStateInvocation {
+    type Exec = AccessControllerExportStateExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(CLOCK)));
+
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::ExportState),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerExportStateExecutable {
+    type Output = AccessControllerStateSnapshot;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let rule_set = current_rule_set(api, self.receiver)?;
+
+        let offset = SubstateOffset::AccessController(AccessControllerOffset::AccessController);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+        let access_controller = {
+            let substate = api.get_ref(handle)?;
+            substate.access_controller().clone()
+        };
+        api.drop_lock(handle)?;
+
+        let mut controlled_assets = Vec::new();
+        for vault_id in &access_controller.controlled_assets {
+            let vault = Vault(*vault_id);
+            controlled_assets.push((vault.sys_resource_address(api)?, vault.sys_amount(api)?));
+        }
+        let (controlled_asset_resource_address, controlled_asset_amount) = controlled_assets[0];
+
+        let primary_role_locked =
+            primary_role_lock_has_taken_effect(&access_controller.state.0, api)?;
+
+        let (primary_role_state, primary_operation_state, recovery_operation_state) =
+            access_controller.state;
+        let primary_role_locks_at = match primary_role_state {
+            PrimaryRoleState::LockPending { locks_at } => Some(locks_at),
+            PrimaryRoleState::Unlocked | PrimaryRoleState::Locked => None,
+        };
+        let primary_role_recovery_proposal = match primary_operation_state {
+            PrimaryOperationState::Normal => None,
+            PrimaryOperationState::Recovery(proposal) => Some(proposal),
+        };
+        let (recovery_role_recovery_proposal, timed_recovery_allowed_after) =
+            match recovery_operation_state {
+                RecoveryOperationState::Normal => (None, None),
+                RecoveryOperationState::Recovery(RecoveryRecoveryState::Untimed(proposal)) => {
+                    (Some(proposal), None)
+                }
+                RecoveryOperationState::Recovery(RecoveryRecoveryState::Timed {
+                    proposal,
+                    timed_recovery_allowed_after,
+                }) => (Some(proposal), Some(timed_recovery_allowed_after)),
+            };
+
+        let snapshot = AccessControllerStateSnapshot {
+            rule_set,
+            controlled_asset_resource_address,
+            controlled_asset_amount,
+            controlled_assets,
+            timed_recovery_delay_in_minutes: access_controller.timed_recovery_delay_in_minutes,
+            prevent_self_confirmation: access_controller.prevent_self_confirmation,
+            primary_role_lock_grace_period_in_minutes: access_controller
+                .primary_role_lock_grace_period_in_minutes,
+            recovery_endorsement_threshold: access_controller.recovery_endorsement_threshold,
+            minimum_recovery_interval_in_minutes: access_controller
+                .minimum_recovery_interval_in_minutes,
+            one_shot_proofs: access_controller.one_shot_proofs,
+            last_recovery_at: access_controller.last_recovery_at,
+            is_paused: access_controller.is_paused,
+            primary_role_locked,
+            primary_role_locks_at,
+            primary_role_recovery_proposal,
+            recovery_role_recovery_proposal,
+            timed_recovery_allowed_after,
+            recovery_proposal_endorsements: access_controller.recovery_proposal_endorsements,
+            recovery_delegates: access_controller.recovery_delegates,
+            recovery_proposal_nonces: access_controller.recovery_proposal_nonces,
+            proposal_authors: access_controller.proposal_authors,
+            proposal_initiated_at: access_controller.proposal_initiated_at,
+        };
+
+        Ok((snapshot, CallFrameUpdate::empty()))
+    }
+}
+
+//==================================================
+// Access Controller Get Controlled Resource Manager
+//==================================================
+
+pub struct AccessControllerGetControlledResourceManagerExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerGetControlledResourceManagerInvocation {
+    type Exec = AccessControllerGetControlledResourceManagerExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::GetControlledResourceManager),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerGetControlledResourceManagerExecutable {
+    type Output = ControlledResourceManagerInfo;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::AccessController(AccessControllerOffset::AccessController);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+        let controlled_asset = {
+            let substate = api.get_ref(handle)?;
+            substate.access_controller().controlled_asset()
+        };
+        api.drop_lock(handle)?;
+
+        // Reading the vault substate directly (rather than invoking the vault) makes the global
+        // resource manager node visible to this frame.
+        let (resource_address, resource_type) = {
+            let node_id = RENodeId::Vault(controlled_asset);
+            let offset = SubstateOffset::Vault(VaultOffset::Vault);
+            let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+            let resource = {
+                let substate = api.get_ref(handle)?;
+                let vault = substate.vault();
+                (vault.resource_address(), vault.resource_type())
+            };
+            api.drop_lock(handle)?;
+            resource
+        };
+
+        let (mintable, burnable) = {
+            let node_id = RENodeId::Global(GlobalAddress::Resource(resource_address));
+            let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+            let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+            let flags = {
+                let substate = api.get_ref(handle)?;
+                let access_rules = &substate.access_rules_chain().access_rules_chain[0];
+                let burn_key =
+                    AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::Burn));
+                (
+                    !matches!(access_rules.get_group("mint"), AccessRule::DenyAll),
+                    !matches!(access_rules.get(&burn_key), AccessRule::DenyAll),
+                )
+            };
+            api.drop_lock(handle)?;
+            flags
+        };
+
+        let info = ControlledResourceManagerInfo {
+            resource_address,
+            resource_type,
+            mintable,
+            burnable,
+        };
+
+        Ok((info, CallFrameUpdate::empty()))
+    }
+}
+
+//===========================================
+// Access Controller Verify Controlled Asset
+//===========================================
+
+pub struct AccessControllerVerifyControlledAssetExecutable {
+    pub receiver: RENodeId,
+    pub expected_resource: ResourceAddress,
+    pub expected_amount: Decimal,
+}
+
+impl ExecutableInvocation for AccessControllerVerifyControlledAssetInvocation {
+    type Exec = AccessControllerVerifyControlledAssetExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::VerifyControlledAsset),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            expected_resource: self.expected_resource,
+            expected_amount: self.expected_amount,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerVerifyControlledAssetExecutable {
+    type Output = bool;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::AccessController(AccessControllerOffset::AccessController);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+        let controlled_assets = {
+            let substate = api.get_ref(handle)?;
+            substate.access_controller().controlled_assets.clone()
+        };
+        api.drop_lock(handle)?;
+
+        // The expected resource may be spread over several vaults, so their amounts are summed.
+        let mut expected_resource_amount = None;
+        for vault_id in controlled_assets {
+            let vault = Vault(vault_id);
+            if vault.sys_resource_address(api)? == self.expected_resource {
+                let amount = vault.sys_amount(api)?;
+                expected_resource_amount =
+                    Some(expected_resource_amount.unwrap_or(Decimal::zero()) + amount);
+            }
+        }
+        let verified = expected_resource_amount == Some(self.expected_amount);
+
+        Ok((verified, CallFrameUpdate::empty()))
+    }
+}
+
+//=========================================
+// Access Controller Get Controlled Vaults
+//=========================================
+
+pub struct AccessControllerGetControlledVaultsExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerGetControlledVaultsInvocation {
+    type Exec = AccessControllerGetControlledVaultsExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::GetControlledVaults),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerGetControlledVaultsExecutable {
+    type Output = Vec<VaultId>;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::AccessController(AccessControllerOffset::AccessController);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+        let controlled_assets = {
+            let substate = api.get_ref(handle)?;
+            substate.access_controller().controlled_assets.clone()
+        };
+        api.drop_lock(handle)?;
+
+        Ok((controlled_assets, CallFrameUpdate::empty()))
+    }
+}
+
+//====================================
+// Access Controller Validate Proposal
+//====================================
+
+pub struct AccessControllerValidateProposalExecutable {
+    pub receiver: RENodeId,
+    pub proposer: Proposer,
+    pub proposal: RecoveryProposal,
+}
+
+impl ExecutableInvocation for AccessControllerValidateProposalInvocation {
+    type Exec = AccessControllerValidateProposalExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(CLOCK)));
+
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::ValidateProposal),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            proposer: self.proposer,
+            proposal: self.proposal,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerValidateProposalExecutable {
+    type Output = ProposalValidationOutcome;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let outcome = transition(
+            self.receiver,
+            api,
+            AccessControllerValidateProposalStateMachineInput {
+                proposer: self.proposer,
+                proposal: self.proposal,
+            },
+        )?;
+
+        Ok((outcome, CallFrameUpdate::empty()))
+    }
+}
+
+//=====================================
+// Access Controller Transfer Ownership
+//=====================================
+
+pub struct AccessControllerTransferOwnershipExecutable {
+    pub receiver: RENodeId,
+    pub rule_set: RuleSet,
+}
+
+impl ExecutableInvocation for AccessControllerTransferOwnershipInvocation {
+    type Exec = AccessControllerTransferOwnershipExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::TransferOwnership),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            rule_set: self.rule_set,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerTransferOwnershipExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        transition_mut(
+            self.receiver,
+            api,
+            AccessControllerTransferOwnershipStateMachineInput,
+        )?;
+
+        apply_rule_set(
+            api,
+            self.receiver,
+            self.rule_set,
+            ConfirmationKind::OwnershipTransfer,
+        )?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+//==============================
+// Access Controller Check Role
+//==============================
+
+pub struct AccessControllerCheckRoleExecutable {
+    pub receiver: RENodeId,
+    pub proof: Proof,
+    pub role: Role,
+}
+
+impl ExecutableInvocation for AccessControllerCheckRoleInvocation {
+    type Exec = AccessControllerCheckRoleExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::move_node(RENodeId::Proof(self.proof.0));
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::CheckRole),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            proof: self.proof,
+            role: self.role,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerCheckRoleExecutable {
+    type Output = (bool, Proof);
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+        let access_rule = {
+            let substate = api.get_ref(handle)?;
+            let access_rules_chain = substate.access_rules_chain();
+            access_rules_chain.access_rules_chain[0]
+                .get_group(role_group_name(self.role))
+                .clone()
+        };
+        api.drop_lock(handle)?;
+
+        let node_id = RENodeId::Proof(self.proof.0);
+        let offset = SubstateOffset::Proof(ProofOffset::Proof);
+        let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        // The clone holds its own locks on the proof's containers, released once it is checked
+        let proof = {
+            let substate = api.get_ref(handle)?;
+            substate.proof().clone()
+        };
+        api.drop_lock(handle)?;
+
+        let is_satisfied =
+            AuthZoneStackSubstate::check_proofs(vec![proof], &convert_contextless(&access_rule))
+                .is_ok();
+
+        // The proof is handed back to the caller rather than consumed, as it may still be needed
+        Ok((
+            (is_satisfied, self.proof),
+            CallFrameUpdate::move_node(node_id),
+        ))
+    }
+}
+
+//==============================
+// Access Controller Can Act As
+//==============================
+
+pub struct AccessControllerCanActAsExecutable {
+    pub receiver: RENodeId,
+    pub non_fungible_global_id: NonFungibleGlobalId,
+    pub role: Role,
+}
+
+impl ExecutableInvocation for AccessControllerCanActAsInvocation {
+    type Exec = AccessControllerCanActAsExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::CanActAs),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            non_fungible_global_id: self.non_fungible_global_id,
+            role: self.role,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerCanActAsExecutable {
+    type Output = bool;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+        let access_rule = {
+            let substate = api.get_ref(handle)?;
+            let access_rules_chain = substate.access_rules_chain();
+            access_rules_chain.access_rules_chain[0]
+                .get_group(role_group_name(self.role))
+                .clone()
+        };
+        api.drop_lock(handle)?;
+
+        let can_act_as = AuthZoneStackSubstate::check_non_fungible_global_id(
+            self.non_fungible_global_id,
+            &convert_contextless(&access_rule),
+        )
+        .is_ok();
+
+        Ok((can_act_as, CallFrameUpdate::empty()))
+    }
+}
+
+//================================================
+// Access Controller Initiate Recovery As Delegate
+//================================================
+
+pub struct AccessControllerInitiateRecoveryAsDelegateExecutable {
+    pub receiver: RENodeId,
+    pub proposal: RecoveryProposal,
+}
+
+impl ExecutableInvocation for AccessControllerInitiateRecoveryAsDelegateInvocation {
+    type Exec = AccessControllerInitiateRecoveryAsDelegateExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(CLOCK)));
+
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::InitiateRecoveryAsDelegate),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            proposal: self.proposal,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerInitiateRecoveryAsDelegateExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let proposer_non_fungible_global_ids = auth_zone_non_fungible_global_ids(api)?;
+        transition_mut(
+            self.receiver,
+            api,
+            AccessControllerInitiateRecoveryAsDelegateStateMachineInput {
+                proposal: self.proposal,
+                proposer_non_fungible_global_ids,
+            },
+        )?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+//=========================================
+// Access Controller Add Recovery Delegate
+//=========================================
+
+pub struct AccessControllerAddRecoveryDelegateExecutable {
+    pub receiver: RENodeId,
+    pub delegate: NonFungibleGlobalId,
+}
+
+impl ExecutableInvocation for AccessControllerAddRecoveryDelegateInvocation {
+    type Exec = AccessControllerAddRecoveryDelegateExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::AddRecoveryDelegate),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            delegate: self.delegate,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerAddRecoveryDelegateExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        transition_mut(
+            self.receiver,
+            api,
+            AccessControllerAddRecoveryDelegateStateMachineInput {
+                delegate: self.delegate,
+            },
+        )?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+//============================================
+// Access Controller Remove Recovery Delegate
+//============================================
+
+pub struct AccessControllerRemoveRecoveryDelegateExecutable {
+    pub receiver: RENodeId,
+    pub delegate: NonFungibleGlobalId,
+}
+
+impl ExecutableInvocation for AccessControllerRemoveRecoveryDelegateInvocation {
+    type Exec = AccessControllerRemoveRecoveryDelegateExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::RemoveRecoveryDelegate),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+            delegate: self.delegate,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerRemoveRecoveryDelegateExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        transition_mut(
+            self.receiver,
+            api,
+            AccessControllerRemoveRecoveryDelegateStateMachineInput {
+                delegate: self.delegate,
+            },
+        )?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+//==============================
+// Access Controller Get Config
+//==============================
+
+pub struct AccessControllerGetConfigExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerGetConfigInvocation {
+    type Exec = AccessControllerGetConfigExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(CLOCK)));
+
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::GetConfig),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerGetConfigExecutable {
+    type Output = AccessControllerConfig;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let rule_set = current_rule_set(api, self.receiver)?;
+
+        let offset = SubstateOffset::AccessController(AccessControllerOffset::AccessController);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+        let (timed_recovery_delay_in_minutes, primary_role_state) = {
+            let substate = api.get_ref(handle)?;
+            let access_controller = substate.access_controller();
+            (
+                access_controller.timed_recovery_delay_in_minutes,
+                access_controller.state.0.clone(),
+            )
+        };
+        api.drop_lock(handle)?;
+
+        let primary_role_locked = primary_role_lock_has_taken_effect(&primary_role_state, api)?;
+
+        let config = AccessControllerConfig {
+            rule_set,
+            timed_recovery_delay_in_minutes,
+            primary_role_locked,
+            timed_recovery_enabled: timed_recovery_delay_in_minutes.is_some(),
+        };
+
+        Ok((config, CallFrameUpdate::empty()))
+    }
+}
+
+//====================================
+// Access Controller Get Default Rule
+//====================================
+
+pub struct AccessControllerGetDefaultRuleExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerGetDefaultRuleInvocation {
+    type Exec = AccessControllerGetDefaultRuleExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::GetDefaultRule),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerGetDefaultRuleExecutable {
+    type Output = AccessControllerDefaultRule;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+
+        let default_rule = {
+            let substate = api.get_ref(handle)?;
+            let access_rules = &substate.access_rules_chain().access_rules_chain[0];
+            AccessControllerDefaultRule {
+                default_rule: access_rules.get_default_auth().clone(),
+                default_rule_mutability: access_rules.get_default_auth_mutability().clone(),
+            }
+        };
+
+        api.drop_lock(handle)?;
+
+        Ok((default_rule, CallFrameUpdate::empty()))
+    }
+}
+
+//========================
+// Access Controller Pause
+//========================
+
+pub struct AccessControllerPauseExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerPauseInvocation {
+    type Exec = AccessControllerPauseExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::Pause),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerPauseExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        transition_mut(self.receiver, api, AccessControllerPauseStateMachineInput)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+//=========================
+// Access Controller Resume
+//=========================
+
+pub struct AccessControllerResumeExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerResumeInvocation {
+    type Exec = AccessControllerResumeExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::Resume),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerResumeExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        transition_mut(self.receiver, api, AccessControllerResumeStateMachineInput)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+//===================================
+// Access Controller Get Timing Info
+//===================================
+
+pub struct AccessControllerGetTimingInfoExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerGetTimingInfoInvocation {
+    type Exec = AccessControllerGetTimingInfoExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(CLOCK)));
+
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::GetTimingInfo),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerGetTimingInfoExecutable {
+    type Output = AccessControllerTimingInfo;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let timing_info = transition(
+            self.receiver,
+            api,
+            AccessControllerGetTimingInfoStateMachineInput,
+        )?;
+
+        Ok((timing_info, CallFrameUpdate::empty()))
+    }
+}
+
+//================================
+// Access Controller Get Timeline
+//================================
+
+pub struct AccessControllerGetTimelineExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerGetTimelineInvocation {
+    type Exec = AccessControllerGetTimelineExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::GetTimeline),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerGetTimelineExecutable {
+    type Output = AccessControllerTimeline;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let timeline = transition(
+            self.receiver,
+            api,
+            AccessControllerGetTimelineStateMachineInput,
+        )?;
+
+        Ok((timeline, CallFrameUpdate::empty()))
+    }
+}
+
+//========================================
+// Access Controller Get Proposal Authors
+//========================================
+
+pub struct AccessControllerGetProposalAuthorsExecutable {
+    pub receiver: RENodeId,
+}
+
+impl ExecutableInvocation for AccessControllerGetProposalAuthorsInvocation {
+    type Exec = AccessControllerGetProposalAuthorsExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = ResolvedActor::method(
+            NativeFn::AccessController(AccessControllerFn::GetProposalAuthors),
+            resolved_receiver,
+        );
+
+        let executor = Self::Exec {
+            receiver: resolved_receiver.receiver,
+        };
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for AccessControllerGetProposalAuthorsExecutable {
+    type Output = BTreeMap<Proposer, Option<NonFungibleGlobalId>>;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let proposal_authors = transition(
+            self.receiver,
+            api,
+            AccessControllerGetProposalAuthorsStateMachineInput,
+        )?;
+
+        Ok((proposal_authors, CallFrameUpdate::empty()))
+    }
+}
+
+//=========
+// Helpers
+//=========
+
+fn access_rules_from_rule_set(rule_set: RuleSet) -> AccessRules {
+    let mut access_rules = AccessRules::new();
+
+    // Primary Role Rules
+    let primary_group = role_group_name(Role::Primary);
+    access_rules.set_group_access_rule(primary_group.into(), rule_set.primary_role.clone());
+    access_rules.set_method_access_rule_to_group(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::CreateProof)),
+        primary_group.into(),
+    );
+    access_rules.set_method_access_rule_to_group(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::CreateProofs)),
+        primary_group.into(),
+    );
+    access_rules.set_method_access_rule_to_group(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::InitiateRecoveryAsPrimary,
+        )),
+        primary_group.into(),
     );
     access_rules.set_method_access_rule_to_group(
         AccessRuleKey::Native(NativeFn::AccessController(
@@ -800,7 +2570,7 @@ fn access_rules_from_rule_set(rule_set: RuleSet) -> AccessRules {
     );
 
     // Recovery Role Rules
-    let recovery_group = "recovery";
+    let recovery_group = role_group_name(Role::Recovery);
     access_rules.set_group_access_rule(recovery_group.into(), rule_set.recovery_role.clone());
     access_rules.set_method_access_rule_to_group(
         AccessRuleKey::Native(NativeFn::AccessController(
@@ -832,15 +2602,142 @@ fn access_rules_from_rule_set(rule_set: RuleSet) -> AccessRules {
         )),
         recovery_group.into(),
     );
+    access_rules.set_method_access_rule_to_group(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::EndorseRecoveryRoleRecoveryProposal,
+        )),
+        recovery_group.into(),
+    );
+    access_rules.set_method_access_rule_to_group(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::AddRecoveryDelegate,
+        )),
+        recovery_group.into(),
+    );
+    access_rules.set_method_access_rule_to_group(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::RemoveRecoveryDelegate,
+        )),
+        recovery_group.into(),
+    );
 
     // Confirmation Role Rules
-    let confirmation_group = "confirmation";
+    let confirmation_group = role_group_name(Role::Confirmation);
     access_rules.set_group_access_rule(
         confirmation_group.into(),
         rule_set.confirmation_role.clone(),
     );
 
+    // Ownership transfers need all three roles at once
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::TransferOwnership,
+        )),
+        access_rule_and(vec![
+            rule_set.primary_role.clone(),
+            rule_set.recovery_role.clone(),
+            rule_set.confirmation_role.clone(),
+        ]),
+    );
+
+    // Pausing and resuming halts or restarts all recovery activity, so it needs the primary role
+    // to agree with the confirmation role
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::Pause)),
+        access_rule_and(vec![
+            rule_set.primary_role.clone(),
+            rule_set.confirmation_role.clone(),
+        ]),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::Resume)),
+        access_rule_and(vec![
+            rule_set.primary_role.clone(),
+            rule_set.confirmation_role.clone(),
+        ]),
+    );
+
     // Other methods
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::GetRoleRule)),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::CheckRole)),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::CanActAs)),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::ExportState)),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::GetConfig)),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::GetTimingInfo,
+        )),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::GetProposalAuthors,
+        )),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(AccessControllerFn::GetTimeline)),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::GetDefaultRule,
+        )),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::GetControlledResourceManager,
+        )),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::VerifyControlledAsset,
+        )),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::GetControlledVaults,
+        )),
+        rule!(allow_all),
+    );
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::ValidateProposal,
+        )),
+        rule!(allow_all),
+    );
+    // The confirmors of a batch are checked against the auth zone by the access controller itself
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::BatchConfirmRecovery,
+        )),
+        rule!(allow_all),
+    );
+    // Delegates are checked against the auth zone by the access controller itself
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::InitiateRecoveryAsDelegate,
+        )),
+        rule!(allow_all),
+    );
     access_rules.set_method_access_rule(
         AccessRuleKey::Native(NativeFn::AccessController(
             AccessControllerFn::StopTimedRecovery,
@@ -854,6 +2751,13 @@ fn access_rules_from_rule_set(rule_set: RuleSet) -> AccessRules {
             .into(),
         ),
     );
+    // Only the recovery role can propose timed recoveries, so only it can extend them
+    access_rules.set_method_access_rule(
+        AccessRuleKey::Native(NativeFn::AccessController(
+            AccessControllerFn::ExtendTimedRecovery,
+        )),
+        rule_set.recovery_role.clone(),
+    );
     access_rules.set_method_access_rule(
         AccessRuleKey::Native(NativeFn::AccessController(
             AccessControllerFn::QuickConfirmPrimaryRoleRecoveryProposal,
@@ -876,6 +2780,15 @@ fn access_rules_from_rule_set(rule_set: RuleSet) -> AccessRules {
     access_rules.default(rule!(deny_all), rule!(require(non_fungible_global_id)))
 }
 
+/// The name of the access rules group which holds the rule of the given role.
+fn role_group_name(role: Role) -> &'static str {
+    match role {
+        Role::Primary => "primary",
+        Role::Recovery => "recovery",
+        Role::Confirmation => "confirmation",
+    }
+}
+
 fn transition<Y, I>(
     node_id: RENodeId,
     api: &mut Y,
@@ -932,6 +2845,153 @@ where
     Ok(rtn)
 }
 
+/// Reads the global ids of the non-fungibles of all proofs in the auth zone(s) of the caller.
+fn auth_zone_non_fungible_global_ids<Y>(
+    api: &mut Y,
+) -> Result<BTreeSet<NonFungibleGlobalId>, RuntimeError>
+where
+    Y: SystemApi,
+{
+    let auth_zone_id = auth_zone_id(api)?;
+
+    let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
+    let handle = api.lock_substate(auth_zone_id, offset, LockFlags::read_only())?;
+    let non_fungible_global_ids = {
+        let substate = api.get_ref(handle)?;
+        substate
+            .auth_zone_stack()
+            .visible_non_fungible_global_ids(false)
+    };
+    api.drop_lock(handle)?;
+
+    Ok(non_fungible_global_ids)
+}
+
+/// Checks whether the proofs in the auth zone(s) of the caller satisfy the given access rule.
+fn auth_zone_satisfies<Y>(api: &mut Y, access_rule: &AccessRule) -> Result<bool, RuntimeError>
+where
+    Y: SystemApi,
+{
+    let auth_zone_id = auth_zone_id(api)?;
+
+    let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
+    let handle = api.lock_substate(auth_zone_id, offset, LockFlags::read_only())?;
+    let is_satisfied = {
+        let substate = api.get_ref(handle)?;
+        substate
+            .auth_zone_stack()
+            .check_auth(false, vec![convert_contextless(access_rule)])
+            .is_ok()
+    };
+    api.drop_lock(handle)?;
+
+    Ok(is_satisfied)
+}
+
+fn auth_zone_id<Y>(api: &mut Y) -> Result<RENodeId, RuntimeError>
+where
+    Y: SystemApi,
+{
+    let auth_zone_id = api
+        .get_visible_nodes()?
+        .into_iter()
+        .find(|node_id| matches!(node_id, RENodeId::AuthZoneStack(..)))
+        .expect("Auth zone stack must be visible to the access controller");
+    Ok(auth_zone_id)
+}
+
+/// Singles out the non-fungible which authored a recovery proposal of the given role: the first
+/// of the proposer's non-fungibles which satisfies the rule of the role on its own. Returns
+/// [`None`] if there is no such non-fungible, e.g. when the role is proven with fungible badges.
+fn proposal_author<Y>(
+    api: &mut Y,
+    receiver: RENodeId,
+    role: Role,
+    proposer_non_fungible_global_ids: &BTreeSet<NonFungibleGlobalId>,
+) -> Result<Option<NonFungibleGlobalId>, RuntimeError>
+where
+    Y: SystemApi,
+{
+    let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+    let handle = api.lock_substate(receiver, offset, LockFlags::read_only())?;
+    let method_auth = {
+        let substate = api.get_ref(handle)?;
+        let access_rules_chain = substate.access_rules_chain();
+        convert_contextless(
+            access_rules_chain.access_rules_chain[0].get_group(role_group_name(role)),
+        )
+    };
+    api.drop_lock(handle)?;
+
+    let author = proposer_non_fungible_global_ids
+        .iter()
+        .find(|non_fungible_global_id| {
+            AuthZoneStackSubstate::check_non_fungible_global_id(
+                (*non_fungible_global_id).clone(),
+                &method_auth,
+            )
+            .is_ok()
+        })
+        .cloned();
+
+    Ok(author)
+}
+
+/// Reads the rule set which is currently applied to the access controller.
+fn current_rule_set<Y>(api: &mut Y, receiver: RENodeId) -> Result<RuleSet, RuntimeError>
+where
+    Y: SystemApi,
+{
+    let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+    let handle = api.lock_substate(receiver, offset, LockFlags::read_only())?;
+
+    let rule_set = {
+        let substate = api.get_ref(handle)?;
+        let access_rules = &substate.access_rules_chain().access_rules_chain[0];
+        RuleSet {
+            primary_role: access_rules
+                .get_group(role_group_name(Role::Primary))
+                .clone(),
+            recovery_role: access_rules
+                .get_group(role_group_name(Role::Recovery))
+                .clone(),
+            confirmation_role: access_rules
+                .get_group(role_group_name(Role::Confirmation))
+                .clone(),
+        }
+    };
+
+    api.drop_lock(handle)?;
+
+    Ok(rule_set)
+}
+
+/// Replaces the rule set of the access controller with a newly confirmed one and logs the change.
+fn apply_rule_set<Y>(
+    api: &mut Y,
+    receiver: RENodeId,
+    rule_set: RuleSet,
+    via: ConfirmationKind,
+) -> Result<(), RuntimeError>
+where
+    Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+{
+    let old = current_rule_set(api, receiver)?;
+    update_access_rules(api, receiver, access_rules_from_rule_set(rule_set.clone()))?;
+
+    let rule_set_changed = RuleSetChanged {
+        old,
+        new: rule_set,
+        via,
+    };
+    api.invoke(LoggerLogInvocation {
+        level: Level::Info,
+        message: format!("{:?}", rule_set_changed),
+    })?;
+
+    Ok(())
+}
+
 fn update_access_rules<Y>(
     api: &mut Y,
     receiver: RENodeId,
@@ -963,3 +3023,47 @@ where
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{MockTrack, MockTrackOperation};
+
+    #[test]
+    fn transition_mut_locks_reads_writes_then_drops_the_substate() {
+        // Arrange
+        let node_id = RENodeId::AccessController([1u8; 36]);
+        let offset = SubstateOffset::AccessController(AccessControllerOffset::AccessController);
+        let mut track = MockTrack::new();
+        track.insert_substate(
+            node_id,
+            offset.clone(),
+            AccessControllerSubstate::new(
+                vec![[2u8; 36]],
+                None,
+                AccessControllerSettings::default(),
+            ),
+        );
+
+        // Act
+        transition_mut(node_id, &mut track, AccessControllerPauseStateMachineInput).unwrap();
+
+        // Assert
+        assert_eq!(
+            track.operations(),
+            &[
+                MockTrackOperation::Acquire {
+                    node_id,
+                    offset: offset.clone(),
+                    flags: LockFlags::MUTABLE,
+                },
+                MockTrackOperation::Read(0),
+                MockTrackOperation::Write(0),
+                MockTrackOperation::Release(0),
+            ]
+        );
+        assert!(!track.has_open_locks());
+        let substate = track.substate(node_id, offset).unwrap();
+        assert!(substate.access_controller().is_paused);
+    }
+}
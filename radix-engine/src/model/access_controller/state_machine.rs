@@ -3,12 +3,14 @@ use crate::types::*;
 use native_sdk::resource::Vault;
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::{EngineApi, InvokableModel};
-use radix_engine_interface::model::{Proof, Proposer, TimePrecision};
-use radix_engine_interface::time::TimeComparisonOperator;
+use radix_engine_interface::model::{
+    Level, LoggerLogInvocation, Proof, ProofMakeOneShotInvocation, Proposer, TimePrecision,
+};
+use radix_engine_interface::time::{Instant, TimeComparisonOperator};
 
 use super::{
     AccessControllerError, AccessControllerSubstate, PrimaryOperationState, PrimaryRoleState,
-    RecoveryOperationState, RecoveryRecoveryState,
+    RecoveryOperationState, RecoveryRecoveryState, MAX_OUTSTANDING_RECOVERY_PROPOSALS,
 };
 
 /// A trait which defines the interface for an access controller transition for a given trigger or
@@ -56,19 +58,36 @@ impl Transition<AccessControllerCreateProofStateMachineInput> for AccessControll
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
-        // Proofs can only be created when the primary role is unlocked - regardless of whether the
-        // controller is in recovery or normal operations.
-        match self.state {
-            (PrimaryRoleState::Unlocked, _, _) => {
-                Vault(self.controlled_asset).sys_create_proof(api)
-            }
-            _ => access_controller_runtime_error!(OperationRequiresUnlockedPrimaryRole),
-        }
+        validate_proofs_can_be_created(self, api)?;
+        create_controlled_asset_proof(self, Vault(self.controlled_asset()), api)
+    }
+}
+
+pub(super) struct AccessControllerCreateProofsStateMachineInput;
+
+impl Transition<AccessControllerCreateProofsStateMachineInput> for AccessControllerSubstate {
+    type Output = Vec<Proof>;
+
+    fn transition<Y>(
+        &self,
+        api: &mut Y,
+        _input: AccessControllerCreateProofsStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        validate_proofs_can_be_created(self, api)?;
+        self.controlled_assets
+            .iter()
+            .map(|vault_id| create_controlled_asset_proof(self, Vault(*vault_id), api))
+            .collect()
     }
 }
 
 pub(super) struct AccessControllerInitiateRecoveryAsPrimaryStateMachineInput {
     pub proposal: RecoveryProposal,
+    pub proposer_non_fungible_global_ids: BTreeSet<NonFungibleGlobalId>,
+    pub author: Option<NonFungibleGlobalId>,
 }
 
 impl TransitionMut<AccessControllerInitiateRecoveryAsPrimaryStateMachineInput>
@@ -78,31 +97,36 @@ impl TransitionMut<AccessControllerInitiateRecoveryAsPrimaryStateMachineInput>
 
     fn transition_mut<Y>(
         &mut self,
-        _api: &mut Y,
+        api: &mut Y,
         input: AccessControllerInitiateRecoveryAsPrimaryStateMachineInput,
     ) -> Result<Self::Output, RuntimeError>
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
-        match self.state {
-            (_, ref mut primary_operations_state @ PrimaryOperationState::Normal, _) => {
-                // Transition the primary operations state from normal to recovery
-                *primary_operations_state = PrimaryOperationState::Recovery(input.proposal);
-                Ok(())
-            }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::AccessControllerError(
-                    AccessControllerError::RecoveryAlreadyExistsForProposer {
-                        proposer: Proposer::Primary,
-                    },
-                ),
-            )),
+        validate_not_paused(self)?;
+
+        validate_outstanding_proposals_below_cap(self)?;
+        validate_no_recovery_exists_for_proposer(self, Proposer::Primary)?;
+        validate_proposed_delay_is_not_a_downgrade(self, &input.proposal)?;
+
+        // Transition the primary operations state from normal to recovery
+        self.state.1 = PrimaryOperationState::Recovery(input.proposal);
+        self.proposer_non_fungible_global_ids
+            .insert(Proposer::Primary, input.proposer_non_fungible_global_ids);
+        if let Some(author) = input.author {
+            self.proposal_authors.insert(Proposer::Primary, author);
         }
+        let current_time = Runtime::sys_current_time(api, TimePrecision::Minute)?;
+        self.proposal_initiated_at
+            .insert(Proposer::Primary, current_time);
+        Ok(())
     }
 }
 
 pub(super) struct AccessControllerInitiateRecoveryAsRecoveryStateMachineInput {
     pub proposal: RecoveryProposal,
+    pub proposer_non_fungible_global_ids: BTreeSet<NonFungibleGlobalId>,
+    pub author: Option<NonFungibleGlobalId>,
 }
 
 impl TransitionMut<AccessControllerInitiateRecoveryAsRecoveryStateMachineInput>
@@ -118,45 +142,275 @@ impl TransitionMut<AccessControllerInitiateRecoveryAsRecoveryStateMachineInput>
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
-        match self.state {
-            (_, _, ref mut recovery_operations_state @ RecoveryOperationState::Normal) => {
-                match self.timed_recovery_delay_in_minutes {
-                    Some(delay_in_minutes) => {
-                        let current_time = Runtime::sys_current_time(api, TimePrecision::Minute)?;
-                        let timed_recovery_allowed_after = current_time
-                            .add_minutes(delay_in_minutes as i64)
-                            .map_or(access_controller_runtime_error!(TimeOverflow), |instant| {
-                                Ok(instant)
-                            })?;
+        validate_not_paused(self)?;
+
+        validate_outstanding_proposals_below_cap(self)?;
+        validate_no_recovery_exists_for_proposer(self, Proposer::Recovery)?;
+        validate_proposed_delay_is_not_a_downgrade(self, &input.proposal)?;
+
+        self.state.2 = match timed_recovery_allowed_after(self, api)? {
+            Some(timed_recovery_allowed_after) => {
+                RecoveryOperationState::Recovery(RecoveryRecoveryState::Timed {
+                    proposal: input.proposal,
+                    timed_recovery_allowed_after,
+                })
+            }
+            None => {
+                RecoveryOperationState::Recovery(RecoveryRecoveryState::Untimed(input.proposal))
+            }
+        };
+        self.proposer_non_fungible_global_ids
+            .insert(Proposer::Recovery, input.proposer_non_fungible_global_ids);
+        if let Some(author) = input.author {
+            self.proposal_authors.insert(Proposer::Recovery, author);
+        }
+        let current_time = Runtime::sys_current_time(api, TimePrecision::Minute)?;
+        self.proposal_initiated_at
+            .insert(Proposer::Recovery, current_time);
+        Ok(())
+    }
+}
 
-                        *recovery_operations_state =
-                            RecoveryOperationState::Recovery(RecoveryRecoveryState::Timed {
-                                proposal: input.proposal,
-                                timed_recovery_allowed_after,
-                            });
-                        Ok(())
+pub(super) struct AccessControllerInitiateRecoveryAsDelegateStateMachineInput {
+    pub proposal: RecoveryProposal,
+    pub proposer_non_fungible_global_ids: BTreeSet<NonFungibleGlobalId>,
+}
+
+impl TransitionMut<AccessControllerInitiateRecoveryAsDelegateStateMachineInput>
+    for AccessControllerSubstate
+{
+    type Output = ();
+
+    fn transition_mut<Y>(
+        &mut self,
+        api: &mut Y,
+        input: AccessControllerInitiateRecoveryAsDelegateStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let author = match self
+            .recovery_delegates
+            .intersection(&input.proposer_non_fungible_global_ids)
+            .next()
+        {
+            Some(delegate) => delegate.clone(),
+            None => return access_controller_runtime_error!(CallerIsNotARecoveryDelegate),
+        };
+
+        // A delegate's proposal occupies the slot of the recovery role, and is confirmed or
+        // cancelled in exactly the same way as one made by the recovery role itself.
+        self.transition_mut(
+            api,
+            AccessControllerInitiateRecoveryAsRecoveryStateMachineInput {
+                proposal: input.proposal,
+                proposer_non_fungible_global_ids: input.proposer_non_fungible_global_ids,
+                author: Some(author),
+            },
+        )
+    }
+}
+
+pub(super) struct AccessControllerValidateProposalStateMachineInput {
+    pub proposer: Proposer,
+    pub proposal: RecoveryProposal,
+}
+
+impl Transition<AccessControllerValidateProposalStateMachineInput> for AccessControllerSubstate {
+    type Output = ProposalValidationOutcome;
+
+    fn transition<Y>(
+        &self,
+        api: &mut Y,
+        input: AccessControllerValidateProposalStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        // Runs the same checks as initiating a recovery as the given proposer, mapping the
+        // access controller errors they raise to a rejection reason.
+        let result = validate_not_paused(self)
+            .and_then(|_| validate_outstanding_proposals_below_cap(self))
+            .and_then(|_| validate_no_recovery_exists_for_proposer(self, input.proposer))
+            .and_then(|_| validate_proposed_delay_is_not_a_downgrade(self, &input.proposal))
+            .map_err(RuntimeError::from)
+            .and_then(|_| match input.proposer {
+                Proposer::Primary => Ok(()),
+                Proposer::Recovery => timed_recovery_allowed_after(self, api).map(|_| ()),
+            });
+
+        match result {
+            Ok(()) => Ok(ProposalValidationOutcome::Accepted),
+            Err(RuntimeError::ApplicationError(ApplicationError::AccessControllerError(error))) => {
+                let reason = match error {
+                    AccessControllerError::ControllerPaused => {
+                        ProposalRejectionReason::ControllerPaused
                     }
-                    None => {
-                        *recovery_operations_state = RecoveryOperationState::Recovery(
-                            RecoveryRecoveryState::Untimed(input.proposal),
-                        );
-                        Ok(())
+                    AccessControllerError::RecoveryAlreadyExistsForProposer { .. } => {
+                        ProposalRejectionReason::RecoveryAlreadyExistsForProposer
                     }
+                    AccessControllerError::DelayDowngradeNotAllowed { .. } => {
+                        ProposalRejectionReason::DelayDowngradeNotAllowed
+                    }
+                    AccessControllerError::TimeOverflow => ProposalRejectionReason::TimeOverflow,
+                    AccessControllerError::TooManyProposals => {
+                        ProposalRejectionReason::TooManyProposals
+                    }
+                    error => return Err(error.into()),
+                };
+                Ok(ProposalValidationOutcome::Rejected(reason))
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+pub(super) struct AccessControllerGetTimingInfoStateMachineInput;
+
+impl Transition<AccessControllerGetTimingInfoStateMachineInput> for AccessControllerSubstate {
+    type Output = AccessControllerTimingInfo;
+
+    fn transition<Y>(
+        &self,
+        api: &mut Y,
+        _input: AccessControllerGetTimingInfoStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let current_time = Runtime::sys_current_time(api, TimePrecision::Minute)?;
+        let minutes_until = |instant: &Instant| {
+            let seconds = instant.seconds_since_unix_epoch - current_time.seconds_since_unix_epoch;
+            (seconds.max(0) as u64 + 59) / 60
+        };
+
+        let recovery_allowed_at = recovery_allowed_at(self)?;
+        let minutes_until_recovery_allowed = recovery_allowed_at.as_ref().map_or(0, minutes_until);
+
+        // A timed confirmation needs both its delay and the minimum recovery interval to elapse
+        let mut minutes_until_timed_confirm = BTreeMap::new();
+        if let PrimaryOperationState::Recovery(..) = self.state.1 {
+            minutes_until_timed_confirm.insert(Proposer::Primary, None);
+        }
+        match self.state.2 {
+            RecoveryOperationState::Recovery(RecoveryRecoveryState::Timed {
+                ref timed_recovery_allowed_after,
+                ..
+            }) => {
+                let minutes =
+                    minutes_until(timed_recovery_allowed_after).max(minutes_until_recovery_allowed);
+                minutes_until_timed_confirm.insert(Proposer::Recovery, Some(minutes));
+            }
+            RecoveryOperationState::Recovery(RecoveryRecoveryState::Untimed(..)) => {
+                minutes_until_timed_confirm.insert(Proposer::Recovery, None);
+            }
+            RecoveryOperationState::Normal => {}
+        }
+
+        Ok(AccessControllerTimingInfo {
+            minutes_until_recovery_allowed,
+            minutes_until_timed_confirm,
+        })
+    }
+}
+
+pub(super) struct AccessControllerGetTimelineStateMachineInput;
+
+impl Transition<AccessControllerGetTimelineStateMachineInput> for AccessControllerSubstate {
+    type Output = AccessControllerTimeline;
+
+    fn transition<Y>(
+        &self,
+        _api: &mut Y,
+        _input: AccessControllerGetTimelineStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let cooldown_until = recovery_allowed_at(self)?;
+        let proposal_timeline =
+            |proposer: Proposer, timed_recovery_allowed_after: Option<&Instant>| {
+                // A timed confirmation needs both its delay and the cooldown to elapse
+                let earliest_timed_confirm_at =
+                    timed_recovery_allowed_after.map(|allowed_after| match cooldown_until {
+                        Some(cooldown_until)
+                            if cooldown_until.seconds_since_unix_epoch
+                                > allowed_after.seconds_since_unix_epoch =>
+                        {
+                            cooldown_until
+                        }
+                        _ => *allowed_after,
+                    });
+                AccessControllerProposalTimeline {
+                    initiated_at: self.proposal_initiated_at.get(&proposer).cloned(),
+                    earliest_timed_confirm_at,
                 }
+            };
+
+        let mut proposals = BTreeMap::new();
+        if let PrimaryOperationState::Recovery(..) = self.state.1 {
+            proposals.insert(
+                Proposer::Primary,
+                proposal_timeline(Proposer::Primary, None),
+            );
+        }
+        match self.state.2 {
+            RecoveryOperationState::Recovery(RecoveryRecoveryState::Timed {
+                ref timed_recovery_allowed_after,
+                ..
+            }) => {
+                proposals.insert(
+                    Proposer::Recovery,
+                    proposal_timeline(Proposer::Recovery, Some(timed_recovery_allowed_after)),
+                );
             }
-            _ => Err(RuntimeError::ApplicationError(
-                ApplicationError::AccessControllerError(
-                    AccessControllerError::RecoveryAlreadyExistsForProposer {
-                        proposer: Proposer::Recovery,
-                    },
-                ),
-            )),
+            RecoveryOperationState::Recovery(RecoveryRecoveryState::Untimed(..)) => {
+                proposals.insert(
+                    Proposer::Recovery,
+                    proposal_timeline(Proposer::Recovery, None),
+                );
+            }
+            RecoveryOperationState::Normal => {}
         }
+
+        Ok(AccessControllerTimeline {
+            cooldown_until,
+            proposals,
+        })
+    }
+}
+
+pub(super) struct AccessControllerGetProposalAuthorsStateMachineInput;
+
+impl Transition<AccessControllerGetProposalAuthorsStateMachineInput> for AccessControllerSubstate {
+    type Output = BTreeMap<Proposer, Option<NonFungibleGlobalId>>;
+
+    fn transition<Y>(
+        &self,
+        _api: &mut Y,
+        _input: AccessControllerGetProposalAuthorsStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let author = |proposer: Proposer| self.proposal_authors.get(&proposer).cloned();
+
+        let mut proposal_authors = BTreeMap::new();
+        if let PrimaryOperationState::Recovery(..) = self.state.1 {
+            proposal_authors.insert(Proposer::Primary, author(Proposer::Primary));
+        }
+        if let RecoveryOperationState::Recovery(..) = self.state.2 {
+            proposal_authors.insert(Proposer::Recovery, author(Proposer::Recovery));
+        }
+
+        Ok(proposal_authors)
     }
 }
 
 pub(super) struct AccessControllerQuickConfirmPrimaryRoleRecoveryProposalStateMachineInput {
     pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
+    pub confirmor_non_fungible_global_ids: BTreeSet<NonFungibleGlobalId>,
 }
 
 impl TransitionMut<AccessControllerQuickConfirmPrimaryRoleRecoveryProposalStateMachineInput>
@@ -166,21 +420,36 @@ impl TransitionMut<AccessControllerQuickConfirmPrimaryRoleRecoveryProposalStateM
 
     fn transition_mut<Y>(
         &mut self,
-        _api: &mut Y,
+        api: &mut Y,
         input: AccessControllerQuickConfirmPrimaryRoleRecoveryProposalStateMachineInput,
     ) -> Result<Self::Output, RuntimeError>
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        validate_not_paused(self)?;
+
         match self.state {
             (_, PrimaryOperationState::Recovery(ref proposal), _) => {
                 let proposal = proposal.clone();
 
+                // Ensure that the confirmation was made for the current proposal of the proposer
+                validate_recovery_proposal_nonce(self, Proposer::Primary, input.proposal_nonce)?;
+
                 // Ensure that the caller has passed in the expected proposal
                 validate_recovery_proposal(&proposal, &input.proposal_to_confirm)?;
 
+                // Ensure that the confirmor is not the same entity that proposed the recovery
+                validate_confirmor_is_not_proposer(
+                    self,
+                    Proposer::Primary,
+                    &input.confirmor_non_fungible_global_ids,
+                )?;
+
+                // Ensure that enough time has passed since the previous recovery
+                record_recovery(self, api)?;
+
                 // Transition back to the initial state of the state machine
-                self.state = Default::default();
+                discard_recovery_proposals(self);
                 Ok(proposal)
             }
             _ => Err(RuntimeError::ApplicationError(
@@ -196,6 +465,8 @@ impl TransitionMut<AccessControllerQuickConfirmPrimaryRoleRecoveryProposalStateM
 
 pub(super) struct AccessControllerQuickConfirmRecoveryRoleRecoveryProposalStateMachineInput {
     pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
+    pub confirmor_non_fungible_global_ids: BTreeSet<NonFungibleGlobalId>,
 }
 
 impl TransitionMut<AccessControllerQuickConfirmRecoveryRoleRecoveryProposalStateMachineInput>
@@ -205,12 +476,14 @@ impl TransitionMut<AccessControllerQuickConfirmRecoveryRoleRecoveryProposalState
 
     fn transition_mut<Y>(
         &mut self,
-        _api: &mut Y,
+        api: &mut Y,
         input: AccessControllerQuickConfirmRecoveryRoleRecoveryProposalStateMachineInput,
     ) -> Result<Self::Output, RuntimeError>
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        validate_not_paused(self)?;
+
         match self.state {
             (
                 _,
@@ -222,11 +495,27 @@ impl TransitionMut<AccessControllerQuickConfirmRecoveryRoleRecoveryProposalState
             ) => {
                 let proposal = proposal.clone();
 
+                // Ensure that the confirmation was made for the current proposal of the proposer
+                validate_recovery_proposal_nonce(self, Proposer::Recovery, input.proposal_nonce)?;
+
                 // Ensure that the caller has passed in the expected proposal
                 validate_recovery_proposal(&proposal, &input.proposal_to_confirm)?;
 
+                // Ensure that the confirmor is not the same entity that proposed the recovery
+                validate_confirmor_is_not_proposer(
+                    self,
+                    Proposer::Recovery,
+                    &input.confirmor_non_fungible_global_ids,
+                )?;
+
+                // Ensure that the proposal has collected enough endorsements to be confirmed
+                validate_recovery_endorsement_threshold_is_met(self)?;
+
+                // Ensure that enough time has passed since the previous recovery
+                record_recovery(self, api)?;
+
                 // Transition back to the initial state of the state machine
-                self.state = Default::default();
+                discard_recovery_proposals(self);
                 Ok(proposal)
             }
             _ => Err(RuntimeError::ApplicationError(
@@ -242,6 +531,7 @@ impl TransitionMut<AccessControllerQuickConfirmRecoveryRoleRecoveryProposalState
 
 pub(super) struct AccessControllerTimedConfirmRecoveryStateMachineInput {
     pub proposal_to_confirm: RecoveryProposal,
+    pub proposal_nonce: u64,
 }
 
 impl TransitionMut<AccessControllerTimedConfirmRecoveryStateMachineInput>
@@ -257,6 +547,8 @@ impl TransitionMut<AccessControllerTimedConfirmRecoveryStateMachineInput>
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        validate_not_paused(self)?;
+
         // Timed confirm recovery can only be performed by the recovery role (this is checked
         // through access rules on the invocation itself) and can be performed in recovery mode
         // regardless of whether primary is locked or unlocked.
@@ -271,9 +563,15 @@ impl TransitionMut<AccessControllerTimedConfirmRecoveryStateMachineInput>
             ) => {
                 let proposal = proposal.clone();
 
+                // Ensure that the confirmation was made for the current proposal of the proposer
+                validate_recovery_proposal_nonce(self, Proposer::Recovery, input.proposal_nonce)?;
+
                 // Ensure that the caller has passed in the expected proposal
                 validate_recovery_proposal(&proposal, &input.proposal_to_confirm)?;
 
+                // Ensure that the proposal has collected enough endorsements to be confirmed
+                validate_recovery_endorsement_threshold_is_met(self)?;
+
                 let recovery_time_has_elapsed = Runtime::sys_compare_against_current_time(
                     api,
                     timed_recovery_allowed_after.clone(),
@@ -286,7 +584,9 @@ impl TransitionMut<AccessControllerTimedConfirmRecoveryStateMachineInput>
                 if !recovery_time_has_elapsed {
                     access_controller_runtime_error!(TimedRecoveryDelayHasNotElapsed)
                 } else {
-                    self.state = Default::default();
+                    record_recovery(self, api)?;
+
+                    discard_recovery_proposals(self);
 
                     Ok(proposal)
                 }
@@ -311,12 +611,19 @@ impl TransitionMut<AccessControllerCancelPrimaryRoleRecoveryProposalStateMachine
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        validate_not_paused(self)?;
+
         // A recovery attempt can only be canceled when we're in recovery mode regardless of whether
         // primary is locked or unlocked
         match self.state {
             (_, PrimaryOperationState::Recovery(..), _) => {
                 // Transition from the recovery state to the normal operations state
                 self.state.1 = PrimaryOperationState::Normal;
+                self.proposer_non_fungible_global_ids
+                    .remove(&Proposer::Primary);
+                self.proposal_authors.remove(&Proposer::Primary);
+                self.proposal_initiated_at.remove(&Proposer::Primary);
+                self.bump_recovery_proposal_nonce(Proposer::Primary);
                 Ok(())
             }
             _ => Err(RuntimeError::ApplicationError(
@@ -345,12 +652,20 @@ impl TransitionMut<AccessControllerCancelRecoveryRoleRecoveryProposalStateMachin
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        validate_not_paused(self)?;
+
         // A recovery attempt can only be canceled when we're in recovery mode regardless of whether
         // primary is locked or unlocked
         match self.state {
             (_, _, RecoveryOperationState::Recovery(..)) => {
                 // Transition from the recovery state to the normal operations state
                 self.state.2 = RecoveryOperationState::Normal;
+                self.proposer_non_fungible_global_ids
+                    .remove(&Proposer::Recovery);
+                self.proposal_authors.remove(&Proposer::Recovery);
+                self.proposal_initiated_at.remove(&Proposer::Recovery);
+                self.recovery_proposal_endorsements.clear();
+                self.bump_recovery_proposal_nonce(Proposer::Recovery);
                 Ok(())
             }
             _ => Err(RuntimeError::ApplicationError(
@@ -371,16 +686,31 @@ impl TransitionMut<AccessControllerLockPrimaryRoleStateMachineInput> for AccessC
 
     fn transition_mut<Y>(
         &mut self,
-        _api: &mut Y,
+        api: &mut Y,
         _input: AccessControllerLockPrimaryRoleStateMachineInput,
     ) -> Result<Self::Output, RuntimeError>
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
-        // Primary can only be locked when it's unlocked
+        // Primary can only be locked when it's unlocked. When the controller has a lock grace
+        // period, the lock is scheduled to take effect once the grace period elapses.
         match self.state {
             (ref mut primary_state @ PrimaryRoleState::Unlocked, _, _) => {
-                *primary_state = PrimaryRoleState::Locked;
+                match self.primary_role_lock_grace_period_in_minutes {
+                    Some(grace_period_in_minutes) => {
+                        let current_time = Runtime::sys_current_time(api, TimePrecision::Minute)?;
+                        let locks_at = current_time
+                            .add_minutes(grace_period_in_minutes as i64)
+                            .map_or(access_controller_runtime_error!(TimeOverflow), |instant| {
+                                Ok(instant)
+                            })?;
+
+                        *primary_state = PrimaryRoleState::LockPending { locks_at };
+                    }
+                    None => {
+                        *primary_state = PrimaryRoleState::Locked;
+                    }
+                }
                 Ok(())
             }
             _ => Ok(()),
@@ -403,9 +733,15 @@ impl TransitionMut<AccessControllerUnlockPrimaryRoleStateMachineInput>
     where
         Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
-        // Primary can only be unlocked when it's locked
+        // Primary can only be unlocked when it's locked or pending a lock, in which case the
+        // pending lock is canceled.
         match self.state {
-            (ref mut primary_state @ PrimaryRoleState::Locked, _, _) => {
+            (
+                ref mut primary_state @ (PrimaryRoleState::Locked
+                | PrimaryRoleState::LockPending { .. }),
+                _,
+                _,
+            ) => {
                 *primary_state = PrimaryRoleState::Unlocked;
                 Ok(())
             }
@@ -457,6 +793,297 @@ impl TransitionMut<AccessControllerStopTimedRecoveryStateMachineInput>
     }
 }
 
+pub(super) struct AccessControllerExtendTimedRecoveryStateMachineInput {
+    pub additional_minutes: i64,
+}
+
+impl TransitionMut<AccessControllerExtendTimedRecoveryStateMachineInput>
+    for AccessControllerSubstate
+{
+    type Output = ();
+
+    fn transition_mut<Y>(
+        &mut self,
+        _api: &mut Y,
+        input: AccessControllerExtendTimedRecoveryStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let additional_minutes = input.additional_minutes;
+        if additional_minutes <= 0 {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::AccessControllerError(
+                    AccessControllerError::TimedRecoveryCanOnlyBeExtended { additional_minutes },
+                ),
+            ));
+        }
+
+        // Only a timed recovery has a delay to extend, regardless of whether primary is locked
+        match self.state {
+            (
+                _,
+                _,
+                RecoveryOperationState::Recovery(RecoveryRecoveryState::Timed {
+                    ref mut timed_recovery_allowed_after,
+                    ..
+                }),
+            ) => {
+                *timed_recovery_allowed_after = timed_recovery_allowed_after
+                    .add_minutes(additional_minutes)
+                    .map_or(access_controller_runtime_error!(TimeOverflow), |instant| {
+                        Ok(instant)
+                    })?;
+                Ok(())
+            }
+            _ => access_controller_runtime_error!(InvalidStateTransition),
+        }
+    }
+}
+
+pub(super) struct AccessControllerEndorseRecoveryRoleRecoveryProposalStateMachineInput {
+    pub proposal_to_endorse: RecoveryProposal,
+    pub endorser: NonFungibleGlobalId,
+}
+
+impl TransitionMut<AccessControllerEndorseRecoveryRoleRecoveryProposalStateMachineInput>
+    for AccessControllerSubstate
+{
+    type Output = ();
+
+    fn transition_mut<Y>(
+        &mut self,
+        _api: &mut Y,
+        input: AccessControllerEndorseRecoveryRoleRecoveryProposalStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        validate_not_paused(self)?;
+
+        // Endorsements can only be collected while the recovery role has a proposal underway,
+        // regardless of whether it is timed or untimed.
+        match self.state {
+            (
+                _,
+                _,
+                RecoveryOperationState::Recovery(
+                    RecoveryRecoveryState::Untimed(ref proposal)
+                    | RecoveryRecoveryState::Timed { ref proposal, .. },
+                ),
+            ) => {
+                // Ensure that the caller has passed in the expected proposal
+                validate_recovery_proposal(proposal, &input.proposal_to_endorse)?;
+
+                // Each non-fungible may only be counted once towards the endorsement threshold
+                if self
+                    .recovery_proposal_endorsements
+                    .insert(input.endorser.clone())
+                {
+                    Ok(())
+                } else {
+                    Err(AccessControllerError::RecoveryProposalAlreadyEndorsed {
+                        endorser: input.endorser,
+                    }
+                    .into())
+                }
+            }
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::AccessControllerError(
+                    AccessControllerError::NoRecoveryExistsForProposer {
+                        proposer: Proposer::Recovery,
+                    },
+                ),
+            )),
+        }
+    }
+}
+
+pub(super) struct AccessControllerAddRecoveryDelegateStateMachineInput {
+    pub delegate: NonFungibleGlobalId,
+}
+
+impl TransitionMut<AccessControllerAddRecoveryDelegateStateMachineInput>
+    for AccessControllerSubstate
+{
+    type Output = ();
+
+    fn transition_mut<Y>(
+        &mut self,
+        _api: &mut Y,
+        input: AccessControllerAddRecoveryDelegateStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        if self.recovery_delegates.insert(input.delegate.clone()) {
+            Ok(())
+        } else {
+            Err(AccessControllerError::RecoveryDelegateAlreadyExists {
+                delegate: input.delegate,
+            }
+            .into())
+        }
+    }
+}
+
+pub(super) struct AccessControllerRemoveRecoveryDelegateStateMachineInput {
+    pub delegate: NonFungibleGlobalId,
+}
+
+impl TransitionMut<AccessControllerRemoveRecoveryDelegateStateMachineInput>
+    for AccessControllerSubstate
+{
+    type Output = ();
+
+    fn transition_mut<Y>(
+        &mut self,
+        _api: &mut Y,
+        input: AccessControllerRemoveRecoveryDelegateStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        if self.recovery_delegates.remove(&input.delegate) {
+            Ok(())
+        } else {
+            Err(AccessControllerError::RecoveryDelegateNotFound {
+                delegate: input.delegate,
+            }
+            .into())
+        }
+    }
+}
+
+pub(super) struct AccessControllerTransferOwnershipStateMachineInput;
+
+impl TransitionMut<AccessControllerTransferOwnershipStateMachineInput>
+    for AccessControllerSubstate
+{
+    type Output = ();
+
+    fn transition_mut<Y>(
+        &mut self,
+        _api: &mut Y,
+        _input: AccessControllerTransferOwnershipStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        // All three roles have agreed to hand the controller over, so any ongoing recoveries or
+        // locks were made by roles which are about to be replaced and are discarded.
+        discard_recovery_proposals(self);
+        self.recovery_delegates.clear();
+        Ok(())
+    }
+}
+
+pub(super) struct AccessControllerPauseStateMachineInput;
+
+impl TransitionMut<AccessControllerPauseStateMachineInput> for AccessControllerSubstate {
+    type Output = ();
+
+    fn transition_mut<Y>(
+        &mut self,
+        _api: &mut Y,
+        _input: AccessControllerPauseStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        // Pausing only freezes the recovery state machine where it is, so that any recovery which
+        // is underway can be resumed exactly as it was.
+        self.is_paused = true;
+        Ok(())
+    }
+}
+
+pub(super) struct AccessControllerResumeStateMachineInput;
+
+impl TransitionMut<AccessControllerResumeStateMachineInput> for AccessControllerSubstate {
+    type Output = ();
+
+    fn transition_mut<Y>(
+        &mut self,
+        _api: &mut Y,
+        _input: AccessControllerResumeStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        self.is_paused = false;
+        Ok(())
+    }
+}
+
+/// Creates a proof of a controlled asset, making it one-shot if the controller requires so.
+fn create_controlled_asset_proof<Y>(
+    access_controller: &AccessControllerSubstate,
+    vault: Vault,
+    api: &mut Y,
+) -> Result<Proof, RuntimeError>
+where
+    Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+{
+    let proof = vault.sys_create_proof(api)?;
+    if access_controller.one_shot_proofs {
+        api.invoke(ProofMakeOneShotInvocation { receiver: proof.0 })?;
+    }
+    Ok(proof)
+}
+
+/// Proofs can only be created when the primary role is unlocked - regardless of whether the
+/// controller is in recovery or normal operations. A pending lock does not prevent proofs from
+/// being created until its grace period has elapsed.
+fn validate_proofs_can_be_created<Y>(
+    access_controller: &AccessControllerSubstate,
+    api: &mut Y,
+) -> Result<(), RuntimeError>
+where
+    Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+{
+    match access_controller.state {
+        (PrimaryRoleState::Unlocked, _, _) => Ok(()),
+        (PrimaryRoleState::LockPending { ref locks_at }, _, _) => {
+            if primary_role_lock_has_taken_effect(&access_controller.state.0, api)? {
+                access_controller_runtime_error!(OperationRequiresUnlockedPrimaryRole)
+            } else {
+                api.invoke(LoggerLogInvocation {
+                    level: Level::Warn,
+                    message: format!(
+                        "The primary role is pending a lock which takes effect at {} seconds \
+                        since the Unix epoch",
+                        locks_at.seconds_since_unix_epoch
+                    ),
+                })?;
+                Ok(())
+            }
+        }
+        _ => access_controller_runtime_error!(OperationRequiresUnlockedPrimaryRole),
+    }
+}
+
+/// Determines whether the primary role is effectively locked: either locked outright, or pending a
+/// lock whose grace period has elapsed.
+pub(super) fn primary_role_lock_has_taken_effect<Y>(
+    primary_role_state: &PrimaryRoleState,
+    api: &mut Y,
+) -> Result<bool, RuntimeError>
+where
+    Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+{
+    match primary_role_state {
+        PrimaryRoleState::Unlocked => Ok(false),
+        PrimaryRoleState::LockPending { locks_at } => Runtime::sys_compare_against_current_time(
+            api,
+            locks_at.clone(),
+            TimePrecision::Minute,
+            TimeComparisonOperator::Gte,
+        ),
+        PrimaryRoleState::Locked => Ok(true),
+    }
+}
+
 fn validate_recovery_proposal(
     expected: &RecoveryProposal,
     actual: &RecoveryProposal,
@@ -470,3 +1097,200 @@ fn validate_recovery_proposal(
         })
     }
 }
+
+fn validate_recovery_proposal_nonce(
+    access_controller: &AccessControllerSubstate,
+    proposer: Proposer,
+    nonce: u64,
+) -> Result<(), AccessControllerError> {
+    let expected = access_controller.recovery_proposal_nonce(proposer);
+    if nonce == expected {
+        Ok(())
+    } else {
+        Err(AccessControllerError::StaleProposal {
+            expected,
+            found: nonce,
+        })
+    }
+}
+
+fn validate_confirmor_is_not_proposer(
+    access_controller: &AccessControllerSubstate,
+    proposer: Proposer,
+    confirmor_non_fungible_global_ids: &BTreeSet<NonFungibleGlobalId>,
+) -> Result<(), AccessControllerError> {
+    if !access_controller.prevent_self_confirmation {
+        return Ok(());
+    }
+
+    let is_self_confirmation = access_controller
+        .proposer_non_fungible_global_ids
+        .get(&proposer)
+        .map_or(false, |proposer_non_fungible_global_ids| {
+            !proposer_non_fungible_global_ids.is_disjoint(confirmor_non_fungible_global_ids)
+        });
+
+    if is_self_confirmation {
+        Err(AccessControllerError::SelfConfirmationNotAllowed { proposer })
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_recovery_endorsement_threshold_is_met(
+    access_controller: &AccessControllerSubstate,
+) -> Result<(), AccessControllerError> {
+    let threshold = match access_controller.recovery_endorsement_threshold {
+        Some(threshold) => threshold,
+        None => return Ok(()),
+    };
+
+    let endorsements = access_controller.recovery_proposal_endorsements.len();
+    if endorsements >= threshold as usize {
+        Ok(())
+    } else {
+        // Below the threshold, so the count fits in the type of the threshold
+        Err(AccessControllerError::RecoveryEndorsementThresholdNotMet {
+            endorsements: endorsements as u8,
+            threshold,
+        })
+    }
+}
+
+fn validate_not_paused(
+    access_controller: &AccessControllerSubstate,
+) -> Result<(), AccessControllerError> {
+    if access_controller.is_paused {
+        Err(AccessControllerError::ControllerPaused)
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_outstanding_proposals_below_cap(
+    access_controller: &AccessControllerSubstate,
+) -> Result<(), AccessControllerError> {
+    if access_controller.outstanding_recovery_proposals() >= MAX_OUTSTANDING_RECOVERY_PROPOSALS {
+        Err(AccessControllerError::TooManyProposals)
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_no_recovery_exists_for_proposer(
+    access_controller: &AccessControllerSubstate,
+    proposer: Proposer,
+) -> Result<(), AccessControllerError> {
+    let recovery_exists = match proposer {
+        Proposer::Primary => !matches!(access_controller.state.1, PrimaryOperationState::Normal),
+        Proposer::Recovery => !matches!(access_controller.state.2, RecoveryOperationState::Normal),
+    };
+
+    if recovery_exists {
+        Err(AccessControllerError::RecoveryAlreadyExistsForProposer { proposer })
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that a recovery proposal does not shorten the window in which a timed recovery can be
+/// cancelled. Proposing to disable timed recoveries is always allowed, as is any delay when the
+/// controller does not currently allow timed recoveries. Only the primary and recovery roles can
+/// propose, so there is no proposer exempt from this check.
+fn validate_proposed_delay_is_not_a_downgrade(
+    access_controller: &AccessControllerSubstate,
+    proposal: &RecoveryProposal,
+) -> Result<(), AccessControllerError> {
+    match (
+        access_controller.timed_recovery_delay_in_minutes,
+        proposal.timed_recovery_delay_in_minutes,
+    ) {
+        (Some(active), Some(proposed)) if proposed < active => {
+            Err(AccessControllerError::DelayDowngradeNotAllowed { active, proposed })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the controller to normal operations with the primary role unlocked, discarding the
+/// recovery proposals of both proposers. Their nonces are bumped, as neither proposal may be
+/// confirmed anymore.
+fn discard_recovery_proposals(access_controller: &mut AccessControllerSubstate) {
+    access_controller.state = Default::default();
+    access_controller.proposer_non_fungible_global_ids.clear();
+    access_controller.proposal_authors.clear();
+    access_controller.proposal_initiated_at.clear();
+    access_controller.recovery_proposal_endorsements.clear();
+    access_controller.bump_recovery_proposal_nonce(Proposer::Primary);
+    access_controller.bump_recovery_proposal_nonce(Proposer::Recovery);
+}
+
+/// Ensures that the controller's minimum interval between recoveries has elapsed since the last
+/// confirmed recovery and, if so, records the current time as the time of the latest recovery.
+fn record_recovery<Y>(
+    access_controller: &mut AccessControllerSubstate,
+    api: &mut Y,
+) -> Result<(), RuntimeError>
+where
+    Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+{
+    if access_controller
+        .minimum_recovery_interval_in_minutes
+        .is_none()
+    {
+        return Ok(());
+    }
+
+    let current_time = Runtime::sys_current_time(api, TimePrecision::Minute)?;
+    if let Some(recovery_allowed_at) = recovery_allowed_at(access_controller)? {
+        if !current_time.compare(recovery_allowed_at, TimeComparisonOperator::Gte) {
+            return Err(AccessControllerError::RecoveryCooldownActive {
+                recovery_allowed_at,
+            }
+            .into());
+        }
+    }
+
+    access_controller.last_recovery_at = Some(current_time);
+    Ok(())
+}
+
+/// Computes the instant from which the controller's minimum interval between recoveries allows a
+/// recovery to be confirmed again, or [`None`] if there is no such restriction.
+fn recovery_allowed_at(
+    access_controller: &AccessControllerSubstate,
+) -> Result<Option<Instant>, RuntimeError> {
+    match (
+        access_controller.last_recovery_at,
+        access_controller.minimum_recovery_interval_in_minutes,
+    ) {
+        (Some(last_recovery_at), Some(minimum_interval_in_minutes)) => last_recovery_at
+            .add_minutes(minimum_interval_in_minutes as i64)
+            .map_or(access_controller_runtime_error!(TimeOverflow), |instant| {
+                Ok(Some(instant))
+            }),
+        _ => Ok(None),
+    }
+}
+
+/// Computes the instant after which a recovery proposal of the recovery role initiated now could be
+/// timed-confirmed, or [`None`] if the controller does not allow timed recoveries.
+fn timed_recovery_allowed_after<Y>(
+    access_controller: &AccessControllerSubstate,
+    api: &mut Y,
+) -> Result<Option<Instant>, RuntimeError>
+where
+    Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+{
+    match access_controller.timed_recovery_delay_in_minutes {
+        Some(delay_in_minutes) => {
+            let current_time = Runtime::sys_current_time(api, TimePrecision::Minute)?;
+            current_time
+                .add_minutes(delay_in_minutes as i64)
+                .map_or(access_controller_runtime_error!(TimeOverflow), |instant| {
+                    Ok(Some(instant))
+                })
+        }
+        None => Ok(None),
+    }
+}
@@ -1,16 +1,79 @@
 use crate::types::*;
 use radix_engine_interface::time::Instant;
 
+/// The maximum number of recovery proposals that may be outstanding on an access controller at any
+/// one time, across all proposers.
+pub const MAX_OUTSTANDING_RECOVERY_PROPOSALS: usize = 2;
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct AccessControllerSubstate {
-    /// A vault where the asset controlled by the access controller lives.
-    pub controlled_asset: VaultId,
+    /// The vaults where the assets controlled by the access controller live, one per asset. There
+    /// is always at least one.
+    pub controlled_assets: Vec<VaultId>,
 
     /// The amount of time (in minutes) that it takes for timed recovery to be done. Maximum is
     /// 4,294,967,295 minutes which is 8171.5511700913 years. When this is [`None`], then timed
     /// recovery can not be performed through this access controller.
     pub timed_recovery_delay_in_minutes: Option<u32>,
 
+    /// Controls whether a recovery proposal can be quick-confirmed by the same entity that has
+    /// proposed it. When this is `true`, quick-confirming a proposal with a proof of any of the
+    /// non-fungibles used to propose it fails.
+    pub prevent_self_confirmation: bool,
+
+    /// The global ids of the non-fungibles that were present in the auth zone of each proposer
+    /// when they initiated their recovery proposal.
+    pub proposer_non_fungible_global_ids: BTreeMap<Proposer, BTreeSet<NonFungibleGlobalId>>,
+
+    /// The global id of the non-fungible which authored the recovery proposal of each proposer.
+    /// Proposals whose author couldn't be singled out from the auth zone of the proposer, such as
+    /// those made with fungible badges, have no entry.
+    pub proposal_authors: BTreeMap<Proposer, NonFungibleGlobalId>,
+
+    /// The instant at which the recovery proposal of each proposer was initiated.
+    pub proposal_initiated_at: BTreeMap<Proposer, Instant>,
+
+    /// The amount of time (in minutes) between the primary role being locked and the lock taking
+    /// effect. During this grace period the primary role can still act, but a warning is logged
+    /// whenever it does. When this is [`None`], locking the primary role takes effect immediately.
+    pub primary_role_lock_grace_period_in_minutes: Option<u32>,
+
+    /// The number of distinct endorsements that a recovery proposal of the recovery role needs to
+    /// collect before it can be confirmed. When this is [`None`], recovery proposals of the
+    /// recovery role do not need to be endorsed.
+    pub recovery_endorsement_threshold: Option<u8>,
+
+    /// The global ids of the non-fungibles which have endorsed the current recovery proposal of
+    /// the recovery role. Each non-fungible can only endorse a proposal once.
+    pub recovery_proposal_endorsements: BTreeSet<NonFungibleGlobalId>,
+
+    /// The minimum amount of time (in minutes) that must pass between two successful recoveries.
+    /// When this is [`None`], recoveries can be confirmed back-to-back.
+    pub minimum_recovery_interval_in_minutes: Option<u32>,
+
+    /// Controls whether the proofs created by the access controller are one-shot. When this is
+    /// `true`, the proofs can't be cloned and can only be pushed onto an auth zone once, limiting
+    /// what can be done with a leaked proof.
+    pub one_shot_proofs: bool,
+
+    /// The instant at which the last recovery proposal was confirmed, or [`None`] if no recovery
+    /// has been confirmed yet.
+    pub last_recovery_at: Option<Instant>,
+
+    /// The global ids of the non-fungibles whose holders may initiate a recovery proposal in the
+    /// slot of the recovery role. Delegates can only propose; confirming still requires the roles.
+    pub recovery_delegates: BTreeSet<NonFungibleGlobalId>,
+
+    /// Whether all recovery activity on the controller is paused. While paused, recoveries can't be
+    /// initiated, confirmed, endorsed or cancelled, but proofs can still be created.
+    pub is_paused: bool,
+
+    /// The nonce which confirmations of the recovery proposal of each proposer must reference. It
+    /// is bumped whenever a proposal of the proposer is cancelled or confirmed, so that a
+    /// confirmation made for an earlier proposal can't be replayed against a later one with the
+    /// same contents. Proposers without an entry are at a nonce of zero.
+    pub recovery_proposal_nonces: BTreeMap<Proposer, u64>,
+
     /// The states of the Access Controller.
     pub state: (
         PrimaryRoleState,
@@ -20,19 +83,74 @@ pub struct AccessControllerSubstate {
 }
 
 impl AccessControllerSubstate {
-    pub fn new(controlled_asset: VaultId, timed_recovery_delay_in_minutes: Option<u32>) -> Self {
+    pub fn new(
+        controlled_assets: Vec<VaultId>,
+        timed_recovery_delay_in_minutes: Option<u32>,
+        settings: AccessControllerSettings,
+    ) -> Self {
+        let AccessControllerSettings {
+            prevent_self_confirmation,
+            primary_role_lock_grace_period_in_minutes,
+            recovery_endorsement_threshold,
+            minimum_recovery_interval_in_minutes,
+            one_shot_proofs,
+        } = settings;
+
         Self {
-            controlled_asset,
+            controlled_assets,
             timed_recovery_delay_in_minutes,
+            prevent_self_confirmation,
+            proposer_non_fungible_global_ids: BTreeMap::new(),
+            proposal_authors: BTreeMap::new(),
+            proposal_initiated_at: BTreeMap::new(),
+            primary_role_lock_grace_period_in_minutes,
+            recovery_endorsement_threshold,
+            recovery_proposal_endorsements: BTreeSet::new(),
+            minimum_recovery_interval_in_minutes,
+            one_shot_proofs,
+            last_recovery_at: None,
+            recovery_delegates: BTreeSet::new(),
+            is_paused: false,
+            recovery_proposal_nonces: BTreeMap::new(),
             state: Default::default(),
         }
     }
+
+    /// The vault of the first controlled asset, which is the one that single proofs are created of
+    /// and whose resource manager the controller describes.
+    pub fn controlled_asset(&self) -> VaultId {
+        self.controlled_assets[0]
+    }
+
+    /// The number of recovery proposals currently underway on the controller.
+    pub fn outstanding_recovery_proposals(&self) -> usize {
+        let primary = !matches!(self.state.1, PrimaryOperationState::Normal);
+        let recovery = !matches!(self.state.2, RecoveryOperationState::Normal);
+        primary as usize + recovery as usize
+    }
+
+    /// The nonce which confirmations of the current recovery proposal of the proposer must
+    /// reference.
+    pub fn recovery_proposal_nonce(&self, proposer: Proposer) -> u64 {
+        self.recovery_proposal_nonces
+            .get(&proposer)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Invalidates any confirmation made for the current recovery proposal of the proposer.
+    pub fn bump_recovery_proposal_nonce(&mut self, proposer: Proposer) {
+        *self.recovery_proposal_nonces.entry(proposer).or_default() += 1;
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode, Default)]
 pub enum PrimaryRoleState {
     #[default]
     Unlocked,
+    LockPending {
+        locks_at: Instant,
+    },
     Locked,
 }
 
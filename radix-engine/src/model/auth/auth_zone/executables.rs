@@ -123,9 +123,17 @@ impl Executor for AuthZonePushInvocation {
         )?;
         let substate_ref = system_api.get_ref(handle)?;
         let proof = substate_ref.proof();
+        if proof.is_one_shot_used() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::AuthZoneError(AuthZoneError::ProofError(
+                    ProofError::OneShotProofAlreadyUsed,
+                )),
+            ));
+        }
         // Take control of the proof lock as the proof in the call frame will lose it's lock once dropped
         let mut cloned_proof = proof.clone();
         cloned_proof.change_to_unrestricted();
+        cloned_proof.mark_one_shot_used();
 
         let mut substate_mut = system_api.get_ref_mut(auth_zone_handle)?;
         let auth_zone_stack = substate_mut.auth_zone_stack();
@@ -252,13 +260,16 @@ impl Executor for AuthZoneCreateProofByAmountInvocation {
         let proof = {
             let mut substate_mut = api.get_ref_mut(auth_zone_handle)?;
             let auth_zone_stack = substate_mut.auth_zone_stack();
-            let proof = auth_zone_stack.cur_auth_zone().create_proof_by_amount(
-                self.amount,
-                self.resource_address,
-                resource_type,
-            )?;
-
-            proof
+            let auth_zone = auth_zone_stack.cur_auth_zone();
+            if self.allow_zero_amount && self.amount.is_zero() {
+                auth_zone.create_presence_proof(self.resource_address, resource_type)?
+            } else {
+                auth_zone.create_proof_by_amount(
+                    self.amount,
+                    self.resource_address,
+                    resource_type,
+                )?
+            }
         };
 
         let node_id = api.allocate_node_id(RENodeType::Proof)?;
@@ -444,6 +455,57 @@ impl Executor for AuthZoneDrainInvocation {
     }
 }
 
+impl ExecutableInvocation for AuthZoneListInvocation {
+    type Exec = Self;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        _deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::AuthZoneStack(self.receiver);
+        let resolved_receiver = ResolvedReceiver::new(receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+
+        let actor = ResolvedActor::method(
+            NativeFn::AuthZoneStack(AuthZoneStackFn::List),
+            resolved_receiver,
+        );
+
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for AuthZoneListInvocation {
+    type Output = Vec<(ResourceAddress, Decimal)>;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Vec<(ResourceAddress, Decimal)>, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::AuthZoneStack(self.receiver);
+        let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
+        let auth_zone_handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+
+        let proofs = {
+            let substate_ref = api.get_ref(auth_zone_handle)?;
+            let auth_zone_stack = substate_ref.auth_zone_stack();
+            auth_zone_stack
+                .cur_auth_zone()
+                .proofs()
+                .iter()
+                .map(|proof| (proof.resource_address(), proof.total_amount()))
+                .collect()
+        };
+
+        api.drop_lock(auth_zone_handle)?;
+
+        Ok((proofs, CallFrameUpdate::empty()))
+    }
+}
+
 impl ExecutableInvocation for AuthZoneAssertAccessRuleInvocation {
     type Exec = Self;
 
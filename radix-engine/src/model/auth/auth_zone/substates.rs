@@ -1,8 +1,8 @@
 use crate::model::MethodAuthorizationError::NotAuthorized;
 use crate::model::{
     AuthZoneError, HardAuthRule, HardCount, HardDecimal, HardProofRule, HardProofRuleResourceList,
-    HardResourceOrNonFungible, InvokeError, MethodAuthorization, MethodAuthorizationError,
-    ProofSubstate,
+    HardResourceOrNonFungible, InvokeError, LockedAmountOrIds, MethodAuthorization,
+    MethodAuthorizationError, ProofSubstate, ResourceContainerId,
 };
 use crate::types::*;
 use sbor::rust::ops::Fn;
@@ -11,11 +11,16 @@ struct AuthVerification;
 
 impl AuthVerification {
     pub fn proof_matches(resource_rule: &HardResourceOrNonFungible, proof: &ProofSubstate) -> bool {
+        // presence proofs never satisfy an access rule, see `ProofValidationMode::ValidatePresence`
+        if proof.is_presence() {
+            return false;
+        }
+
         match resource_rule {
             HardResourceOrNonFungible::NonFungible(non_fungible_global_id) => {
                 let proof_resource_address = proof.resource_address();
                 proof_resource_address == non_fungible_global_id.resource_address()
-                    && match proof.total_ids() {
+                    && match proof.visible_ids() {
                         Ok(ids) => ids.contains(non_fungible_global_id.local_id()),
                         Err(_) => false,
                     }
@@ -257,6 +262,67 @@ impl AuthZoneStackSubstate {
         Ok(())
     }
 
+    /// Checks whether the given proofs alone would satisfy a method authorization, independently
+    /// of any auth zone. The proofs are dropped once checked, releasing their locks.
+    pub fn check_proofs(
+        proofs: Vec<ProofSubstate>,
+        method_auth: &MethodAuthorization,
+    ) -> Result<(), MethodAuthorizationError> {
+        let mut auth_zone_stack = Self::new(proofs, BTreeSet::new(), BTreeSet::new());
+        let result = AuthVerification::verify_method_auth(0, method_auth, &auth_zone_stack);
+        auth_zone_stack.clear_all();
+        result
+    }
+
+    /// Checks whether the given non-fungible alone would satisfy a method authorization, without
+    /// any proof of it. The non-fungible is treated like a virtual badge, so it only satisfies
+    /// rules naming it directly - rules on its resource as a whole still require a proof.
+    pub fn check_non_fungible_global_id(
+        non_fungible_global_id: NonFungibleGlobalId,
+        method_auth: &MethodAuthorization,
+    ) -> Result<(), MethodAuthorizationError> {
+        let auth_zone_stack = Self::new(
+            Vec::new(),
+            BTreeSet::new(),
+            btreeset!(non_fungible_global_id),
+        );
+        AuthVerification::verify_method_auth(0, method_auth, &auth_zone_stack)
+    }
+
+    /// Collects the global ids of all non-fungibles in the proofs which are visible for auth
+    /// purposes, crossing barriers in the same way as [`Self::check_auth`].
+    pub fn visible_non_fungible_global_ids(
+        &self,
+        is_barrier: bool,
+    ) -> BTreeSet<NonFungibleGlobalId> {
+        let mut barrier_crossings_allowed = 1u32;
+        if is_barrier {
+            barrier_crossings_allowed -= 1;
+        }
+
+        let mut non_fungible_global_ids = BTreeSet::new();
+        for auth_zone in self.auth_zones.iter().rev() {
+            for proof in auth_zone.proofs.iter() {
+                if let Ok(ids) = proof.visible_ids() {
+                    let resource_address = proof.resource_address();
+                    non_fungible_global_ids.extend(
+                        ids.into_iter()
+                            .map(|id| NonFungibleGlobalId::new(resource_address, id)),
+                    );
+                }
+            }
+
+            if auth_zone.barrier {
+                if barrier_crossings_allowed == 0 {
+                    break;
+                }
+                barrier_crossings_allowed -= 1;
+            }
+        }
+
+        non_fungible_global_ids
+    }
+
     pub fn new_frame(
         &mut self,
         virtual_non_fungibles_non_extending: BTreeSet<NonFungibleGlobalId>,
@@ -339,6 +405,25 @@ impl AuthZone {
         self.proofs.push(proof);
     }
 
+    /// Removes a proof holding exactly the given locks, preferring the one at `index` and otherwise
+    /// taking the topmost one. See [`ProofSubstate::locks`].
+    pub fn remove_proof_with_locks(
+        &mut self,
+        index: usize,
+        locks: &HashMap<ResourceContainerId, LockedAmountOrIds>,
+    ) -> Option<ProofSubstate> {
+        let holds_locks = |proof: &ProofSubstate| proof.locks() == *locks;
+        let index = match self.proofs.get(index) {
+            Some(proof) if holds_locks(proof) => index,
+            _ => self.proofs.iter().rposition(holds_locks)?,
+        };
+        Some(self.proofs.remove(index))
+    }
+
+    pub fn proofs(&self) -> &[ProofSubstate] {
+        &self.proofs
+    }
+
     pub fn drain(&mut self) -> Vec<ProofSubstate> {
         self.proofs.drain(0..).collect()
     }
@@ -372,6 +457,15 @@ impl AuthZone {
             .map_err(|e| InvokeError::SelfError(AuthZoneError::ProofError(e)))
     }
 
+    pub fn create_presence_proof(
+        &self,
+        resource_address: ResourceAddress,
+        resource_type: ResourceType,
+    ) -> Result<ProofSubstate, InvokeError<AuthZoneError>> {
+        ProofSubstate::compose_presence(&self.proofs, resource_address, resource_type)
+            .map_err(|e| InvokeError::SelfError(AuthZoneError::ProofError(e)))
+    }
+
     pub fn create_proof_by_ids(
         &self,
         ids: &BTreeSet<NonFungibleLocalId>,
@@ -161,6 +161,76 @@ impl Executor for ComponentGlobalizeWithOwnerInvocation {
     }
 }
 
+impl ExecutableInvocation for ComponentGlobalizeWithAccessRulesInvocation {
+    type Exec = Self;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        _deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let actor =
+            ResolvedActor::function(NativeFn::Component(ComponentFn::GlobalizeWithAccessRules));
+        let call_frame_update = CallFrameUpdate::move_node(RENodeId::Component(self.component_id));
+
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for ComponentGlobalizeWithAccessRulesInvocation {
+    type Output = ComponentAddress;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(ComponentAddress, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let component_node_id = RENodeId::Component(self.component_id);
+        let global_node_id = {
+            let handle = api.lock_substate(
+                component_node_id,
+                SubstateOffset::Component(ComponentOffset::Info),
+                LockFlags::read_only(),
+            )?;
+            let substate_ref = api.get_ref(handle)?;
+            let node_id = if substate_ref
+                .component_info()
+                .package_address
+                .eq(&ACCOUNT_PACKAGE)
+            {
+                api.allocate_node_id(RENodeType::GlobalAccount)?
+            } else {
+                api.allocate_node_id(RENodeType::GlobalComponent)?
+            };
+            api.drop_lock(handle)?;
+            node_id
+        };
+        let component_address: ComponentAddress = global_node_id.into();
+
+        // The supplied access rules are added to the chain before the component becomes globally
+        // reachable, so there is no window in which it is callable without them.
+        api.invoke(AccessRulesAddAccessCheckInvocation {
+            receiver: component_node_id,
+            access_rules: self.access_rules,
+        })?;
+
+        api.create_node(
+            global_node_id,
+            RENodeInit::Global(GlobalAddressSubstate::Component(self.component_id)),
+        )?;
+
+        let call_frame_update = CallFrameUpdate::copy_ref(RENodeId::Global(
+            GlobalAddress::Component(component_address),
+        ));
+
+        Ok((component_address, call_frame_update))
+    }
+}
+
 impl ExecutableInvocation for ComponentSetRoyaltyConfigInvocation {
     type Exec = Self;
 
@@ -76,6 +76,13 @@ impl<R: FeeReserve> BaseModule<R> for CostingModule {
                     SystemApiCostingEntry::ReadOwnedNodes,
                 )?;
             }
+            SysCallInput::ReadActorContext => {
+                consume_api_cost(
+                    track,
+                    "read_actor_context",
+                    SystemApiCostingEntry::ReadActorContext,
+                )?;
+            }
             SysCallInput::DropNode { .. } => {
                 // TODO: get size of the value
                 consume_api_cost(
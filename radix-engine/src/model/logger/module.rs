@@ -53,6 +53,9 @@ impl<R: FeeReserve> BaseModule<R> for LoggerModule {
             SysCallInput::ReadOwnedNodes => {
                 log!(call_frame, "Reading owned nodes");
             }
+            SysCallInput::ReadActorContext => {
+                log!(call_frame, "Reading actor context");
+            }
             SysCallInput::DropNode { node_id } => {
                 log!(call_frame, "Dropping node: node_id = {:?}", node_id);
             }
@@ -105,6 +108,7 @@ impl<R: FeeReserve> BaseModule<R> for LoggerModule {
                 log!(call_frame, "Exiting invoke: output = {:?}", rtn);
             }
             SysCallOutput::ReadOwnedNodes { .. } => {}
+            SysCallOutput::ReadActorContext { .. } => {}
             SysCallOutput::DropNode { .. } => {}
             SysCallOutput::CreateNode { .. } => {}
             SysCallOutput::LockSubstate { lock_handle } => {
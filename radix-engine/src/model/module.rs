@@ -8,6 +8,7 @@ use sbor::rust::vec::Vec;
 pub struct KernelModule {
     trace: bool,
     execution_trace: ExecutionTraceModule,
+    substate_access: Option<SubstateAccessModule>,
     costing: CostingModule,
     royalty: RoyaltyModule,
 }
@@ -17,6 +18,11 @@ impl KernelModule {
         Self {
             trace: config.trace,
             execution_trace: ExecutionTraceModule::new(config.max_sys_call_trace_depth),
+            substate_access: if config.count_substate_accesses {
+                Some(SubstateAccessModule::default())
+            } else {
+                None
+            },
             royalty: RoyaltyModule::default(),
             costing: CostingModule::new(config.max_call_depth),
         }
@@ -25,7 +31,11 @@ impl KernelModule {
 
 impl KernelModule {
     pub fn collect_events(&mut self) -> Vec<TrackedEvent> {
-        self.execution_trace.collect_events()
+        let mut events = self.execution_trace.collect_events();
+        if let Some(substate_access) = &mut self.substate_access {
+            events.extend(substate_access.collect_events());
+        }
+        events
     }
 }
 
@@ -119,6 +129,9 @@ impl<R: FeeReserve> BaseModule<R> for KernelModule {
             .post_execute_invocation(caller, update, call_frame, heap, track)?;
         self.execution_trace
             .post_execute_invocation(caller, update, call_frame, heap, track)?;
+        if let Some(substate_access) = &mut self.substate_access {
+            substate_access.post_execute_invocation(caller, update, call_frame, heap, track)?;
+        }
 
         Ok(())
     }
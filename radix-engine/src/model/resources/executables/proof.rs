@@ -2,11 +2,11 @@ use crate::engine::{
     CallFrameUpdate, ExecutableInvocation, Executor, LockFlags, RENodeInit, ResolvedActor,
     ResolvedReceiver, ResolverApi, RuntimeError, SystemApi,
 };
-use crate::model::ResourceOperationError;
+use crate::model::{convert_contextless, AuthZoneStackSubstate, ResourceOperationError};
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::types::{
-    GlobalAddress, NativeFn, ProofFn, ProofOffset, RENodeId, SubstateOffset,
+    GlobalAddress, KeyValueStoreOffset, NativeFn, ProofFn, ProofOffset, RENodeId, SubstateOffset,
 };
 use radix_engine_interface::model::*;
 
@@ -24,6 +24,12 @@ pub enum ProofError {
     FungibleOperationNotAllowed,
     CouldNotCreateProof,
     InvalidRequestData(DecodeError),
+    /// One-shot proofs can't be cloned.
+    OneShotProofCannotBeCloned,
+    /// One-shot proofs can't be pushed onto an auth zone more than once.
+    OneShotProofAlreadyUsed,
+    /// No access rule is stored under the key a proof was asked to be validated against.
+    StoredAccessRuleNotFound,
 }
 
 impl ExecutableInvocation for ProofGetAmountInvocation {
@@ -95,7 +101,7 @@ impl Executor for ProofGetNonFungibleLocalIdsInvocation {
         let handle = system_api.lock_substate(node_id, offset, LockFlags::read_only())?;
         let substate_ref = system_api.get_ref(handle)?;
         let proof = substate_ref.proof();
-        let ids = proof.total_ids()?;
+        let ids = proof.visible_ids()?;
 
         Ok((ids, CallFrameUpdate::empty()))
     }
@@ -175,6 +181,11 @@ impl Executor for ProofCloneInvocation {
         let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
         let substate_ref = api.get_ref(handle)?;
         let proof = substate_ref.proof();
+        if proof.is_one_shot() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ProofError(ProofError::OneShotProofCannotBeCloned),
+            ));
+        }
         let cloned_proof = proof.clone();
 
         let node_id = api.allocate_node_id(RENodeType::Proof)?;
@@ -187,3 +198,243 @@ impl Executor for ProofCloneInvocation {
         ))
     }
 }
+
+impl ExecutableInvocation for ProofMakeOneShotInvocation {
+    type Exec = Self;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        _api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Proof(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = ResolvedActor::method(
+            NativeFn::Proof(ProofFn::MakeOneShot),
+            ResolvedReceiver::new(receiver),
+        );
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for ProofMakeOneShotInvocation {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Proof(self.receiver);
+        let offset = SubstateOffset::Proof(ProofOffset::Proof);
+        let handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        let mut substate_mut = api.get_ref_mut(handle)?;
+        substate_mut.proof().change_to_one_shot();
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+impl ExecutableInvocation for ProofIntoResourceProofInvocation {
+    type Exec = Self;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        _api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Proof(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = ResolvedActor::method(
+            NativeFn::Proof(ProofFn::IntoResourceProof),
+            ResolvedReceiver::new(receiver),
+        );
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for ProofIntoResourceProofInvocation {
+    type Output = Proof;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Proof, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Proof(self.receiver);
+        let offset = SubstateOffset::Proof(ProofOffset::Proof);
+        let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let substate_ref = api.get_ref(handle)?;
+        let proof = substate_ref.proof();
+        if proof.is_one_shot() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ProofError(ProofError::OneShotProofCannotBeCloned),
+            ));
+        }
+        let mut resource_proof = proof.clone();
+        resource_proof.change_to_ids_hidden();
+
+        let node_id = api.allocate_node_id(RENodeType::Proof)?;
+        api.create_node(node_id, RENodeInit::Proof(resource_proof))?;
+        let proof_id = node_id.into();
+
+        Ok((
+            Proof(proof_id),
+            CallFrameUpdate::move_node(RENodeId::Proof(proof_id)),
+        ))
+    }
+}
+
+impl ExecutableInvocation for ProofValidateAgainstStoredRuleInvocation {
+    type Exec = Self;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        _api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Proof(self.receiver);
+        let mut call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        call_frame_update
+            .node_refs_to_copy
+            .insert(RENodeId::KeyValueStore(self.kv_store_id));
+        let actor = ResolvedActor::method(
+            NativeFn::Proof(ProofFn::ValidateAgainstStoredRule),
+            ResolvedReceiver::new(receiver),
+        );
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for ProofValidateAgainstStoredRuleInvocation {
+    type Output = bool;
+
+    fn execute<Y, W: WasmEngine>(self, api: &mut Y) -> Result<(bool, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::KeyValueStore(self.kv_store_id);
+        let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(self.key));
+        let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let entry = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref.kv_store_entry().clone()
+        };
+        api.drop_lock(handle)?;
+        let raw = entry.0.ok_or(RuntimeError::ApplicationError(
+            ApplicationError::ProofError(ProofError::StoredAccessRuleNotFound),
+        ))?;
+        let access_rule: AccessRule = scrypto_decode(&raw).map_err(|e| {
+            RuntimeError::ApplicationError(ApplicationError::ProofError(
+                ProofError::InvalidRequestData(e),
+            ))
+        })?;
+
+        let node_id = RENodeId::Proof(self.receiver);
+        let offset = SubstateOffset::Proof(ProofOffset::Proof);
+        let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        // The clone holds its own locks on the proof's containers, released once it is checked
+        let proof = {
+            let substate_ref = api.get_ref(handle)?;
+            substate_ref.proof().clone()
+        };
+        api.drop_lock(handle)?;
+
+        let is_satisfied =
+            AuthZoneStackSubstate::check_proofs(vec![proof], &convert_contextless(&access_rule))
+                .is_ok();
+
+        Ok((is_satisfied, CallFrameUpdate::empty()))
+    }
+}
+
+impl ExecutableInvocation for ProofSatisfiedRolesInvocation {
+    type Exec = Self;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        _api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Proof(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = ResolvedActor::method(
+            NativeFn::Proof(ProofFn::SatisfiedRoles),
+            ResolvedReceiver::new(receiver),
+        );
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for ProofSatisfiedRolesInvocation {
+    type Output = Vec<Role>;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Vec<Role>, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Proof(self.receiver);
+        let offset = SubstateOffset::Proof(ProofOffset::Proof);
+        let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let roles: Vec<Role> = {
+            let substate_ref = api.get_ref(handle)?;
+            let proof = substate_ref.proof();
+            [
+                (Role::Primary, &self.rule_set.primary_role),
+                (Role::Recovery, &self.rule_set.recovery_role),
+                (Role::Confirmation, &self.rule_set.confirmation_role),
+            ]
+            .into_iter()
+            .filter(|(_, access_rule)| {
+                // Each rule is checked against its own clone, which releases its locks once checked
+                AuthZoneStackSubstate::check_proofs(
+                    vec![proof.clone()],
+                    &convert_contextless(access_rule),
+                )
+                .is_ok()
+            })
+            .map(|(role, _)| role)
+            .collect()
+        };
+        api.drop_lock(handle)?;
+
+        Ok((roles, CallFrameUpdate::empty()))
+    }
+}
+
+impl ExecutableInvocation for ProofIsPresenceInvocation {
+    type Exec = Self;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        _api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Proof(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = ResolvedActor::method(
+            NativeFn::Proof(ProofFn::IsPresence),
+            ResolvedReceiver::new(receiver),
+        );
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for ProofIsPresenceInvocation {
+    type Output = bool;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        system_api: &mut Y,
+    ) -> Result<(bool, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Proof(self.receiver);
+        let offset = SubstateOffset::Proof(ProofOffset::Proof);
+        let handle = system_api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let substate_ref = system_api.get_ref(handle)?;
+        let proof = substate_ref.proof();
+
+        Ok((proof.is_presence(), CallFrameUpdate::empty()))
+    }
+}
@@ -240,6 +240,16 @@ fn build_substates(
         AllowAll,
         DenyAll,
     );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::GetBehavior)),
+        AllowAll,
+        DenyAll,
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::IsFrozen)),
+        AllowAll,
+        DenyAll,
+    );
     access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::CreateVault)),
         AllowAll,
@@ -1455,6 +1465,146 @@ impl Executor for ResourceManagerGetResourceTypeExecutable {
     }
 }
 
+impl ExecutableInvocation for ResourceManagerGetBehaviorInvocation {
+    type Exec = ResourceManagerGetBehaviorExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = ResolvedActor::method(
+            NativeFn::ResourceManager(ResourceManagerFn::GetBehavior),
+            resolved_receiver,
+        );
+        let executor = ResourceManagerGetBehaviorExecutable(resolved_receiver.receiver);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerGetBehaviorExecutable(RENodeId);
+
+impl Executor for ResourceManagerGetBehaviorExecutable {
+    type Output = BehaviorFlags;
+
+    fn execute<'a, Y, W: WasmEngine>(
+        self,
+        system_api: &mut Y,
+    ) -> Result<(BehaviorFlags, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let mut behavior = BehaviorFlags::empty();
+
+        let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+        let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        {
+            let substate_ref = system_api.get_ref(handle)?;
+            let access_rules = &substate_ref.access_rules_chain().access_rules_chain[0];
+            let rules = [
+                (ResourceMethodAuthKey::Mint, access_rules.get_group("mint")),
+                (
+                    ResourceMethodAuthKey::Burn,
+                    access_rules.get(&AccessRuleKey::Native(NativeFn::ResourceManager(
+                        ResourceManagerFn::Burn,
+                    ))),
+                ),
+                (
+                    ResourceMethodAuthKey::UpdateNonFungibleData,
+                    access_rules.get(&AccessRuleKey::Native(NativeFn::ResourceManager(
+                        ResourceManagerFn::UpdateNonFungibleData,
+                    ))),
+                ),
+                (
+                    ResourceMethodAuthKey::UpdateMetadata,
+                    access_rules.get(&AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Set))),
+                ),
+            ];
+            for (key, access_rule) in rules {
+                if !matches!(access_rule, DenyAll) {
+                    behavior.insert(BehaviorFlags::for_method_auth_key(key));
+                }
+            }
+        }
+        system_api.drop_lock(handle)?;
+
+        let offset =
+            SubstateOffset::VaultAccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+        let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        {
+            let substate_ref = system_api.get_ref(handle)?;
+            let access_rules = &substate_ref.access_rules_chain().access_rules_chain[0];
+            let put_key = AccessRuleKey::Native(NativeFn::Vault(VaultFn::Put));
+            if !matches!(access_rules.get_group("withdraw"), AllowAll) {
+                behavior.insert(BehaviorFlags::RESTRICTED_WITHDRAW);
+            }
+            if !matches!(access_rules.get(&put_key), AllowAll) {
+                behavior.insert(BehaviorFlags::RESTRICTED_DEPOSIT);
+            }
+            if !matches!(access_rules.get_group("recall"), DenyAll) {
+                behavior.insert(BehaviorFlags::RECALLABLE);
+            }
+        }
+        system_api.drop_lock(handle)?;
+
+        Ok((behavior, CallFrameUpdate::empty()))
+    }
+}
+
+impl ExecutableInvocation for ResourceManagerIsFrozenInvocation {
+    type Exec = ResourceManagerIsFrozenExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = ResolvedActor::method(
+            NativeFn::ResourceManager(ResourceManagerFn::IsFrozen),
+            resolved_receiver,
+        );
+        let executor = ResourceManagerIsFrozenExecutable(resolved_receiver.receiver);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerIsFrozenExecutable(RENodeId);
+
+impl Executor for ResourceManagerIsFrozenExecutable {
+    type Output = bool;
+
+    fn execute<'a, Y, W: WasmEngine>(
+        self,
+        system_api: &mut Y,
+    ) -> Result<(bool, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset =
+            SubstateOffset::VaultAccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+        let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let is_frozen = {
+            let substate_ref = system_api.get_ref(handle)?;
+            let access_rules = &substate_ref.access_rules_chain().access_rules_chain[0];
+            matches!(access_rules.get_group("withdraw"), DenyAll)
+                && !matches!(access_rules.get_group_mutability("withdraw"), DenyAll)
+        };
+        system_api.drop_lock(handle)?;
+
+        Ok((is_frozen, CallFrameUpdate::empty()))
+    }
+}
+
 impl ExecutableInvocation for ResourceManagerGetTotalSupplyInvocation {
     type Exec = ResourceManagerGetTotalSupplyExecutable;
 
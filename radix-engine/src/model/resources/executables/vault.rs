@@ -495,13 +495,17 @@ impl Executor for VaultCreateProofByAmountInvocation {
         let proof = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
             let vault = substate_mut.vault();
-            vault
-                .create_proof_by_amount(self.amount, ResourceContainerId::Vault(self.receiver))
-                .map_err(|e| {
-                    RuntimeError::ApplicationError(ApplicationError::VaultError(
-                        VaultError::ProofError(e),
-                    ))
-                })?
+            let container_id = ResourceContainerId::Vault(self.receiver);
+            let proof = if self.allow_zero_amount && self.amount.is_zero() {
+                vault.create_presence_proof(container_id)
+            } else {
+                vault.create_proof_by_amount(self.amount, container_id)
+            };
+            proof.map_err(|e| {
+                RuntimeError::ApplicationError(ApplicationError::VaultError(
+                    VaultError::ProofError(e),
+                ))
+            })?
         };
 
         let node_id = api.allocate_node_id(RENodeType::Proof)?;
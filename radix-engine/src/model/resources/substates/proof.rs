@@ -12,6 +12,12 @@ pub struct ProofSubstate {
     pub resource_type: ResourceType,
     /// Whether movement of this proof is restricted.
     pub restricted: bool,
+    /// Whether this proof may only be pushed onto an auth zone once, and never cloned.
+    pub one_shot: bool,
+    /// Whether this one-shot proof has already been pushed onto an auth zone.
+    pub one_shot_used: bool,
+    /// Whether this proof only proves its resource address and amount, hiding its non-fungible ids.
+    pub ids_hidden: bool,
     /// The total locked amount or non-fungible ids.
     pub total_locked: LockedAmountOrIds,
     /// The supporting containers.
@@ -33,6 +39,37 @@ impl ProofSubstate {
             resource_address,
             resource_type,
             restricted: false,
+            one_shot: false,
+            one_shot_used: false,
+            ids_hidden: false,
+            total_locked,
+            evidence,
+        })
+    }
+
+    /// Creates a zero-amount proof which only proves access to one of the given containers of the resource. It never
+    /// satisfies an access rule and is skipped when composing other proofs; see `ProofValidationMode::ValidatePresence`.
+    pub fn new_presence(
+        resource_address: ResourceAddress,
+        resource_type: ResourceType,
+        evidence: HashMap<ResourceContainerId, (Rc<RefCell<LockableResource>>, LockedAmountOrIds)>,
+    ) -> Result<ProofSubstate, ProofError> {
+        if evidence.is_empty() {
+            return Err(ProofError::InsufficientBaseProofs);
+        }
+
+        let total_locked = match resource_type {
+            ResourceType::Fungible { .. } => LockedAmountOrIds::Amount(Decimal::zero()),
+            ResourceType::NonFungible { .. } => LockedAmountOrIds::Ids(BTreeSet::new()),
+        };
+
+        Ok(Self {
+            resource_address,
+            resource_type,
+            restricted: false,
+            one_shot: false,
+            one_shot_used: false,
+            ids_hidden: false,
             total_locked,
             evidence,
         })
@@ -47,10 +84,17 @@ impl ProofSubstate {
         LockedAmountOrIds,
         HashMap<ResourceContainerId, LockedAmountOrIds>,
     ) {
-        // filter proofs by resource address and restricted flag
+        // filter proofs by resource address, restricted, one-shot and hidden ids flags, as composing
+        // from a proof with hidden ids would reveal them again
         let proofs: Vec<&ProofSubstate> = proofs
             .iter()
-            .filter(|p| p.resource_address() == resource_address && !p.is_restricted())
+            .filter(|p| {
+                p.resource_address() == resource_address
+                    && !p.is_restricted()
+                    && !p.is_one_shot()
+                    && !p.is_ids_hidden()
+                    && !p.is_presence()
+            })
             .collect();
 
         // calculate the max locked amount (or ids) of each container
@@ -185,6 +229,35 @@ impl ProofSubstate {
         }
     }
 
+    /// Creates a presence proof from proofs, backed by the first container of the resource that they reference.
+    pub fn compose_presence(
+        proofs: &[ProofSubstate],
+        resource_address: ResourceAddress,
+        resource_type: ResourceType,
+    ) -> Result<ProofSubstate, ProofError> {
+        let (_, per_container) =
+            Self::compute_total_locked(proofs, resource_address, resource_type);
+
+        let mut evidence = HashMap::new();
+        'outer: for proof in proofs {
+            for (container_id, (container, _)) in &proof.evidence {
+                if per_container.contains_key(container_id) {
+                    let locked_amount_or_ids = container
+                        .borrow_mut()
+                        .lock_by_amount(Decimal::zero())
+                        .map_err(ProofError::ResourceOperationError)?;
+                    evidence.insert(
+                        container_id.clone(),
+                        (container.clone(), locked_amount_or_ids),
+                    );
+                    break 'outer;
+                }
+            }
+        }
+
+        ProofSubstate::new_presence(resource_address, resource_type, evidence)
+    }
+
     pub fn compose_by_ids(
         proofs: &[ProofSubstate],
         ids: &BTreeSet<NonFungibleLocalId>,
@@ -268,6 +341,9 @@ impl ProofSubstate {
             resource_address: self.resource_address.clone(),
             resource_type: self.resource_type.clone(),
             restricted: self.restricted,
+            one_shot: self.one_shot,
+            one_shot_used: self.one_shot_used,
+            ids_hidden: self.ids_hidden,
             total_locked: self.total_locked.clone(),
             evidence: self.evidence.clone(),
         }
@@ -287,6 +363,19 @@ impl ProofSubstate {
         self.restricted = true;
     }
 
+    pub fn change_to_one_shot(&mut self) {
+        self.one_shot = true;
+    }
+
+    /// Records that a one-shot proof has been pushed onto an auth zone; no-op for other proofs.
+    pub fn mark_one_shot_used(&mut self) {
+        self.one_shot_used = self.one_shot;
+    }
+
+    pub fn change_to_ids_hidden(&mut self) {
+        self.ids_hidden = true;
+    }
+
     pub fn resource_address(&self) -> ResourceAddress {
         self.resource_address
     }
@@ -301,10 +390,48 @@ impl ProofSubstate {
             .map_err(|_| InvokeError::SelfError(ProofError::NonFungibleOperationNotAllowed))
     }
 
+    /// The non-fungible ids this proof reveals, which is none if its ids are hidden.
+    pub fn visible_ids(&self) -> Result<BTreeSet<NonFungibleLocalId>, InvokeError<ProofError>> {
+        let ids = self.total_ids()?;
+        if self.ids_hidden {
+            Ok(BTreeSet::new())
+        } else {
+            Ok(ids)
+        }
+    }
+
     pub fn is_restricted(&self) -> bool {
         self.restricted
     }
 
+    pub fn is_one_shot(&self) -> bool {
+        self.one_shot
+    }
+
+    pub fn is_ids_hidden(&self) -> bool {
+        self.ids_hidden
+    }
+
+    /// Whether this is a zero-amount proof created by [`ProofSubstate::new_presence`].
+    pub fn is_presence(&self) -> bool {
+        self.total_locked.is_empty()
+    }
+
+    pub fn is_one_shot_used(&self) -> bool {
+        self.one_shot && self.one_shot_used
+    }
+
+    /// The amount or non-fungible ids this proof locks in each of its supporting containers. A
+    /// proof and its clones hold the same locks.
+    pub fn locks(&self) -> HashMap<ResourceContainerId, LockedAmountOrIds> {
+        self.evidence
+            .iter()
+            .map(|(container_id, (_, locked_amount_or_ids))| {
+                (container_id.clone(), locked_amount_or_ids.clone())
+            })
+            .collect()
+    }
+
     pub fn snapshot(&self) -> ProofSnapshot {
         ProofSnapshot {
             resource_address: self.resource_address,
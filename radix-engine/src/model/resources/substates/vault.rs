@@ -134,6 +134,21 @@ impl VaultRuntimeSubstate {
         )
     }
 
+    pub fn create_presence_proof(
+        &mut self,
+        container_id: ResourceContainerId,
+    ) -> Result<ProofSubstate, ProofError> {
+        // lock nothing, but keep the vault as the evidence of the proof
+        let locked_amount_or_ids = self
+            .borrow_resource_mut()
+            .lock_by_amount(Decimal::zero())
+            .map_err(ProofError::ResourceOperationError)?;
+
+        let mut evidence = HashMap::new();
+        evidence.insert(container_id, (self.resource.clone(), locked_amount_or_ids));
+        ProofSubstate::new_presence(self.resource_address(), self.resource_type(), evidence)
+    }
+
     pub fn create_proof_by_ids(
         &mut self,
         ids: &BTreeSet<NonFungibleLocalId>,
@@ -857,6 +857,13 @@ impl<'a> SubstateRef<'a> {
         }
     }
 
+    pub fn kv_store_entry(&self) -> &KeyValueStoreEntrySubstate {
+        match self {
+            SubstateRef::KeyValueStoreEntry(kv_store_entry_substate) => *kv_store_entry_substate,
+            _ => panic!("Not a key value store entry"),
+        }
+    }
+
     pub fn epoch_manager(&self) -> &EpochManagerSubstate {
         match self {
             SubstateRef::EpochManager(epoch_manager_substate) => *epoch_manager_substate,
@@ -1090,8 +1097,11 @@ impl<'a> SubstateRef<'a> {
                 (references, owned_nodes)
             }
             SubstateRef::AccessController(substate) => {
-                let mut owned_nodes = HashSet::new();
-                owned_nodes.insert(RENodeId::Vault(substate.controlled_asset));
+                let owned_nodes = substate
+                    .controlled_assets
+                    .iter()
+                    .map(|vault_id| RENodeId::Vault(*vault_id))
+                    .collect();
                 (HashSet::new(), owned_nodes)
             }
             SubstateRef::PackageRoyaltyAccumulator(substate) => {
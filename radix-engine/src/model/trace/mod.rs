@@ -1,3 +1,5 @@
 mod module;
+mod substate_access;
 
 pub use module::*;
+pub use substate_access::*;
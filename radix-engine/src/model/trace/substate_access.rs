@@ -0,0 +1,47 @@
+use crate::engine::*;
+use crate::fee::FeeReserve;
+use crate::types::*;
+
+/// The substate accesses made by a single invocation, as counted by its call frame.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct SubstateAccessTrace {
+    pub actor: ResolvedActor,
+    pub call_frame_depth: usize,
+    pub counts: SubstateAccessCounts,
+}
+
+/// Records the substate read and write counts of every invocation once its call frame exits, so
+/// that blueprint authors can spot methods which access more substates than they need to.
+#[derive(Default)]
+pub struct SubstateAccessModule {
+    traces: Vec<SubstateAccessTrace>,
+}
+
+impl SubstateAccessModule {
+    /// Emits an event for each finished invocation, in the order the invocations finished.
+    pub fn collect_events(&mut self) -> Vec<TrackedEvent> {
+        self.traces
+            .drain(..)
+            .map(TrackedEvent::SubstateAccessTrace)
+            .collect()
+    }
+}
+
+impl<R: FeeReserve> BaseModule<R> for SubstateAccessModule {
+    fn post_execute_invocation(
+        &mut self,
+        _caller: &ResolvedActor,
+        _update: &CallFrameUpdate,
+        call_frame: &CallFrame,
+        _heap: &mut Heap,
+        _track: &mut Track<R>,
+    ) -> Result<(), ModuleError> {
+        self.traces.push(SubstateAccessTrace {
+            actor: call_frame.actor.clone(),
+            call_frame_depth: call_frame.depth,
+            counts: call_frame.substate_access_counts(),
+        });
+
+        Ok(())
+    }
+}
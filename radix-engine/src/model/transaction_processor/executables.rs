@@ -786,16 +786,19 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                     recovery_role,
                     confirmation_role,
                     timed_recovery_delay_in_minutes,
+                    settings,
                 }) => {
-                    let rtn = api.invoke(AccessControllerCreateGlobalInvocation {
-                        controlled_asset: processor.get_bucket(controlled_asset)?.0,
-                        rule_set: RuleSet {
-                            primary_role: primary_role.clone(),
-                            recovery_role: recovery_role.clone(),
-                            confirmation_role: confirmation_role.clone(),
-                        },
-                        timed_recovery_delay_in_minutes: *timed_recovery_delay_in_minutes,
-                    })?;
+                    let rtn =
+                        api.invoke(AccessControllerCreateGlobalInvocation::with_single_asset(
+                            processor.get_bucket(controlled_asset)?.0,
+                            RuleSet {
+                                primary_role: primary_role.clone(),
+                                recovery_role: recovery_role.clone(),
+                                confirmation_role: confirmation_role.clone(),
+                            },
+                            *timed_recovery_delay_in_minutes,
+                            settings.clone(),
+                        ))?;
 
                     InstructionOutput::Native(Box::new(rtn))
                 }
@@ -38,6 +38,8 @@ pub struct ExecutionConfig {
     pub trace: bool,
     pub max_sys_call_trace_depth: usize,
     pub abort_when_loan_repaid: bool,
+    /// Whether to report the number of substates each invocation read and wrote as receipt events.
+    pub count_substate_accesses: bool,
 }
 
 impl Default for ExecutionConfig {
@@ -53,12 +55,14 @@ impl ExecutionConfig {
             trace: false,
             max_sys_call_trace_depth: 1,
             abort_when_loan_repaid: false,
+            count_substate_accesses: false,
         }
     }
 
     pub fn debug() -> Self {
         Self {
             trace: true,
+            count_substate_accesses: true,
             ..Self::default()
         }
     }
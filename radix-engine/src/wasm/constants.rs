@@ -22,6 +22,12 @@ pub const GET_ACTOR_FUNCTION_ID: usize = 10;
 pub const GET_ACTOR_FUNCTION_NAME: &str = "get_actor";
 pub const CONSUME_COST_UNITS_FUNCTION_ID: usize = 11;
 pub const CONSUME_COST_UNITS_FUNCTION_NAME: &str = "gas";
+pub const WRITE_SUBSTATE_BATCH_FUNCTION_ID: usize = 12;
+pub const WRITE_SUBSTATE_BATCH_FUNCTION_NAME: &str = "write_substate_batch";
+pub const READ_SUBSTATE_FIELD_FUNCTION_ID: usize = 13;
+pub const READ_SUBSTATE_FIELD_FUNCTION_NAME: &str = "read_substate_field";
+pub const DROP_NODES_FUNCTION_ID: usize = 14;
+pub const DROP_NODES_FUNCTION_NAME: &str = "drop_nodes";
 
 pub const MODULE_ENV_NAME: &str = "env";
 pub const EXPORT_MEMORY: &str = "memory";
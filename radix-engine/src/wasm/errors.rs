@@ -92,7 +92,10 @@ pub enum WasmRuntimeError {
     /// Host attempted to call unknown WASM function, addressed by name.
     UnknownWasmFunction(String),
 
-    /// WASM interpreter error, such as traps.
+    /// WASM trap, such as an out of bounds memory access or reaching an `unreachable` instruction.
+    Trap(WasmTrapKind),
+
+    /// WASM interpreter error which isn't a trap.
     InterpreterError(String),
 
     /// WASM function return is not a `u64`, which points to a valid memory range.
@@ -117,10 +120,37 @@ pub enum WasmRuntimeError {
     InvalidNodeId(DecodeError),
     /// Invalid substate offset
     InvalidOffset(DecodeError),
+    /// Invalid batch of substate writes
+    InvalidSubstateWriteBatch(DecodeError),
+    /// Invalid substate field path
+    InvalidSubstateFieldPath(DecodeError),
     /// Costing error
     CostingError(FeeReserveError),
 }
 
+/// The kind of a trap raised by the WASM code itself, as opposed to an error raised by the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum WasmTrapKind {
+    /// An `unreachable` instruction was executed, which is how a Scrypto panic surfaces.
+    Unreachable,
+    MemoryAccessOutOfBounds,
+    TableAccessOutOfBounds,
+    ElemUninitialized,
+    DivisionByZero,
+    InvalidConversionToInt,
+    StackOverflow,
+    UnexpectedSignature,
+}
+
+/// Maps the error of a WASM export invocation into a [`RuntimeError`]. Unlike the blanket conversion, traps are
+/// surfaced as [`RuntimeError::WasmTrap`] so that receipts tell them apart from host errors.
+pub fn wasm_invoke_error_into_runtime_error(error: InvokeError<WasmRuntimeError>) -> RuntimeError {
+    match error {
+        InvokeError::SelfError(WasmRuntimeError::Trap(kind)) => RuntimeError::WasmTrap { kind },
+        error => error.into(),
+    }
+}
+
 impl SelfError for WasmRuntimeError {
     fn into_runtime_error(self) -> RuntimeError {
         RuntimeError::KernelError(KernelError::WasmRuntimeError(self))
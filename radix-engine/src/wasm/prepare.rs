@@ -254,6 +254,18 @@ impl WasmModule {
                                 }
                             }
                         }
+                        DROP_NODES_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![ValueType::I32, ValueType::I32],
+                                    vec![],
+                                ) {
+                                    continue;
+                                }
+                            }
+                        }
                         LOCK_SUBSTATE_FUNCTION_NAME => {
                             if let External::Function(type_index) = entry.external() {
                                 if Self::function_type_matches(
@@ -284,6 +296,18 @@ impl WasmModule {
                                 }
                             }
                         }
+                        READ_SUBSTATE_FIELD_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![ValueType::I32, ValueType::I32, ValueType::I32],
+                                    vec![ValueType::I64],
+                                ) {
+                                    continue;
+                                }
+                            }
+                        }
                         WRITE_SUBSTATE_FUNCTION_NAME => {
                             if let External::Function(type_index) = entry.external() {
                                 if Self::function_type_matches(
@@ -296,6 +320,18 @@ impl WasmModule {
                                 }
                             }
                         }
+                        WRITE_SUBSTATE_BATCH_FUNCTION_NAME => {
+                            if let External::Function(type_index) = entry.external() {
+                                if Self::function_type_matches(
+                                    &self.module,
+                                    *type_index as usize,
+                                    vec![ValueType::I32, ValueType::I32],
+                                    vec![],
+                                ) {
+                                    continue;
+                                }
+                            }
+                        }
                         UNLOCK_SUBSTATE_FUNCTION_NAME => {
                             if let External::Function(type_index) = entry.external() {
                                 if Self::function_type_matches(
@@ -478,6 +514,16 @@ impl WasmModule {
         Ok(self)
     }
 
+    /// The total number of instructions in the bodies of all functions defined by the module.
+    pub fn instruction_count(&self) -> usize {
+        self.module.code_section().map_or(0, |code| {
+            code.bodies()
+                .iter()
+                .map(|func_body| func_body.code().elements().len())
+                .sum()
+        })
+    }
+
     pub fn inject_instruction_metering<R: Rules>(
         mut self,
         rules: &R,
@@ -31,6 +31,8 @@ pub trait WasmRuntime {
 
     fn drop_node(&mut self, node_id: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn drop_nodes(&mut self, node_ids: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn lock_substate(
         &mut self,
         node_id: Vec<u8>,
@@ -43,12 +45,23 @@ pub trait WasmRuntime {
         handle: LockHandle,
     ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn read_substate_field(
+        &mut self,
+        handle: LockHandle,
+        path: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn write_substate(
         &mut self,
         handle: LockHandle,
         data: Vec<u8>,
     ) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn write_substate_batch(
+        &mut self,
+        writes: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn unlock_substate(&mut self, handle: LockHandle) -> Result<(), InvokeError<WasmRuntimeError>>;
 
     fn get_actor(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
@@ -1,6 +1,6 @@
 use super::{CodeKey, MeteredCodeKey, WasmMeteringParams};
 use crate::types::*;
-use crate::wasm::{WasmMeteringConfig, WasmModule};
+use crate::wasm::{PrepareError, WasmMeteringConfig, WasmModule};
 use sbor::rust::sync::Arc;
 
 pub struct WasmInstrumenter {
@@ -23,6 +23,29 @@ impl Default for WasmInstrumenter {
     }
 }
 
+/// Statistics on the metering overhead that instrumentation adds to a WASM module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstrumentationStats {
+    /// The size of the code before instrumentation, in bytes.
+    pub original_size: usize,
+    /// The size of the code after instrumentation, in bytes.
+    pub instrumented_size: usize,
+    /// The number of instructions injected into function bodies for instruction metering.
+    pub injected_instruction_metering_instructions: usize,
+    /// The number of instructions injected into function bodies for stack metering.
+    pub injected_stack_metering_instructions: usize,
+}
+
+impl InstrumentationStats {
+    pub fn injected_instructions(&self) -> usize {
+        self.injected_instruction_metering_instructions + self.injected_stack_metering_instructions
+    }
+
+    pub fn size_delta(&self) -> isize {
+        self.instrumented_size as isize - self.original_size as isize
+    }
+}
+
 pub struct InstrumentedCode {
     pub metered_code_key: MeteredCodeKey,
     pub code: Arc<Vec<u8>>,
@@ -88,11 +111,43 @@ impl WasmInstrumenter {
     }
 
     pub fn instrument_no_cache(&self, code: &[u8], metering_params: WasmMeteringParams) -> Vec<u8> {
-        WasmModule::init(code)
-            .and_then(|m| m.inject_instruction_metering(metering_params.instruction_cost_rules()))
-            .and_then(|m| m.inject_stack_metering(metering_params.max_stack_size()))
-            .and_then(|m| m.to_bytes())
+        self.instrument_with_stats(code, metering_params).0
+    }
+
+    /// Instruments the code in the same way as [`WasmInstrumenter::instrument`], bypassing the cache,
+    /// and reports the overhead added by the instrumentation.
+    pub fn instrument_with_stats(
+        &self,
+        code: &[u8],
+        metering_params: WasmMeteringParams,
+    ) -> (Vec<u8>, InstrumentationStats) {
+        Self::instrument_and_measure(code, metering_params)
             .expect("Failed to instrument WASM module")
-            .0
+    }
+
+    fn instrument_and_measure(
+        code: &[u8],
+        metering_params: WasmMeteringParams,
+    ) -> Result<(Vec<u8>, InstrumentationStats), PrepareError> {
+        let module = WasmModule::init(code)?;
+        let original_instruction_count = module.instruction_count();
+
+        let module =
+            module.inject_instruction_metering(metering_params.instruction_cost_rules())?;
+        let instruction_metered_count = module.instruction_count();
+
+        let module = module.inject_stack_metering(metering_params.max_stack_size())?;
+        let stack_metered_count = module.instruction_count();
+
+        let (instrumented_code, _) = module.to_bytes()?;
+        let stats = InstrumentationStats {
+            original_size: code.len(),
+            instrumented_size: instrumented_code.len(),
+            injected_instruction_metering_instructions: instruction_metered_count
+                - original_instruction_count,
+            injected_stack_metering_instructions: stack_metered_count - instruction_metered_count,
+        };
+
+        Ok((instrumented_code, stats))
     }
 }
@@ -8,7 +8,7 @@ use crate::wasm::traits::*;
 use radix_engine_interface::api::wasm::*;
 use sbor::rust::sync::{Arc, Mutex};
 use wasmer::{
-    imports, Function, HostEnvInitError, Instance, LazyInit, Module, RuntimeError, Store,
+    imports, Function, HostEnvInitError, Instance, LazyInit, Module, RuntimeError, Store, TrapCode,
     Universal, Val, WasmerEnv,
 };
 use wasmer_compiler_singlepass::Singlepass;
@@ -246,6 +246,22 @@ impl WasmerModule {
             Ok(())
         }
 
+        pub fn drop_nodes(
+            env: &WasmerInstanceEnv,
+            node_ids_ptr: u32,
+            node_ids_len: u32,
+        ) -> Result<(), RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let node_ids = read_memory(&instance, node_ids_ptr, node_ids_len)?;
+
+            runtime
+                .drop_nodes(node_ids)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(())
+        }
+
         pub fn lock_substate(
             env: &WasmerInstanceEnv,
             node_id_ptr: u32,
@@ -276,6 +292,23 @@ impl WasmerModule {
             Ok(buffer.0)
         }
 
+        pub fn read_substate_field(
+            env: &WasmerInstanceEnv,
+            handle: u32,
+            path_ptr: u32,
+            path_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let path = read_memory(&instance, path_ptr, path_len)?;
+
+            let buffer = runtime
+                .read_substate_field(handle, path)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
         pub fn write_substate(
             env: &WasmerInstanceEnv,
             handle: u32,
@@ -293,6 +326,22 @@ impl WasmerModule {
             Ok(())
         }
 
+        pub fn write_substate_batch(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<(), RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            runtime
+                .write_substate_batch(data)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(())
+        }
+
         pub fn unlock_substate(env: &WasmerInstanceEnv, handle: u32) -> Result<(), RuntimeError> {
             let (_instance, runtime) = grab_runtime!(env);
 
@@ -336,9 +385,12 @@ impl WasmerModule {
                 CREATE_NODE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), create_node),
                 GET_VISIBLE_NODES_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_visible_nodes),
                 DROP_NODE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), drop_node),
+                DROP_NODES_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), drop_nodes),
                 LOCK_SUBSTATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), lock_substate),
                 READ_SUBSTATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), read_substate),
+                READ_SUBSTATE_FIELD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), read_substate_field),
                 WRITE_SUBSTATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), write_substate),
+                WRITE_SUBSTATE_BATCH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), write_substate_batch),
                 UNLOCK_SUBSTATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), unlock_substate),
                 GET_ACTOR_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_actor),
                 CONSUME_COST_UNITS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), consume_cost_units),
@@ -356,8 +408,26 @@ impl WasmerModule {
     }
 }
 
+fn trap_kind(code: TrapCode) -> Option<WasmTrapKind> {
+    match code {
+        TrapCode::UnreachableCodeReached => Some(WasmTrapKind::Unreachable),
+        TrapCode::HeapAccessOutOfBounds => Some(WasmTrapKind::MemoryAccessOutOfBounds),
+        TrapCode::TableAccessOutOfBounds => Some(WasmTrapKind::TableAccessOutOfBounds),
+        TrapCode::IndirectCallToNull => Some(WasmTrapKind::ElemUninitialized),
+        TrapCode::IntegerDivisionByZero => Some(WasmTrapKind::DivisionByZero),
+        TrapCode::BadConversionToInteger => Some(WasmTrapKind::InvalidConversionToInt),
+        TrapCode::StackOverflow => Some(WasmTrapKind::StackOverflow),
+        TrapCode::BadSignature => Some(WasmTrapKind::UnexpectedSignature),
+        _ => None,
+    }
+}
+
 impl From<RuntimeError> for InvokeError<WasmRuntimeError> {
     fn from(error: RuntimeError) -> Self {
+        if let Some(kind) = error.clone().to_trap().and_then(trap_kind) {
+            return InvokeError::SelfError(WasmRuntimeError::Trap(kind));
+        }
+
         let e_str = format!("{:?}", error);
         match error.downcast::<InvokeError<WasmRuntimeError>>() {
             Ok(e) => e,
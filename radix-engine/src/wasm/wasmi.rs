@@ -84,6 +84,18 @@ impl ModuleImportResolver for WasmiEnvModule {
                 signature.clone(),
                 CONSUME_COST_UNITS_FUNCTION_ID,
             )),
+            WRITE_SUBSTATE_BATCH_FUNCTION_NAME => Ok(FuncInstance::alloc_host(
+                signature.clone(),
+                WRITE_SUBSTATE_BATCH_FUNCTION_ID,
+            )),
+            READ_SUBSTATE_FIELD_FUNCTION_NAME => Ok(FuncInstance::alloc_host(
+                signature.clone(),
+                READ_SUBSTATE_FIELD_FUNCTION_ID,
+            )),
+            DROP_NODES_FUNCTION_NAME => Ok(FuncInstance::alloc_host(
+                signature.clone(),
+                DROP_NODES_FUNCTION_ID,
+            )),
             _ => Err(Error::Instantiation(format!(
                 "Function {} not found",
                 field_name
@@ -92,8 +104,25 @@ impl ModuleImportResolver for WasmiEnvModule {
     }
 }
 
+fn trap_kind(code: &TrapCode) -> WasmTrapKind {
+    match code {
+        TrapCode::Unreachable => WasmTrapKind::Unreachable,
+        TrapCode::MemoryAccessOutOfBounds => WasmTrapKind::MemoryAccessOutOfBounds,
+        TrapCode::TableAccessOutOfBounds => WasmTrapKind::TableAccessOutOfBounds,
+        TrapCode::ElemUninitialized => WasmTrapKind::ElemUninitialized,
+        TrapCode::DivisionByZero => WasmTrapKind::DivisionByZero,
+        TrapCode::InvalidConversionToInt => WasmTrapKind::InvalidConversionToInt,
+        TrapCode::StackOverflow => WasmTrapKind::StackOverflow,
+        TrapCode::UnexpectedSignature => WasmTrapKind::UnexpectedSignature,
+    }
+}
+
 impl From<Error> for InvokeError<WasmRuntimeError> {
     fn from(error: Error) -> Self {
+        if let Error::Trap(Trap::Code(code)) = &error {
+            return InvokeError::SelfError(WasmRuntimeError::Trap(trap_kind(code)));
+        }
+
         let e_str = format!("{:?}", error);
         match error.into_host_error() {
             // Pass-through invoke errors
@@ -232,6 +261,15 @@ impl<'a, 'b, 'r> Externals for WasmiExternals<'a, 'b, 'r> {
 
                 Ok(None)
             }
+            DROP_NODES_FUNCTION_ID => {
+                let node_ids_ptr = args.nth_checked::<u32>(0)?;
+                let node_ids_len = args.nth_checked::<u32>(1)?;
+
+                self.runtime
+                    .drop_nodes(self.read_memory(node_ids_ptr, node_ids_len)?)?;
+
+                Ok(None)
+            }
             LOCK_SUBSTATE_FUNCTION_ID => {
                 let node_id_ptr = args.nth_checked::<u32>(0)?;
                 let node_id_len = args.nth_checked::<u32>(1)?;
@@ -254,6 +292,17 @@ impl<'a, 'b, 'r> Externals for WasmiExternals<'a, 'b, 'r> {
 
                 Ok(Some(RuntimeValue::I64(buffer.as_i64())))
             }
+            READ_SUBSTATE_FIELD_FUNCTION_ID => {
+                let handle = args.nth_checked::<u32>(0)?;
+                let path_ptr = args.nth_checked::<u32>(1)?;
+                let path_len = args.nth_checked::<u32>(2)?;
+
+                let buffer = self
+                    .runtime
+                    .read_substate_field(handle, self.read_memory(path_ptr, path_len)?)?;
+
+                Ok(Some(RuntimeValue::I64(buffer.as_i64())))
+            }
             WRITE_SUBSTATE_FUNCTION_ID => {
                 let handle = args.nth_checked::<u32>(0)?;
                 let data_ptr = args.nth_checked::<u32>(1)?;
@@ -264,6 +313,15 @@ impl<'a, 'b, 'r> Externals for WasmiExternals<'a, 'b, 'r> {
 
                 Ok(None)
             }
+            WRITE_SUBSTATE_BATCH_FUNCTION_ID => {
+                let data_ptr = args.nth_checked::<u32>(0)?;
+                let data_len = args.nth_checked::<u32>(1)?;
+
+                self.runtime
+                    .write_substate_batch(self.read_memory(data_ptr, data_len)?)?;
+
+                Ok(None)
+            }
             UNLOCK_SUBSTATE_FUNCTION_ID => {
                 let handle = args.nth_checked::<u32>(0)?;
 
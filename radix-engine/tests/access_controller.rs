@@ -1,19 +1,26 @@
-use radix_engine::engine::{ApplicationError, AuthError, ModuleError, RuntimeError};
-use radix_engine::model::AccessControllerError;
+use radix_engine::engine::{
+    predict_access_controller_address, ApplicationError, AuthError, ModuleError, RuntimeError,
+};
+use radix_engine::model::{
+    AccessControllerError, AuthZoneError, ConfirmationKind, ProofError, RuleSetChanged,
+    MAX_OUTSTANDING_RECOVERY_PROPOSALS,
+};
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
-use scrypto_unit::TestRunner;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
+use scrypto_unit::{AccessControllerTestFixture, TestRunner};
+use transaction::model::{Instruction, SystemTransaction, TestTransaction};
 use transaction::{builder::ManifestBuilder, model::TransactionManifest};
 
 #[test]
 pub fn creating_an_access_controller_succeeds() {
-    AccessControllerTestRunner::new(Some(10));
+    AccessControllerTestFixture::new(Some(10));
 }
 
 #[test]
 pub fn role_cant_quick_confirm_a_ruleset_it_proposed() {
     // Arrange
-    let mut test_runner = AccessControllerTestRunner::new(Some(10));
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
     test_runner.initiate_recovery(
         Role::Recovery,
         rule!(require(RADIX_TOKEN)),
@@ -39,7 +46,7 @@ pub fn role_cant_quick_confirm_a_ruleset_it_proposed() {
 #[test]
 pub fn quick_confirm_non_existent_recovery_fails() {
     // Arrange
-    let mut test_runner = AccessControllerTestRunner::new(Some(10));
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
     test_runner.initiate_recovery(
         Role::Recovery,
         rule!(require(RADIX_TOKEN)),
@@ -65,7 +72,7 @@ pub fn quick_confirm_non_existent_recovery_fails() {
 #[test]
 pub fn initiating_recovery_multiple_times_as_the_same_role_fails() {
     // Arrange
-    let mut test_runner = AccessControllerTestRunner::new(Some(10));
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
     test_runner.initiate_recovery(
         Role::Recovery,
         rule!(require(RADIX_TOKEN)),
@@ -90,7 +97,7 @@ pub fn initiating_recovery_multiple_times_as_the_same_role_fails() {
 #[test]
 pub fn timed_confirm_recovery_before_delay_passes_fails() {
     // Arrange
-    let mut test_runner = AccessControllerTestRunner::new(Some(10));
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
     test_runner.initiate_recovery(
         Role::Recovery,
         rule!(require(RADIX_TOKEN)),
@@ -98,7 +105,7 @@ pub fn timed_confirm_recovery_before_delay_passes_fails() {
         rule!(require(RADIX_TOKEN)),
         Some(10),
     );
-    test_runner.push_time_forward(9);
+    test_runner.advance_clock(9);
 
     // Act
     let receipt = test_runner.timed_confirm_recovery(
@@ -116,7 +123,7 @@ pub fn timed_confirm_recovery_before_delay_passes_fails() {
 #[test]
 pub fn timed_confirm_recovery_after_delay_passes_succeeds() {
     // Arrange
-    let mut test_runner = AccessControllerTestRunner::new(Some(10));
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
     test_runner.initiate_recovery(
         Role::Recovery,
         rule!(require(RADIX_TOKEN)),
@@ -124,7 +131,7 @@ pub fn timed_confirm_recovery_after_delay_passes_succeeds() {
         rule!(require(RADIX_TOKEN)),
         Some(10),
     );
-    test_runner.push_time_forward(10);
+    test_runner.advance_clock(10);
 
     // Act
     let receipt = test_runner.timed_confirm_recovery(
@@ -142,7 +149,7 @@ pub fn timed_confirm_recovery_after_delay_passes_succeeds() {
 #[test]
 pub fn timed_confirm_recovery_with_disabled_timed_recovery_fails() {
     // Arrange
-    let mut test_runner = AccessControllerTestRunner::new(None);
+    let mut test_runner = AccessControllerTestFixture::new(None);
     test_runner.initiate_recovery(
         Role::Recovery,
         rule!(require(RADIX_TOKEN)),
@@ -150,7 +157,7 @@ pub fn timed_confirm_recovery_with_disabled_timed_recovery_fails() {
         rule!(require(RADIX_TOKEN)),
         Some(10),
     );
-    test_runner.push_time_forward(10);
+    test_runner.advance_clock(10);
 
     // Act
     let receipt = test_runner.timed_confirm_recovery(
@@ -168,7 +175,7 @@ pub fn timed_confirm_recovery_with_disabled_timed_recovery_fails() {
 #[test]
 pub fn timed_confirm_recovery_with_non_recovery_role_fails() {
     // Arrange
-    let mut test_runner = AccessControllerTestRunner::new(None);
+    let mut test_runner = AccessControllerTestFixture::new(None);
     test_runner.initiate_recovery(
         Role::Primary,
         rule!(require(RADIX_TOKEN)),
@@ -176,7 +183,7 @@ pub fn timed_confirm_recovery_with_non_recovery_role_fails() {
         rule!(require(RADIX_TOKEN)),
         Some(10),
     );
-    test_runner.push_time_forward(10);
+    test_runner.advance_clock(10);
 
     // Act
     let receipt = test_runner.timed_confirm_recovery(
@@ -191,10 +198,183 @@ pub fn timed_confirm_recovery_with_non_recovery_role_fails() {
     receipt.expect_specific_failure(is_auth_unauthorized_error);
 }
 
+#[test]
+pub fn extending_a_timed_recovery_delays_its_confirmation() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner.initiate_recovery(
+        Role::Recovery,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Act
+    let receipt = test_runner.extend_timed_recovery(Role::Recovery, 5);
+
+    // Assert
+    receipt.expect_commit_success();
+
+    test_runner.advance_clock(10);
+    test_runner
+        .timed_confirm_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_specific_failure(is_timed_recovery_delay_has_not_elapsed_error);
+
+    test_runner.advance_clock(5);
+    test_runner
+        .timed_confirm_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+}
+
+#[test]
+pub fn shortening_a_timed_recovery_fails() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner.initiate_recovery(
+        Role::Recovery,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Act
+    let receipt = test_runner.extend_timed_recovery(Role::Recovery, -5);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+                AccessControllerError::TimedRecoveryCanOnlyBeExtended {
+                    additional_minutes: -5
+                }
+            ))
+        )
+    });
+}
+
+#[test]
+pub fn extending_a_timed_recovery_when_none_is_underway_fails() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    // Act
+    let receipt = test_runner.extend_timed_recovery(Role::Recovery, 5);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+                AccessControllerError::InvalidStateTransition
+            ))
+        )
+    });
+}
+
+#[test]
+pub fn batch_confirmation_by_all_confirmors_applies_the_proposed_rule_set() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.batch_confirm_recovery(
+        &[Role::Recovery, Role::Confirmation],
+        Role::Primary,
+        vec![Role::Recovery, Role::Confirmation],
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let snapshot = test_runner.export_state();
+    assert_eq!(
+        snapshot.rule_set,
+        RuleSet {
+            primary_role: rule!(require(RADIX_TOKEN)),
+            recovery_role: rule!(require(RADIX_TOKEN)),
+            confirmation_role: rule!(require(RADIX_TOKEN)),
+        }
+    );
+    assert_eq!(snapshot.primary_role_recovery_proposal, None);
+}
+
+#[test]
+pub fn batch_confirmation_with_an_unauthorized_confirmor_changes_nothing() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.batch_confirm_recovery(
+        &[Role::Recovery],
+        Role::Primary,
+        vec![Role::Recovery, Role::Confirmation],
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+                AccessControllerError::InvalidStateTransition
+            ))
+        )
+    });
+    let snapshot = test_runner.export_state();
+    assert_eq!(
+        snapshot.rule_set,
+        RuleSet {
+            primary_role: rule!(require(test_runner.primary_role_badge)),
+            recovery_role: rule!(require(test_runner.recovery_role_badge)),
+            confirmation_role: rule!(require(test_runner.confirmation_role_badge)),
+        }
+    );
+    assert!(snapshot.primary_role_recovery_proposal.is_some());
+}
+
 #[test]
 pub fn primary_is_unlocked_after_a_successful_recovery() {
     // Arrange
-    let mut test_runner = AccessControllerTestRunner::new(Some(10));
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
     test_runner.initiate_recovery(
         Role::Recovery,
         rule!(require(test_runner.primary_role_badge)),
@@ -206,7 +386,7 @@ pub fn primary_is_unlocked_after_a_successful_recovery() {
         .lock_primary_role(Role::Recovery)
         .expect_commit_success();
 
-    test_runner.push_time_forward(10);
+    test_runner.advance_clock(10);
 
     test_runner
         .timed_confirm_recovery(
@@ -228,7 +408,7 @@ pub fn primary_is_unlocked_after_a_successful_recovery() {
 #[test]
 pub fn stop_timed_recovery_with_no_access_fails() {
     // Arrange
-    let mut test_runner = AccessControllerTestRunner::new(Some(10));
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
 
     let manifest = ManifestBuilder::new()
         .call_method(
@@ -247,62 +427,2089 @@ pub fn stop_timed_recovery_with_no_access_fails() {
         .build();
 
     // Act
-    let receipt = test_runner.execute_manifest(manifest);
+    let receipt = test_runner.execute_manifest(manifest);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_unauthorized_error)
+}
+
+#[test]
+pub fn quick_confirm_semantics_are_correct() {
+    // Arrange
+    let test_vectors = [
+        (
+            Proposer::Primary,
+            Role::Primary,
+            Some(is_auth_unauthorized_error),
+        ),
+        (Proposer::Primary, Role::Recovery, None),
+        (Proposer::Primary, Role::Confirmation, None),
+        (Proposer::Recovery, Role::Primary, None),
+        (
+            Proposer::Recovery,
+            Role::Recovery,
+            Some(is_auth_unauthorized_error),
+        ),
+        (Proposer::Recovery, Role::Confirmation, None),
+    ];
+
+    for (proposer, role, error_assertion_function) in test_vectors {
+        let mut test_runner = AccessControllerTestFixture::new(Some(10));
+        test_runner
+            .initiate_recovery(
+                proposer.into(),
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                Some(10),
+            )
+            .expect_commit_success();
+
+        // Act
+        let receipt = test_runner.quick_confirm_recovery(
+            role,
+            proposer.into(),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        );
+
+        // Assert
+        match error_assertion_function {
+            None => {
+                receipt.expect_commit_success();
+            }
+            Some(function) => receipt.expect_specific_failure(function),
+        };
+    }
+}
+
+#[test]
+pub fn quick_confirm_with_proposer_badge_fails_when_self_confirmation_is_prevented() {
+    // Arrange
+    let mut test_runner = SharedBadgeAccessControllerTestRunner::new(true);
+    test_runner
+        .initiate_recovery_as_primary(1)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.quick_confirm_primary_role_recovery_proposal(1);
+
+    // Assert
+    receipt.expect_specific_failure(is_self_confirmation_not_allowed_error);
+}
+
+#[test]
+pub fn quick_confirm_with_different_badge_succeeds_when_self_confirmation_is_prevented() {
+    // Arrange
+    let mut test_runner = SharedBadgeAccessControllerTestRunner::new(true);
+    test_runner
+        .initiate_recovery_as_primary(1)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.quick_confirm_primary_role_recovery_proposal(2);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn quick_confirm_with_proposer_badge_succeeds_when_self_confirmation_is_allowed() {
+    // Arrange
+    let mut test_runner = SharedBadgeAccessControllerTestRunner::new(false);
+    test_runner
+        .initiate_recovery_as_primary(1)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.quick_confirm_primary_role_recovery_proposal(1);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn component_can_prove_recovery_role_and_quick_confirm_recovery() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+    let role_prover = instantiate_role_prover(&mut test_runner, Role::Recovery);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            role_prover,
+            "prove_recovery_role_and_quick_confirm_primary_role_recovery_proposal",
+            args!(
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                Some(10u32),
+                0u64
+            ),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn component_cant_prove_role_without_holding_its_badge() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let role_prover = instantiate_role_prover(&mut test_runner, Role::Recovery);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_method(role_prover, "can_prove_confirmation_role", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest);
+
+    // Assert
+    let can_prove_confirmation_role: bool = receipt.output(0);
+    assert!(!can_prove_confirmation_role);
+}
+
+#[test]
+pub fn primary_can_create_proof_during_lock_grace_period() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_primary_role_lock_grace_period(Some(10), Some(10));
+    test_runner
+        .lock_primary_role(Role::Recovery)
+        .expect_commit_success();
+    test_runner.advance_clock(9);
+
+    // Act
+    let receipt = test_runner.create_proof(Role::Primary);
+
+    // Assert
+    receipt.expect_commit_success();
+    let logs = &receipt.expect_commit().application_logs;
+    assert!(logs.iter().any(|(level, _)| *level == Level::Warn));
+}
+
+#[test]
+pub fn primary_cant_create_proof_after_lock_grace_period_elapses() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_primary_role_lock_grace_period(Some(10), Some(10));
+    test_runner
+        .lock_primary_role(Role::Recovery)
+        .expect_commit_success();
+    test_runner.advance_clock(10);
+
+    // Act
+    let receipt = test_runner.create_proof(Role::Primary);
+
+    // Assert
+    receipt.expect_specific_failure(is_operation_requires_unlocked_primary_role_error);
+}
+
+#[test]
+pub fn config_reports_primary_role_locked_once_lock_grace_period_elapses() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_primary_role_lock_grace_period(Some(10), Some(10));
+    test_runner
+        .lock_primary_role(Role::Recovery)
+        .expect_commit_success();
+    test_runner.advance_clock(9);
+    assert!(!test_runner.get_config().primary_role_locked);
+    test_runner.advance_clock(1);
+
+    // Act
+    let config = test_runner.get_config();
+
+    // Assert
+    assert!(config.primary_role_locked);
+}
+
+#[test]
+pub fn snapshot_reports_primary_role_locked_once_lock_grace_period_elapses() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_primary_role_lock_grace_period(Some(10), Some(10));
+    test_runner
+        .lock_primary_role(Role::Recovery)
+        .expect_commit_success();
+    test_runner.advance_clock(9);
+    assert!(!test_runner.export_state().primary_role_locked);
+    test_runner.advance_clock(1);
+
+    // Act
+    let snapshot = test_runner.export_state();
+
+    // Assert
+    assert!(snapshot.primary_role_locked);
+    assert!(snapshot.primary_role_locks_at.is_some());
+    assert_eq!(
+        snapshot.primary_role_locked,
+        test_runner.get_config().primary_role_locked
+    );
+}
+
+#[test]
+pub fn unlocking_primary_cancels_a_pending_lock() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_primary_role_lock_grace_period(Some(10), Some(10));
+    test_runner
+        .lock_primary_role(Role::Recovery)
+        .expect_commit_success();
+    test_runner
+        .unlock_primary_role(Role::Recovery)
+        .expect_commit_success();
+    test_runner.advance_clock(10);
+
+    // Act
+    let receipt = test_runner.create_proof(Role::Primary);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn unauthorized_caller_fails_with_auth_error() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    // Act
+    let receipt = test_runner.timed_confirm_recovery(
+        Role::Confirmation,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_unauthorized_error);
+}
+
+#[test]
+pub fn authorized_caller_in_invalid_state_fails_with_access_controller_error() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    // Act
+    let receipt = test_runner.timed_confirm_recovery(
+        Role::Recovery,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_no_timed_recoveries_found_error);
+}
+
+#[test]
+pub fn recovery_proposal_can_be_confirmed_once_endorsement_threshold_is_met() {
+    // Arrange
+    let mut test_runner =
+        SharedBadgeAccessControllerTestRunner::new_with_recovery_endorsement_threshold(2);
+    test_runner
+        .initiate_recovery_as_recovery(1)
+        .expect_commit_success();
+    test_runner
+        .endorse_recovery_role_recovery_proposal(1)
+        .expect_commit_success();
+    test_runner
+        .endorse_recovery_role_recovery_proposal(2)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.quick_confirm_recovery_role_recovery_proposal(3);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn recovery_proposal_cant_be_confirmed_before_endorsement_threshold_is_met() {
+    // Arrange
+    let mut test_runner =
+        SharedBadgeAccessControllerTestRunner::new_with_recovery_endorsement_threshold(2);
+    test_runner
+        .initiate_recovery_as_recovery(1)
+        .expect_commit_success();
+    test_runner
+        .endorse_recovery_role_recovery_proposal(1)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.quick_confirm_recovery_role_recovery_proposal(3);
+
+    // Assert
+    receipt.expect_specific_failure(is_recovery_endorsement_threshold_not_met_error);
+}
+
+#[test]
+pub fn endorsing_recovery_proposal_twice_with_same_badge_fails() {
+    // Arrange
+    let mut test_runner =
+        SharedBadgeAccessControllerTestRunner::new_with_recovery_endorsement_threshold(2);
+    test_runner
+        .initiate_recovery_as_recovery(1)
+        .expect_commit_success();
+    test_runner
+        .endorse_recovery_role_recovery_proposal(1)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.endorse_recovery_role_recovery_proposal(1);
+
+    // Assert
+    receipt.expect_specific_failure(is_recovery_proposal_already_endorsed_error);
+}
+
+#[test]
+pub fn endorsing_recovery_proposal_with_a_foreign_non_fungible_fails() {
+    // Arrange
+    let mut test_runner =
+        SharedBadgeAccessControllerTestRunner::new_with_recovery_endorsement_threshold(2);
+    test_runner
+        .initiate_recovery_as_recovery(1)
+        .expect_commit_success();
+    let account = test_runner.account.0;
+    let foreign_badge = test_runner
+        .test_runner
+        .create_non_fungible_resource(account);
+    let foreign_endorser = NonFungibleGlobalId::new(foreign_badge, NonFungibleLocalId::integer(1));
+
+    // Act
+    let mut manifest_builder = test_runner.manifest_builder(1);
+    manifest_builder.create_proof_from_account_by_ids(
+        account,
+        &[NonFungibleLocalId::integer(1)].into(),
+        foreign_badge,
+    );
+    let manifest = manifest_builder
+        .call_method(
+            test_runner.access_controller_component_address,
+            &AccessControllerFn::EndorseRecoveryRoleRecoveryProposal.to_string(),
+            scrypto_encode(
+                &AccessControllerEndorseRecoveryRoleRecoveryProposalMethodArgs {
+                    rule_set: SharedBadgeAccessControllerTestRunner::proposed_rule_set(),
+                    timed_recovery_delay_in_minutes: None,
+                    endorser: foreign_endorser.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+                AccessControllerError::EndorserDoesNotSatisfyRecoveryRole { endorser }
+            )) if *endorser == foreign_endorser
+        )
+    });
+}
+
+#[test]
+pub fn quick_confirming_recovery_logs_old_and_new_rule_sets() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.quick_confirm_recovery(
+        Role::Recovery,
+        Role::Primary,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    let expected_rule_set_changed = RuleSetChanged {
+        old: RuleSet {
+            primary_role: rule!(require(test_runner.primary_role_badge)),
+            recovery_role: rule!(require(test_runner.recovery_role_badge)),
+            confirmation_role: rule!(require(test_runner.confirmation_role_badge)),
+        },
+        new: RuleSet {
+            primary_role: rule!(require(RADIX_TOKEN)),
+            recovery_role: rule!(require(RADIX_TOKEN)),
+            confirmation_role: rule!(require(RADIX_TOKEN)),
+        },
+        via: ConfirmationKind::Quick,
+    };
+    let logs = &receipt.expect_commit().application_logs;
+    assert!(logs.contains(&(Level::Info, format!("{:?}", expected_rule_set_changed))));
+}
+
+#[test]
+pub fn exported_state_reflects_state_after_transitions() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+    test_runner
+        .lock_primary_role(Role::Recovery)
+        .expect_commit_success();
+
+    // Act
+    let snapshot = test_runner.export_state();
+
+    // Assert
+    assert_eq!(
+        snapshot.rule_set,
+        RuleSet {
+            primary_role: rule!(require(test_runner.primary_role_badge)),
+            recovery_role: rule!(require(test_runner.recovery_role_badge)),
+            confirmation_role: rule!(require(test_runner.confirmation_role_badge)),
+        }
+    );
+    assert_eq!(snapshot.controlled_asset_amount, Decimal::one());
+    assert_eq!(snapshot.timed_recovery_delay_in_minutes, Some(10));
+    assert!(snapshot.primary_role_locked);
+    assert_eq!(snapshot.primary_role_locks_at, None);
+    assert_eq!(snapshot.primary_role_recovery_proposal, None);
+    assert_eq!(
+        snapshot.recovery_role_recovery_proposal,
+        Some(RecoveryProposal {
+            rule_set: RuleSet {
+                primary_role: rule!(require(RADIX_TOKEN)),
+                recovery_role: rule!(require(RADIX_TOKEN)),
+                confirmation_role: rule!(require(RADIX_TOKEN)),
+            },
+            timed_recovery_delay_in_minutes: Some(10),
+        })
+    );
+    assert!(snapshot.timed_recovery_allowed_after.is_some());
+}
+
+#[test]
+pub fn proposal_validation_accepts_proposers_without_recoveries_underway() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    // Act
+    let primary_outcome = test_runner.validate_proposal(
+        Proposer::Primary,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+    let recovery_outcome = test_runner.validate_proposal(
+        Proposer::Recovery,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    assert_eq!(primary_outcome, ProposalValidationOutcome::Accepted);
+    assert_eq!(recovery_outcome, ProposalValidationOutcome::Accepted);
+}
+
+#[test]
+pub fn proposal_validation_rejects_proposers_with_recoveries_underway() {
+    for (role, proposer) in [
+        (Role::Primary, Proposer::Primary),
+        (Role::Recovery, Proposer::Recovery),
+    ] {
+        // Arrange
+        let mut test_runner = AccessControllerTestFixture::new(Some(10));
+        test_runner
+            .initiate_recovery(
+                role,
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                Some(10),
+            )
+            .expect_commit_success();
+
+        // Act
+        let outcome = test_runner.validate_proposal(
+            proposer,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        );
+
+        // Assert
+        assert_eq!(
+            outcome,
+            ProposalValidationOutcome::Rejected(
+                ProposalRejectionReason::RecoveryAlreadyExistsForProposer
+            )
+        );
+    }
+}
+
+#[test]
+pub fn proposal_validation_does_not_change_state() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    // Act
+    test_runner.validate_proposal(
+        Proposer::Recovery,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    let snapshot = test_runner.export_state();
+    assert_eq!(snapshot.recovery_role_recovery_proposal, None);
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+}
+
+#[test]
+pub fn controlled_resource_manager_info_matches_controlled_asset() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let controlled_asset_resource_address =
+        test_runner.export_state().controlled_asset_resource_address;
+
+    // Act
+    let info = test_runner.get_controlled_resource_manager();
+
+    // Assert
+    assert_eq!(
+        info,
+        ControlledResourceManagerInfo {
+            resource_address: controlled_asset_resource_address,
+            resource_type: ResourceType::Fungible { divisibility: 0 },
+            mintable: false,
+            burnable: false,
+        }
+    );
+}
+
+#[test]
+pub fn verifying_the_controlled_asset_against_its_actual_contents_succeeds() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let controlled_asset_resource_address =
+        test_runner.export_state().controlled_asset_resource_address;
+
+    // Act
+    let verified =
+        test_runner.verify_controlled_asset(controlled_asset_resource_address, Decimal::one());
+
+    // Assert
+    assert!(verified);
+}
+
+#[test]
+pub fn verifying_the_controlled_asset_against_other_contents_fails() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let controlled_asset_resource_address =
+        test_runner.export_state().controlled_asset_resource_address;
+
+    // Act
+    let wrong_amount =
+        test_runner.verify_controlled_asset(controlled_asset_resource_address, 2.into());
+    let wrong_resource = test_runner.verify_controlled_asset(RADIX_TOKEN, Decimal::one());
+
+    // Assert
+    assert!(!wrong_amount);
+    assert!(!wrong_resource);
+}
+
+#[test]
+pub fn transfer_ownership_with_all_three_roles_applies_new_rule_set() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let new_rule_set = RuleSet {
+        primary_role: rule!(require(RADIX_TOKEN)),
+        recovery_role: rule!(require(RADIX_TOKEN)),
+        confirmation_role: rule!(require(RADIX_TOKEN)),
+    };
+
+    // Act
+    let receipt = test_runner.transfer_ownership(
+        &[Role::Primary, Role::Recovery, Role::Confirmation],
+        new_rule_set.clone(),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_runner.export_state().rule_set, new_rule_set);
+}
+
+#[test]
+pub fn transfer_ownership_without_all_three_roles_fails() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let new_rule_set = RuleSet {
+        primary_role: rule!(require(RADIX_TOKEN)),
+        recovery_role: rule!(require(RADIX_TOKEN)),
+        confirmation_role: rule!(require(RADIX_TOKEN)),
+    };
+
+    for as_roles in [
+        vec![Role::Primary, Role::Recovery],
+        vec![Role::Primary, Role::Confirmation],
+        vec![Role::Recovery, Role::Confirmation],
+    ] {
+        // Act
+        let receipt = test_runner.transfer_ownership(&as_roles, new_rule_set.clone());
+
+        // Assert
+        receipt.expect_specific_failure(is_auth_unauthorized_error);
+    }
+}
+
+#[test]
+pub fn check_role_with_a_proof_satisfying_the_role_returns_true() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    // Act
+    let is_satisfied = test_runner.check_role(Role::Recovery, Role::Recovery);
+
+    // Assert
+    assert!(is_satisfied);
+}
+
+#[test]
+pub fn check_role_with_a_proof_not_satisfying_the_role_returns_false() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    // Act
+    let is_satisfied = test_runner.check_role(Role::Primary, Role::Recovery);
+
+    // Assert
+    assert!(!is_satisfied);
+}
+
+#[test]
+pub fn can_act_as_with_a_badge_satisfying_the_role_returns_true() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let badge = test_runner.recovery_delegate(1);
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(badge.clone())),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+    test_runner
+        .quick_confirm_recovery(
+            Role::Primary,
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(badge.clone())),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let can_act_as = test_runner.can_act_as(badge, Role::Recovery);
+
+    // Assert
+    assert!(can_act_as);
+}
+
+#[test]
+pub fn can_act_as_with_a_badge_not_satisfying_the_role_returns_false() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let badge = test_runner.recovery_delegate(1);
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(badge.clone())),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+    test_runner
+        .quick_confirm_recovery(
+            Role::Primary,
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(badge.clone())),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let other_badge = test_runner.recovery_delegate(2);
+    let can_act_as_primary = test_runner.can_act_as(badge, Role::Primary);
+    let other_badge_can_act_as_recovery = test_runner.can_act_as(other_badge, Role::Recovery);
+
+    // Assert
+    assert!(!can_act_as_primary);
+    assert!(!other_badge_can_act_as_recovery);
+}
+
+#[test]
+pub fn back_to_back_recoveries_within_the_minimum_recovery_interval_fail() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_minimum_recovery_interval(Some(10), Some(60));
+    test_runner
+        .recover_with_current_rule_set()
+        .expect_commit_success();
+    test_runner.advance_clock(59);
+
+    // Act
+    let receipt = test_runner.recover_with_current_rule_set();
+
+    // Assert
+    receipt.expect_specific_failure(is_recovery_cooldown_active_error);
+}
+
+#[test]
+pub fn timing_info_of_a_new_access_controller_allows_everything_now() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_minimum_recovery_interval(Some(10), Some(60));
+
+    // Act
+    let timing_info = test_runner.get_timing_info();
+
+    // Assert
+    assert_eq!(
+        timing_info,
+        AccessControllerTimingInfo {
+            minutes_until_recovery_allowed: 0,
+            minutes_until_timed_confirm: BTreeMap::new(),
+        }
+    );
+}
+
+#[test]
+pub fn timing_info_counts_down_the_minimum_recovery_interval() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_minimum_recovery_interval(Some(10), Some(60));
+    test_runner
+        .recover_with_current_rule_set()
+        .expect_commit_success();
+
+    // Act
+    let timing_info_after_recovery = test_runner.get_timing_info();
+    test_runner.advance_clock(45);
+    let timing_info_later = test_runner.get_timing_info();
+    test_runner.advance_clock(30);
+    let timing_info_after_interval = test_runner.get_timing_info();
+
+    // Assert
+    assert_eq!(
+        timing_info_after_recovery.minutes_until_recovery_allowed,
+        60
+    );
+    assert_eq!(timing_info_later.minutes_until_recovery_allowed, 15);
+    assert_eq!(timing_info_after_interval.minutes_until_recovery_allowed, 0);
+}
+
+#[test]
+pub fn timing_info_counts_down_the_timed_recovery_delay_of_each_proposal() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let timing_info_after_initiation = test_runner.get_timing_info();
+    test_runner.advance_clock(4);
+    let timing_info_later = test_runner.get_timing_info();
+    test_runner.advance_clock(6);
+    let timing_info_after_delay = test_runner.get_timing_info();
+
+    // Assert
+    for (timing_info, minutes) in [
+        (timing_info_after_initiation, 10),
+        (timing_info_later, 6),
+        (timing_info_after_delay, 0),
+    ] {
+        assert_eq!(
+            timing_info.minutes_until_timed_confirm,
+            BTreeMap::from([
+                (Proposer::Primary, None),
+                (Proposer::Recovery, Some(minutes)),
+            ])
+        );
+    }
+}
+
+#[test]
+pub fn timeline_of_a_timed_proposal_ends_after_its_delay() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let initiated_at = test_runner.current_time();
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let timeline = test_runner.get_timeline();
+
+    // Assert
+    assert_eq!(
+        timeline,
+        AccessControllerTimeline {
+            cooldown_until: None,
+            proposals: BTreeMap::from([
+                (
+                    Proposer::Primary,
+                    AccessControllerProposalTimeline {
+                        initiated_at: Some(initiated_at),
+                        earliest_timed_confirm_at: None,
+                    }
+                ),
+                (
+                    Proposer::Recovery,
+                    AccessControllerProposalTimeline {
+                        initiated_at: Some(initiated_at),
+                        earliest_timed_confirm_at: Some(initiated_at.add_minutes(10).unwrap()),
+                    }
+                ),
+            ]),
+        }
+    );
+}
+
+#[test]
+pub fn snapshot_includes_when_proposals_underway_were_initiated() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let initiated_at = test_runner.current_time();
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let snapshot = test_runner.export_state();
+
+    // Assert
+    assert_eq!(
+        snapshot.proposal_initiated_at,
+        BTreeMap::from([(Proposer::Recovery, initiated_at)])
+    );
+}
+
+#[test]
+pub fn timeline_of_a_timed_proposal_ends_no_earlier_than_the_cooldown() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_minimum_recovery_interval(Some(10), Some(60));
+    let recovered_at = test_runner.current_time();
+    test_runner
+        .recover_with_current_rule_set()
+        .expect_commit_success();
+    test_runner.advance_clock(5);
+    let initiated_at = test_runner.current_time();
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let timeline = test_runner.get_timeline();
+
+    // Assert
+    let cooldown_until = recovered_at.add_minutes(60).unwrap();
+    assert_eq!(
+        timeline,
+        AccessControllerTimeline {
+            cooldown_until: Some(cooldown_until),
+            proposals: BTreeMap::from([(
+                Proposer::Recovery,
+                AccessControllerProposalTimeline {
+                    initiated_at: Some(initiated_at),
+                    earliest_timed_confirm_at: Some(cooldown_until),
+                }
+            )]),
+        }
+    );
+}
+
+#[test]
+pub fn recovery_after_the_minimum_recovery_interval_succeeds() {
+    // Arrange
+    let mut test_runner =
+        AccessControllerTestFixture::new_with_minimum_recovery_interval(Some(10), Some(60));
+    test_runner
+        .recover_with_current_rule_set()
+        .expect_commit_success();
+    test_runner.advance_clock(60);
+
+    // Act
+    let receipt = test_runner.recover_with_current_rule_set();
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn recovery_delegate_can_initiate_recovery_proposal() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .add_recovery_delegate(Role::Recovery, 1)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.initiate_recovery_as_delegate(
+        1,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let snapshot = test_runner.export_state();
+    assert_eq!(
+        snapshot.recovery_role_recovery_proposal,
+        Some(RecoveryProposal {
+            rule_set: RuleSet {
+                primary_role: rule!(require(RADIX_TOKEN)),
+                recovery_role: rule!(require(RADIX_TOKEN)),
+                confirmation_role: rule!(require(RADIX_TOKEN)),
+            },
+            timed_recovery_delay_in_minutes: Some(10),
+        })
+    );
+}
+
+#[test]
+pub fn initiating_recovery_as_a_non_delegate_fails() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .add_recovery_delegate(Role::Recovery, 1)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.initiate_recovery_as_delegate(
+        2,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_caller_is_not_a_recovery_delegate_error);
+}
+
+#[test]
+pub fn removed_recovery_delegate_can_not_initiate_recovery() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .add_recovery_delegate(Role::Recovery, 1)
+        .expect_commit_success();
+    test_runner
+        .remove_recovery_delegate(Role::Recovery, 1)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.initiate_recovery_as_delegate(
+        1,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_caller_is_not_a_recovery_delegate_error);
+}
+
+#[test]
+pub fn only_recovery_role_can_manage_recovery_delegates() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    // Act
+    let receipt = test_runner.add_recovery_delegate(Role::Primary, 1);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_unauthorized_error);
+}
+
+#[test]
+pub fn initiating_recovery_past_the_outstanding_proposal_cap_fails() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .add_recovery_delegate(Role::Recovery, 1)
+        .expect_commit_success();
+
+    // Act - fill the controller up to the cap
+    for role in [Role::Primary, Role::Recovery] {
+        test_runner
+            .initiate_recovery(
+                role,
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                Some(10),
+            )
+            .expect_commit_success();
+    }
+    let snapshot = test_runner.export_state();
+    let outstanding = [
+        snapshot.primary_role_recovery_proposal,
+        snapshot.recovery_role_recovery_proposal,
+    ]
+    .iter()
+    .filter(|proposal| proposal.is_some())
+    .count();
+    assert_eq!(outstanding, MAX_OUTSTANDING_RECOVERY_PROPOSALS);
+
+    // Act - go past the cap
+    let receipt = test_runner.initiate_recovery_as_delegate(
+        1,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_too_many_proposals_error);
+    assert_eq!(
+        test_runner.validate_proposal(
+            Proposer::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        ),
+        ProposalValidationOutcome::Rejected(ProposalRejectionReason::TooManyProposals)
+    );
+}
+
+#[test]
+pub fn proposing_a_shorter_timed_recovery_delay_fails() {
+    for role in [Role::Primary, Role::Recovery] {
+        // Arrange
+        let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+        // Act
+        let receipt = test_runner.initiate_recovery(
+            role,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(5),
+        );
+
+        // Assert
+        receipt.expect_specific_failure(is_delay_downgrade_not_allowed_error);
+    }
+}
+
+#[test]
+pub fn proposal_validation_rejects_a_shorter_timed_recovery_delay() {
+    for proposer in [Proposer::Primary, Proposer::Recovery] {
+        // Arrange
+        let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+        // Act
+        let outcome = test_runner.validate_proposal(
+            proposer,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(5),
+        );
+
+        // Assert
+        assert_eq!(
+            outcome,
+            ProposalValidationOutcome::Rejected(ProposalRejectionReason::DelayDowngradeNotAllowed)
+        );
+    }
+}
+
+#[test]
+pub fn proposing_an_equal_or_longer_timed_recovery_delay_succeeds() {
+    for (role, timed_recovery_delay_in_minutes) in [
+        (Role::Primary, Some(10)),
+        (Role::Primary, Some(20)),
+        (Role::Recovery, Some(10)),
+        (Role::Recovery, Some(20)),
+    ] {
+        // Arrange
+        let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+        // Act
+        let receipt = test_runner.initiate_recovery(
+            role,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            timed_recovery_delay_in_minutes,
+        );
+
+        // Assert
+        receipt.expect_commit_success();
+    }
+}
+
+#[test]
+pub fn recovery_delegate_can_not_confirm_recovery_proposal() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .add_recovery_delegate(Role::Recovery, 1)
+        .expect_commit_success();
+    test_runner
+        .initiate_recovery_as_delegate(
+            1,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+    test_runner.advance_clock(10);
+
+    // Act
+    let quick_confirm_receipt = test_runner.quick_confirm_recovery_as_delegate(
+        1,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+    let timed_confirm_receipt = test_runner.timed_confirm_recovery_as_delegate(
+        1,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    quick_confirm_receipt.expect_specific_failure(is_auth_unauthorized_error);
+    timed_confirm_receipt.expect_specific_failure(is_auth_unauthorized_error);
+}
+
+#[test]
+pub fn author_of_a_delegate_proposal_is_the_badge_of_the_delegate() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .add_recovery_delegate(Role::Recovery, 1)
+        .expect_commit_success();
+    test_runner
+        .initiate_recovery_as_delegate(
+            1,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let proposal_authors = test_runner.get_proposal_authors();
+
+    // Assert
+    assert_eq!(
+        proposal_authors,
+        BTreeMap::from([(Proposer::Recovery, Some(test_runner.recovery_delegate(1)))])
+    );
+}
+
+#[test]
+pub fn author_of_a_proposal_made_with_a_fungible_badge_is_not_known() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let proposal_authors = test_runner.get_proposal_authors();
+
+    // Assert
+    assert_eq!(
+        proposal_authors,
+        BTreeMap::from([(Proposer::Primary, None)])
+    );
+}
+
+#[test]
+pub fn author_of_a_cancelled_proposal_is_no_longer_reported() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .add_recovery_delegate(Role::Recovery, 1)
+        .expect_commit_success();
+    test_runner
+        .initiate_recovery_as_delegate(
+            1,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    test_runner
+        .cancel_recovery(Role::Recovery)
+        .expect_commit_success();
+
+    // Assert
+    assert!(test_runner.get_proposal_authors().is_empty());
+}
+
+#[test]
+pub fn snapshot_includes_the_authors_of_proposals_underway() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .add_recovery_delegate(Role::Recovery, 1)
+        .expect_commit_success();
+    test_runner
+        .initiate_recovery_as_delegate(
+            1,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+
+    // Act
+    let snapshot = test_runner.export_state();
+
+    // Assert
+    assert_eq!(
+        snapshot.proposal_authors,
+        BTreeMap::from([(Proposer::Recovery, test_runner.recovery_delegate(1))])
+    );
+}
+
+#[test]
+pub fn pausing_without_primary_and_confirmation_roles_fails() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    for as_roles in [
+        vec![Role::Primary],
+        vec![Role::Confirmation],
+        vec![Role::Primary, Role::Recovery],
+    ] {
+        // Act
+        let receipt = test_runner.pause(&as_roles);
+
+        // Assert
+        receipt.expect_specific_failure(is_auth_unauthorized_error);
+    }
+}
+
+#[test]
+pub fn recovery_can_not_be_initiated_confirmed_or_cancelled_while_paused() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+    test_runner
+        .pause(&[Role::Primary, Role::Confirmation])
+        .expect_commit_success();
+    test_runner.advance_clock(10);
+
+    // Act
+    let initiate_receipt = test_runner.initiate_recovery(
+        Role::Primary,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+    let quick_confirm_receipt = test_runner.quick_confirm_recovery(
+        Role::Confirmation,
+        Role::Recovery,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+    let timed_confirm_receipt = test_runner.timed_confirm_recovery(
+        Role::Recovery,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+    let cancel_receipt = test_runner.cancel_recovery(Role::Recovery);
+
+    // Assert
+    initiate_receipt.expect_specific_failure(is_controller_paused_error);
+    quick_confirm_receipt.expect_specific_failure(is_controller_paused_error);
+    timed_confirm_receipt.expect_specific_failure(is_controller_paused_error);
+    cancel_receipt.expect_specific_failure(is_controller_paused_error);
+}
+
+#[test]
+pub fn proposals_are_rejected_while_paused() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .pause(&[Role::Primary, Role::Confirmation])
+        .expect_commit_success();
+
+    // Act
+    let snapshot = test_runner.export_state();
+
+    // Assert
+    assert!(snapshot.is_paused);
+    for proposer in [Proposer::Primary, Proposer::Recovery] {
+        assert_eq!(
+            test_runner.validate_proposal(
+                proposer,
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                rule!(require(RADIX_TOKEN)),
+                Some(10),
+            ),
+            ProposalValidationOutcome::Rejected(ProposalRejectionReason::ControllerPaused)
+        );
+    }
+}
+
+#[test]
+pub fn proofs_can_be_created_while_paused() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .pause(&[Role::Primary, Role::Confirmation])
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.create_proof(Role::Primary);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn recovery_underway_can_be_confirmed_after_resuming() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
+    test_runner
+        .pause(&[Role::Primary, Role::Confirmation])
+        .expect_commit_success();
+    test_runner
+        .resume(&[Role::Primary, Role::Confirmation])
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.quick_confirm_recovery(
+        Role::Confirmation,
+        Role::Recovery,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn predicted_access_controller_address_matches_created_address() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let controlled_asset = test_runner.create_fungible_resource(2.into(), 0, account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .withdraw_from_account(account, controlled_asset)
+        .take_from_worktop_by_amount(1.into(), controlled_asset, |builder, bucket| {
+            builder.create_access_controller(
+                bucket,
+                rule!(allow_all),
+                rule!(allow_all),
+                rule!(allow_all),
+                None,
+                AccessControllerSettings::default(),
+            )
+        })
+        .take_from_worktop(controlled_asset, |builder, bucket| {
+            builder.create_access_controller(
+                bucket,
+                rule!(allow_all),
+                rule!(allow_all),
+                rule!(allow_all),
+                None,
+                AccessControllerSettings::default(),
+            )
+        })
+        .build();
+    let transaction = TestTransaction::new(
+        manifest,
+        test_runner.next_transaction_nonce(),
+        DEFAULT_COST_UNIT_LIMIT,
+    );
+    let executable =
+        transaction.get_executable(vec![NonFungibleGlobalId::from_public_key(&public_key)]);
+    let transaction_hash = executable.transaction_hash().clone();
+
+    // Act
+    let receipt = test_runner.execute_transaction(executable);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        receipt.new_component_addresses(),
+        &vec![
+            predict_access_controller_address(&transaction_hash, 0),
+            predict_access_controller_address(&transaction_hash, 1),
+        ]
+    );
+}
+
+#[test]
+pub fn proofs_of_an_access_controller_with_one_shot_proofs_cant_be_cloned() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let controlled_asset = test_runner.create_fungible_resource(1.into(), 0, account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .withdraw_from_account(account, controlled_asset)
+        .take_from_worktop(controlled_asset, |builder, bucket| {
+            builder.create_access_controller(
+                bucket,
+                rule!(allow_all),
+                rule!(allow_all),
+                rule!(allow_all),
+                None,
+                AccessControllerSettings {
+                    one_shot_proofs: true,
+                    ..Default::default()
+                },
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+    let access_controller_component_address = receipt.new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .call_method(
+            access_controller_component_address,
+            &AccessControllerFn::CreateProof.to_string(),
+            scrypto_encode(&AccessControllerCreateProofMethodArgs {}).unwrap(),
+        )
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.clone_proof(&proof_id, |builder, _| builder)
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ProofError(
+                ProofError::OneShotProofCannotBeCloned
+            ))
+        )
+    });
+}
+
+#[test]
+pub fn access_controller_created_with_several_assets_holds_a_vault_for_each() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let admin_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let emergency_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+
+    // Act
+    let receipt = create_access_controller_with_assets(
+        &mut test_runner,
+        &public_key,
+        account,
+        &[admin_badge, emergency_badge],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let access_controller_component_address = receipt.new_component_addresses()[0];
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            access_controller_component_address,
+            &AccessControllerFn::GetControlledVaults.to_string(),
+            scrypto_encode(&AccessControllerGetControlledVaultsMethodArgs).unwrap(),
+        )
+        .build();
+    let controlled_vaults: Vec<VaultId> = test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .output(0);
+    assert_eq!(controlled_vaults.len(), 2);
+    assert_ne!(controlled_vaults[0], controlled_vaults[1]);
+}
+
+#[test]
+pub fn access_controller_created_without_assets_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+
+    // Act
+    let receipt = create_access_controller_with_assets(&mut test_runner, &public_key, account, &[]);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+                AccessControllerError::NoControlledAssets
+            ))
+        )
+    });
+}
+
+#[test]
+pub fn access_controller_created_with_a_bucket_given_twice_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let controlled_asset = test_runner.create_fungible_resource(1.into(), 0, account);
+    let mut manifest_builder = ManifestBuilder::new();
+    manifest_builder.withdraw_from_account(account, controlled_asset);
+    let mut buckets = Vec::new();
+    manifest_builder.take_from_worktop(controlled_asset, |builder, bucket| {
+        buckets.push(bucket.0);
+        buckets.push(bucket.0);
+        builder
+    });
+
+    // Act
+    let receipt = create_access_controller_from_buckets(
+        &mut test_runner,
+        &public_key,
+        manifest_builder,
+        buckets.clone(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+                AccessControllerError::DuplicateControlledAsset(bucket_id)
+            )) if *bucket_id == buckets[0]
+        )
+    });
+}
+
+#[test]
+pub fn proofs_of_every_controlled_asset_are_created() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let admin_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let emergency_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let receipt = create_access_controller_with_assets(
+        &mut test_runner,
+        &public_key,
+        account,
+        &[admin_badge, emergency_badge],
+    );
+    receipt.expect_commit_success();
+    let access_controller_component_address = receipt.new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .call_method(
+            access_controller_component_address,
+            &AccessControllerFn::CreateProofs.to_string(),
+            scrypto_encode(&AccessControllerCreateProofsMethodArgs {}).unwrap(),
+        )
+        .assert_access_rule(rule!(require(admin_badge) && require(emergency_badge)))
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn single_proof_is_only_of_the_first_controlled_asset() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let admin_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let emergency_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let receipt = create_access_controller_with_assets(
+        &mut test_runner,
+        &public_key,
+        account,
+        &[admin_badge, emergency_badge],
+    );
+    receipt.expect_commit_success();
+    let access_controller_component_address = receipt.new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10.into())
+        .call_method(
+            access_controller_component_address,
+            &AccessControllerFn::CreateProof.to_string(),
+            scrypto_encode(&AccessControllerCreateProofMethodArgs {}).unwrap(),
+        )
+        .assert_access_rule(rule!(require(admin_badge)))
+        .assert_access_rule(rule!(require(emergency_badge)))
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(..)
+            ))
+        )
+    });
+}
+
+#[test]
+pub fn exported_state_and_verification_cover_every_controlled_asset() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let admin_badge = test_runner.create_fungible_resource(2.into(), 0, account);
+    let emergency_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let receipt = create_access_controller_with_assets(
+        &mut test_runner,
+        &public_key,
+        account,
+        &[admin_badge, emergency_badge, admin_badge],
+    );
+    receipt.expect_commit_success();
+    let access_controller_component_address = receipt.new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            access_controller_component_address,
+            &AccessControllerFn::ExportState.to_string(),
+            scrypto_encode(&AccessControllerExportStateMethodArgs).unwrap(),
+        )
+        .build();
+    let snapshot: AccessControllerStateSnapshot = test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .output(0);
+    let verify = |test_runner: &mut TestRunner, expected_resource, expected_amount: u32| {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                access_controller_component_address,
+                &AccessControllerFn::VerifyControlledAsset.to_string(),
+                scrypto_encode(&AccessControllerVerifyControlledAssetMethodArgs {
+                    expected_resource,
+                    expected_amount: expected_amount.into(),
+                })
+                .unwrap(),
+            )
+            .build();
+        test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![])
+            .output::<bool>(0)
+    };
+
+    // Assert
+    assert_eq!(snapshot.controlled_asset_resource_address, admin_badge);
+    assert_eq!(snapshot.controlled_asset_amount, Decimal::one());
+    assert_eq!(
+        snapshot.controlled_assets,
+        vec![
+            (admin_badge, Decimal::one()),
+            (emergency_badge, Decimal::one()),
+            (admin_badge, Decimal::one()),
+        ]
+    );
+    assert!(verify(&mut test_runner, admin_badge, 2));
+    assert!(!verify(&mut test_runner, admin_badge, 1));
+    assert!(verify(&mut test_runner, emergency_badge, 1));
+    assert!(!verify(&mut test_runner, RADIX_TOKEN, 0));
+}
+
+#[test]
+pub fn access_controller_created_from_a_manifest_holds_a_single_vault() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+
+    // Act
+    let controlled_vaults = test_runner.get_controlled_vaults();
+
+    // Assert
+    assert_eq!(controlled_vaults.len(), 1);
+}
+
+#[test]
+pub fn default_rule_matches_the_rule_installed_on_creation() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    let package_token = NonFungibleGlobalId::new(
+        PACKAGE_TOKEN,
+        NonFungibleLocalId::bytes(
+            scrypto_encode(&PackageIdentifier::Native(NativePackage::AccessController)).unwrap(),
+        )
+        .unwrap(),
+    );
+
+    // Act
+    let default_rule = test_runner.get_default_rule();
+
+    // Assert
+    assert_eq!(
+        default_rule,
+        AccessControllerDefaultRule {
+            default_rule: rule!(deny_all),
+            default_rule_mutability: rule!(require(package_token)),
+        }
+    );
+}
+
+#[test]
+pub fn config_matches_individual_queries_after_transitions() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(20),
+        )
+        .expect_commit_success();
+    test_runner
+        .lock_primary_role(Role::Recovery)
+        .expect_commit_success();
+    test_runner
+        .quick_confirm_recovery(
+            Role::Confirmation,
+            Role::Recovery,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(20),
+        )
+        .expect_commit_success();
+
+    // Act
+    let config = test_runner.get_config();
 
     // Assert
-    receipt.expect_specific_failure(is_auth_unauthorized_error)
+    let snapshot = test_runner.export_state();
+    assert_eq!(
+        config.rule_set,
+        RuleSet {
+            primary_role: test_runner.get_role_rule(Role::Primary),
+            recovery_role: test_runner.get_role_rule(Role::Recovery),
+            confirmation_role: test_runner.get_role_rule(Role::Confirmation),
+        }
+    );
+    assert_eq!(config.rule_set, snapshot.rule_set);
+    assert_eq!(
+        config.timed_recovery_delay_in_minutes,
+        snapshot.timed_recovery_delay_in_minutes
+    );
+    assert_eq!(config.primary_role_locked, snapshot.primary_role_locked);
+    assert_eq!(
+        config.timed_recovery_enabled,
+        snapshot.timed_recovery_delay_in_minutes.is_some()
+    );
 }
 
 #[test]
-pub fn quick_confirm_semantics_are_correct() {
+pub fn access_controller_test_fixture_drives_a_full_recovery_lifecycle() {
     // Arrange
-    let test_vectors = [
-        (
-            Proposer::Primary,
+    let mut fixture = AccessControllerTestFixture::new(Some(10));
+    let RuleSet {
+        recovery_role,
+        confirmation_role,
+        ..
+    } = fixture.initial_rule_set();
+    let primary_role = rule!(require(fixture.role_badge(Role::Confirmation)));
+
+    fixture
+        .initiate_recovery(
             Role::Primary,
-            Some(is_auth_unauthorized_error),
-        ),
-        (Proposer::Primary, Role::Recovery, None),
-        (Proposer::Primary, Role::Confirmation, None),
-        (Proposer::Recovery, Role::Primary, None),
-        (
-            Proposer::Recovery,
+            primary_role.clone(),
+            recovery_role.clone(),
+            confirmation_role.clone(),
+            Some(10),
+        )
+        .expect_commit_success();
+    fixture
+        .cancel_recovery(Role::Primary)
+        .expect_commit_success();
+    fixture
+        .initiate_recovery(
             Role::Recovery,
-            Some(is_auth_unauthorized_error),
-        ),
-        (Proposer::Recovery, Role::Confirmation, None),
-    ];
+            primary_role.clone(),
+            recovery_role.clone(),
+            confirmation_role.clone(),
+            Some(10),
+        )
+        .expect_commit_success();
+    fixture
+        .timed_confirm_recovery(
+            Role::Recovery,
+            primary_role.clone(),
+            recovery_role.clone(),
+            confirmation_role.clone(),
+            Some(10),
+        )
+        .expect_specific_failure(is_timed_recovery_delay_has_not_elapsed_error);
 
-    for (proposer, role, error_assertion_function) in test_vectors {
-        let mut test_runner = AccessControllerTestRunner::new(Some(10));
+    // Act
+    fixture.advance_clock(10);
+    let receipt = fixture.timed_confirm_recovery(
+        Role::Recovery,
+        primary_role,
+        recovery_role,
+        confirmation_role,
+        Some(10),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    fixture
+        .create_proof(Role::Primary)
+        .expect_specific_failure(is_auth_unauthorized_error);
+    fixture
+        .create_proof(Role::Confirmation)
+        .expect_commit_success();
+}
+
+#[test]
+pub fn confirming_re_proposed_recovery_with_current_nonce_succeeds() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    for _ in 0..2 {
         test_runner
             .initiate_recovery(
-                proposer.into(),
+                Role::Primary,
                 rule!(require(RADIX_TOKEN)),
                 rule!(require(RADIX_TOKEN)),
                 rule!(require(RADIX_TOKEN)),
                 Some(10),
             )
             .expect_commit_success();
+        test_runner
+            .cancel_recovery(Role::Primary)
+            .expect_commit_success();
+    }
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
 
-        // Act
-        let receipt = test_runner.quick_confirm_recovery(
-            role,
-            proposer.into(),
+    // Act
+    let receipt = test_runner.quick_confirm_recovery_with_nonce(
+        Role::Recovery,
+        Role::Primary,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+        2,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn confirming_re_proposed_recovery_with_nonce_of_cancelled_proposal_fails() {
+    // Arrange
+    let mut test_runner = AccessControllerTestFixture::new(Some(10));
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
             rule!(require(RADIX_TOKEN)),
             rule!(require(RADIX_TOKEN)),
             rule!(require(RADIX_TOKEN)),
             Some(10),
-        );
+        )
+        .expect_commit_success();
+    let cancelled_proposal_nonce = test_runner.recovery_proposal_nonce(Proposer::Primary);
+    test_runner
+        .cancel_recovery(Role::Primary)
+        .expect_commit_success();
+    test_runner
+        .initiate_recovery(
+            Role::Primary,
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            rule!(require(RADIX_TOKEN)),
+            Some(10),
+        )
+        .expect_commit_success();
 
-        // Assert
-        match error_assertion_function {
-            None => {
-                receipt.expect_commit_success();
-            }
-            Some(function) => receipt.expect_specific_failure(function),
-        };
-    }
+    // Act
+    let receipt = test_runner.quick_confirm_recovery_with_nonce(
+        Role::Recovery,
+        Role::Primary,
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        rule!(require(RADIX_TOKEN)),
+        Some(10),
+        cancelled_proposal_nonce,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_stale_proposal_error);
+    assert_eq!(
+        test_runner.recovery_proposal_nonce(Proposer::Primary),
+        cancelled_proposal_nonce + 1
+    );
 }
 
 //=============
@@ -314,8 +2521,8 @@ mod normal_operations_with_primary_unlocked {
 
     const TIMED_RECOVERY_DELAY_IN_MINUTES: Option<u32> = Some(10);
 
-    fn setup_environment() -> AccessControllerTestRunner {
-        AccessControllerTestRunner::new(TIMED_RECOVERY_DELAY_IN_MINUTES)
+    fn setup_environment() -> AccessControllerTestFixture {
+        AccessControllerTestFixture::new(TIMED_RECOVERY_DELAY_IN_MINUTES)
     }
 
     #[test]
@@ -513,7 +2720,7 @@ mod normal_operations_with_primary_unlocked {
             let mut test_runner = setup_environment();
 
             // Act
-            let receipt = test_runner.cancel_recovery_attempt(role);
+            let receipt = test_runner.cancel_recovery(role);
 
             // Assert
             match error_assertion_function {
@@ -562,8 +2769,8 @@ mod normal_operations_with_primary_locked {
 
     const TIMED_RECOVERY_DELAY_IN_MINUTES: Option<u32> = Some(10);
 
-    fn setup_environment() -> AccessControllerTestRunner {
-        let mut test_runner = AccessControllerTestRunner::new(TIMED_RECOVERY_DELAY_IN_MINUTES);
+    fn setup_environment() -> AccessControllerTestFixture {
+        let mut test_runner = AccessControllerTestFixture::new(TIMED_RECOVERY_DELAY_IN_MINUTES);
         test_runner
             .lock_primary_role(Role::Recovery)
             .expect_commit_success();
@@ -768,7 +2975,7 @@ mod normal_operations_with_primary_locked {
             let mut test_runner = setup_environment();
 
             // Act
-            let receipt = test_runner.cancel_recovery_attempt(role);
+            let receipt = test_runner.cancel_recovery(role);
 
             // Assert
             match error_assertion_function {
@@ -817,8 +3024,8 @@ mod recovery_mode_with_primary_unlocked {
 
     const TIMED_RECOVERY_DELAY_IN_MINUTES: Option<u32> = Some(10);
 
-    fn setup_environment() -> AccessControllerTestRunner {
-        let mut test_runner = AccessControllerTestRunner::new(TIMED_RECOVERY_DELAY_IN_MINUTES);
+    fn setup_environment() -> AccessControllerTestFixture {
+        let mut test_runner = AccessControllerTestFixture::new(TIMED_RECOVERY_DELAY_IN_MINUTES);
         test_runner
             .initiate_recovery(
                 Role::Recovery,
@@ -1031,7 +3238,7 @@ mod recovery_mode_with_primary_unlocked {
             let mut test_runner = setup_environment();
 
             // Act
-            let receipt = test_runner.cancel_recovery_attempt(role);
+            let receipt = test_runner.cancel_recovery(role);
 
             // Assert
             match error_assertion_function {
@@ -1080,8 +3287,8 @@ mod recovery_mode_with_primary_locked {
 
     const TIMED_RECOVERY_DELAY_IN_MINUTES: Option<u32> = Some(10);
 
-    fn setup_environment() -> AccessControllerTestRunner {
-        let mut test_runner = AccessControllerTestRunner::new(TIMED_RECOVERY_DELAY_IN_MINUTES);
+    fn setup_environment() -> AccessControllerTestFixture {
+        let mut test_runner = AccessControllerTestFixture::new(TIMED_RECOVERY_DELAY_IN_MINUTES);
         test_runner
             .lock_primary_role(Role::Recovery)
             .expect_commit_success();
@@ -1300,7 +3507,7 @@ mod recovery_mode_with_primary_locked {
             let mut test_runner = setup_environment();
 
             // Act
-            let receipt = test_runner.cancel_recovery_attempt(role);
+            let receipt = test_runner.cancel_recovery(role);
 
             // Assert
             match error_assertion_function {
@@ -1411,50 +3618,223 @@ fn is_recovery_proposal_mismatch_error(error: &RuntimeError) -> bool {
     )
 }
 
-#[allow(dead_code)]
-struct AccessControllerTestRunner {
-    pub test_runner: TestRunner,
+fn is_self_confirmation_not_allowed_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::SelfConfirmationNotAllowed { .. }
+        ))
+    )
+}
 
-    pub account: (ComponentAddress, PublicKey),
+fn is_recovery_proposal_already_endorsed_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::RecoveryProposalAlreadyEndorsed { .. }
+        ))
+    )
+}
 
-    pub access_controller_component_address: ComponentAddress,
-    pub primary_role_badge: ResourceAddress,
-    pub recovery_role_badge: ResourceAddress,
-    pub confirmation_role_badge: ResourceAddress,
+fn is_recovery_endorsement_threshold_not_met_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::RecoveryEndorsementThresholdNotMet { .. }
+        ))
+    )
+}
 
-    pub timed_recovery_delay_in_minutes: Option<u32>,
+fn is_recovery_cooldown_active_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::RecoveryCooldownActive { .. }
+        ))
+    )
 }
 
-#[allow(dead_code)]
-impl AccessControllerTestRunner {
-    pub fn new(timed_recovery_delay_in_minutes: Option<u32>) -> Self {
-        let mut test_runner = TestRunner::builder().build();
+fn is_caller_is_not_a_recovery_delegate_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::CallerIsNotARecoveryDelegate
+        ))
+    )
+}
+
+fn is_delay_downgrade_not_allowed_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::DelayDowngradeNotAllowed { .. }
+        ))
+    )
+}
+
+fn is_controller_paused_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::ControllerPaused
+        ))
+    )
+}
+
+fn is_stale_proposal_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::StaleProposal { .. }
+        ))
+    )
+}
+
+fn is_too_many_proposals_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::TooManyProposals
+        ))
+    )
+}
+
+/// Creates an access controller controlling one unit of each of the given resources, through the
+/// create global invocation directly, as manifests can only pass it a single asset.
+fn create_access_controller_with_assets(
+    test_runner: &mut TestRunner,
+    public_key: &PublicKey,
+    account: ComponentAddress,
+    controlled_assets: &[ResourceAddress],
+) -> TransactionReceipt {
+    let mut manifest_builder = ManifestBuilder::new();
+    for resource_address in controlled_assets {
+        manifest_builder.withdraw_from_account_by_amount(account, 1.into(), *resource_address);
+    }
+    let mut buckets = Vec::new();
+    for resource_address in controlled_assets {
+        manifest_builder.take_from_worktop_by_amount(
+            1.into(),
+            *resource_address,
+            |builder, bucket| {
+                buckets.push(bucket.0);
+                builder
+            },
+        );
+    }
+
+    create_access_controller_from_buckets(test_runner, public_key, manifest_builder, buckets)
+}
+
+/// Creates an access controller controlling the given buckets, which the manifest builder must have
+/// taken from the worktop, through the create global invocation directly.
+fn create_access_controller_from_buckets(
+    test_runner: &mut TestRunner,
+    public_key: &PublicKey,
+    mut manifest_builder: ManifestBuilder,
+    buckets: Vec<BucketId>,
+) -> TransactionReceipt {
+    let mut instructions: Vec<Instruction> = manifest_builder
+        .build()
+        .instructions
+        .into_iter()
+        .map(Instruction::Basic)
+        .collect();
+    instructions.push(Instruction::System(NativeInvocation::AccessController(
+        AccessControllerInvocation::CreateGlobal(AccessControllerCreateGlobalInvocation {
+            controlled_assets: buckets,
+            rule_set: RuleSet {
+                primary_role: rule!(allow_all),
+                recovery_role: rule!(allow_all),
+                confirmation_role: rule!(allow_all),
+            },
+            timed_recovery_delay_in_minutes: None,
+            settings: AccessControllerSettings::default(),
+        }),
+    )));
+
+    let transaction = SystemTransaction {
+        instructions,
+        blobs: vec![],
+        nonce: test_runner.next_transaction_nonce(),
+        pre_allocated_ids: BTreeSet::new(),
+    };
+    test_runner.execute_transaction(
+        transaction.get_executable(vec![NonFungibleGlobalId::from_public_key(public_key)]),
+    )
+}
+
+/// Publishes the role prover blueprint and instantiates a role prover holding the badge of the given
+/// role of the fixture's access controller.
+fn instantiate_role_prover(
+    fixture: &mut AccessControllerTestFixture,
+    badge_role: Role,
+) -> ComponentAddress {
+    let package_address = fixture
+        .test_runner
+        .compile_and_publish("./tests/blueprints/access_controller");
+    let badge = fixture.role_badge(badge_role);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(fixture.account.0, badge)
+        .take_from_worktop(badge, |builder, bucket| {
+            builder.call_function(
+                package_address,
+                "AccessControllerRoleProver",
+                "new",
+                args!(fixture.access_controller_component_address, bucket),
+            )
+        })
+        .build();
+    let receipt = fixture.execute_manifest(manifest);
+    receipt.expect_commit_success();
+
+    receipt.new_component_addresses()[0]
+}
+
+/// A test runner for an access controller where all three roles require the same non-fungible
+/// badge resource, so that any of the badges can act as any role.
+struct SharedBadgeAccessControllerTestRunner {
+    pub test_runner: TestRunner,
+    pub account: (ComponentAddress, PublicKey),
+    pub access_controller_component_address: ComponentAddress,
+    pub badge: ResourceAddress,
+}
+
+impl SharedBadgeAccessControllerTestRunner {
+    pub fn new(prevent_self_confirmation: bool) -> Self {
+        Self::new_with_config(prevent_self_confirmation, None)
+    }
 
-        // Creating a new account - this is where the badges will be held
+    pub fn new_with_recovery_endorsement_threshold(recovery_endorsement_threshold: u8) -> Self {
+        Self::new_with_config(false, Some(recovery_endorsement_threshold))
+    }
+
+    fn new_with_config(
+        prevent_self_confirmation: bool,
+        recovery_endorsement_threshold: Option<u8>,
+    ) -> Self {
+        let mut test_runner = TestRunner::builder().build();
         let (public_key, _, account_component) = test_runner.new_account(false);
 
-        // Creating the resource to be protected
         let controlled_asset = test_runner.create_fungible_resource(1.into(), 0, account_component);
+        let badge = test_runner.create_non_fungible_resource(account_component);
 
-        // Creating three badges for the three roles.
-        let primary_role_badge =
-            test_runner.create_fungible_resource(1.into(), 0, account_component);
-        let recovery_role_badge =
-            test_runner.create_fungible_resource(1.into(), 0, account_component);
-        let confirmation_role_badge =
-            test_runner.create_fungible_resource(1.into(), 0, account_component);
-
-        // Creating the access controller component
         let manifest = ManifestBuilder::new()
             .lock_fee(account_component, 10.into())
             .withdraw_from_account(account_component, controlled_asset)
             .take_from_worktop(controlled_asset, |builder, bucket| {
                 builder.create_access_controller(
                     bucket,
-                    rule!(require(primary_role_badge)),
-                    rule!(require(recovery_role_badge)),
-                    rule!(require(confirmation_role_badge)),
-                    timed_recovery_delay_in_minutes,
+                    rule!(require(badge)),
+                    rule!(require(badge)),
+                    rule!(require(badge)),
+                    None,
+                    AccessControllerSettings {
+                        prevent_self_confirmation,
+                        recovery_endorsement_threshold,
+                        ..Default::default()
+                    },
                 )
             })
             .build();
@@ -1470,55 +3850,20 @@ impl AccessControllerTestRunner {
         Self {
             test_runner,
             account: (account_component, public_key.into()),
-
             access_controller_component_address,
-            primary_role_badge,
-            recovery_role_badge,
-            confirmation_role_badge,
-
-            timed_recovery_delay_in_minutes,
+            badge,
         }
     }
 
-    pub fn create_proof(&mut self, as_role: Role) -> TransactionReceipt {
-        let manifest = self
-            .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_component_address,
-                "create_proof",
-                scrypto_encode(&AccessControllerCreateProofMethodArgs {}).unwrap(),
-            )
-            .pop_from_auth_zone(|builder, _| builder)
-            .build();
-        self.execute_manifest(manifest)
-    }
-
-    pub fn initiate_recovery(
-        &mut self,
-        as_role: Role,
-        proposed_primary_role: AccessRule,
-        proposed_recovery_role: AccessRule,
-        proposed_confirmation_role: AccessRule,
-        timed_recovery_delay_in_minutes: Option<u32>,
-    ) -> TransactionReceipt {
-        let method_name = match as_role {
-            Role::Primary => AccessControllerFn::InitiateRecoveryAsPrimary,
-            Role::Recovery => AccessControllerFn::InitiateRecoveryAsRecovery,
-            Role::Confirmation => panic!("Confirmation Role can't initiate recovery!"),
-        };
-
+    pub fn initiate_recovery_as_primary(&mut self, badge_id: u64) -> TransactionReceipt {
         let manifest = self
-            .manifest_builder(as_role)
+            .manifest_builder(badge_id)
             .call_method(
                 self.access_controller_component_address,
-                &method_name.to_string(),
+                &AccessControllerFn::InitiateRecoveryAsPrimary.to_string(),
                 scrypto_encode(&AccessControllerInitiateRecoveryAsPrimaryMethodArgs {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
+                    rule_set: Self::proposed_rule_set(),
+                    timed_recovery_delay_in_minutes: None,
                 })
                 .unwrap(),
             )
@@ -1526,39 +3871,21 @@ impl AccessControllerTestRunner {
         self.execute_manifest(manifest)
     }
 
-    pub fn quick_confirm_recovery(
+    pub fn quick_confirm_primary_role_recovery_proposal(
         &mut self,
-        as_role: Role,
-        proposer: Role,
-        proposed_primary_role: AccessRule,
-        proposed_recovery_role: AccessRule,
-        proposed_confirmation_role: AccessRule,
-        timed_recovery_delay_in_minutes: Option<u32>,
+        badge_id: u64,
     ) -> TransactionReceipt {
-        let proposer = match proposer {
-            Role::Primary => Proposer::Primary,
-            Role::Recovery => Proposer::Recovery,
-            Role::Confirmation => panic!("Confirmation is not a valid proposer"),
-        };
-
-        let method_name = match proposer {
-            Proposer::Primary => AccessControllerFn::QuickConfirmPrimaryRoleRecoveryProposal,
-            Proposer::Recovery => AccessControllerFn::QuickConfirmRecoveryRoleRecoveryProposal,
-        };
-
+        let proposal_nonce = self.recovery_proposal_nonce(Proposer::Primary);
         let manifest = self
-            .manifest_builder(as_role)
+            .manifest_builder(badge_id)
             .call_method(
                 self.access_controller_component_address,
-                &method_name.to_string(),
+                &AccessControllerFn::QuickConfirmPrimaryRoleRecoveryProposal.to_string(),
                 scrypto_encode(
                     &AccessControllerQuickConfirmPrimaryRoleRecoveryProposalMethodArgs {
-                        rule_set: RuleSet {
-                            primary_role: proposed_primary_role,
-                            recovery_role: proposed_recovery_role,
-                            confirmation_role: proposed_confirmation_role,
-                        },
-                        timed_recovery_delay_in_minutes,
+                        rule_set: Self::proposed_rule_set(),
+                        timed_recovery_delay_in_minutes: None,
+                        proposal_nonce,
                     },
                 )
                 .unwrap(),
@@ -1567,28 +3894,15 @@ impl AccessControllerTestRunner {
         self.execute_manifest(manifest)
     }
 
-    pub fn timed_confirm_recovery(
-        &mut self,
-        as_role: Role,
-        proposed_primary_role: AccessRule,
-        proposed_recovery_role: AccessRule,
-        proposed_confirmation_role: AccessRule,
-        timed_recovery_delay_in_minutes: Option<u32>,
-    ) -> TransactionReceipt {
-        let method_name = AccessControllerFn::TimedConfirmRecovery;
-
+    pub fn initiate_recovery_as_recovery(&mut self, badge_id: u64) -> TransactionReceipt {
         let manifest = self
-            .manifest_builder(as_role)
+            .manifest_builder(badge_id)
             .call_method(
                 self.access_controller_component_address,
-                &method_name.to_string(),
-                scrypto_encode(&AccessControllerTimedConfirmRecoveryMethodArgs {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
+                &AccessControllerFn::InitiateRecoveryAsRecovery.to_string(),
+                scrypto_encode(&AccessControllerInitiateRecoveryAsRecoveryMethodArgs {
+                    rule_set: Self::proposed_rule_set(),
+                    timed_recovery_delay_in_minutes: None,
                 })
                 .unwrap(),
             )
@@ -1596,74 +3910,73 @@ impl AccessControllerTestRunner {
         self.execute_manifest(manifest)
     }
 
-    pub fn cancel_recovery_attempt(&mut self, as_role: Role) -> TransactionReceipt {
-        let method_name = match as_role {
-            Role::Primary => AccessControllerFn::CancelPrimaryRoleRecoveryProposal,
-            Role::Recovery => AccessControllerFn::CancelRecoveryRoleRecoveryProposal,
-            Role::Confirmation => panic!("No method for the given role"),
-        };
-
+    pub fn endorse_recovery_role_recovery_proposal(&mut self, badge_id: u64) -> TransactionReceipt {
         let manifest = self
-            .manifest_builder(as_role)
+            .manifest_builder(badge_id)
             .call_method(
                 self.access_controller_component_address,
-                &method_name.to_string(),
-                scrypto_encode(&AccessControllerCancelPrimaryRoleRecoveryProposalMethodArgs)
-                    .unwrap(),
+                &AccessControllerFn::EndorseRecoveryRoleRecoveryProposal.to_string(),
+                scrypto_encode(
+                    &AccessControllerEndorseRecoveryRoleRecoveryProposalMethodArgs {
+                        rule_set: Self::proposed_rule_set(),
+                        timed_recovery_delay_in_minutes: None,
+                        endorser: NonFungibleGlobalId::new(
+                            self.badge,
+                            NonFungibleLocalId::integer(badge_id),
+                        ),
+                    },
+                )
+                .unwrap(),
             )
             .build();
         self.execute_manifest(manifest)
     }
 
-    pub fn lock_primary_role(&mut self, as_role: Role) -> TransactionReceipt {
+    pub fn quick_confirm_recovery_role_recovery_proposal(
+        &mut self,
+        badge_id: u64,
+    ) -> TransactionReceipt {
+        let proposal_nonce = self.recovery_proposal_nonce(Proposer::Recovery);
         let manifest = self
-            .manifest_builder(as_role)
+            .manifest_builder(badge_id)
             .call_method(
                 self.access_controller_component_address,
-                "lock_primary_role",
-                scrypto_encode(&AccessControllerLockPrimaryRoleMethodArgs {}).unwrap(),
+                &AccessControllerFn::QuickConfirmRecoveryRoleRecoveryProposal.to_string(),
+                scrypto_encode(
+                    &AccessControllerQuickConfirmRecoveryRoleRecoveryProposalMethodArgs {
+                        rule_set: Self::proposed_rule_set(),
+                        timed_recovery_delay_in_minutes: None,
+                        proposal_nonce,
+                    },
+                )
+                .unwrap(),
             )
             .build();
         self.execute_manifest(manifest)
     }
 
-    pub fn unlock_primary_role(&mut self, as_role: Role) -> TransactionReceipt {
-        let manifest = self
-            .manifest_builder(as_role)
+    fn recovery_proposal_nonce(&mut self, proposer: Proposer) -> u64 {
+        let manifest = ManifestBuilder::new()
             .call_method(
                 self.access_controller_component_address,
-                "unlock_primary_role",
-                scrypto_encode(&AccessControllerUnlockPrimaryRoleMethodArgs {}).unwrap(),
+                "export_state",
+                scrypto_encode(&AccessControllerExportStateMethodArgs).unwrap(),
             )
             .build();
-        self.execute_manifest(manifest)
+        let snapshot: AccessControllerStateSnapshot = self.execute_manifest(manifest).output(0);
+        snapshot
+            .recovery_proposal_nonces
+            .get(&proposer)
+            .cloned()
+            .unwrap_or_default()
     }
 
-    pub fn stop_timed_recovery(
-        &mut self,
-        as_role: Role,
-        proposed_primary_role: AccessRule,
-        proposed_recovery_role: AccessRule,
-        proposed_confirmation_role: AccessRule,
-        timed_recovery_delay_in_minutes: Option<u32>,
-    ) -> TransactionReceipt {
-        let manifest = self
-            .manifest_builder(as_role)
-            .call_method(
-                self.access_controller_component_address,
-                "stop_timed_recovery",
-                scrypto_encode(&AccessControllerStopTimedRecoveryMethodArgs {
-                    rule_set: RuleSet {
-                        primary_role: proposed_primary_role,
-                        recovery_role: proposed_recovery_role,
-                        confirmation_role: proposed_confirmation_role,
-                    },
-                    timed_recovery_delay_in_minutes,
-                })
-                .unwrap(),
-            )
-            .build();
-        self.execute_manifest(manifest)
+    fn proposed_rule_set() -> RuleSet {
+        RuleSet {
+            primary_role: rule!(require(RADIX_TOKEN)),
+            recovery_role: rule!(require(RADIX_TOKEN)),
+            confirmation_role: rule!(require(RADIX_TOKEN)),
+        }
     }
 
     fn execute_manifest(&mut self, manifest: TransactionManifest) -> TransactionReceipt {
@@ -1673,21 +3986,13 @@ impl AccessControllerTestRunner {
         )
     }
 
-    fn manifest_builder(&self, role: Role) -> ManifestBuilder {
+    fn manifest_builder(&self, badge_id: u64) -> ManifestBuilder {
         let mut manifest_builder = ManifestBuilder::new();
-        let resource_address = match role {
-            Role::Primary => self.primary_role_badge,
-            Role::Recovery => self.recovery_role_badge,
-            Role::Confirmation => self.confirmation_role_badge,
-        };
-        manifest_builder.create_proof_from_account(self.account.0, resource_address);
+        manifest_builder.create_proof_from_account_by_ids(
+            self.account.0,
+            &[NonFungibleLocalId::integer(badge_id)].into(),
+            self.badge,
+        );
         manifest_builder
     }
-
-    fn push_time_forward(&mut self, minutes: i64) {
-        let current_time = self.test_runner.get_current_time(TimePrecision::Minute);
-        let new_time = current_time.add_minutes(minutes).unwrap();
-        self.test_runner
-            .set_current_time(new_time.seconds_since_unix_epoch * 1000);
-    }
 }
@@ -501,6 +501,50 @@ fn assert_access_rule_through_component_when_fulfilled_succeeds() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn access_rules_supplied_when_globalizing_are_enforced() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/access_rules");
+    let (public_key, _) = test_runner.new_key_pair();
+    let virtual_badge_non_fungible_global_id = NonFungibleGlobalId::from_public_key(&public_key);
+    let access_rules = AccessRules::new()
+        .method(
+            "deposit_funds",
+            rule!(require(virtual_badge_non_fungible_global_id.clone())),
+            rule!(deny_all),
+        )
+        .default(rule!(allow_all), rule!(deny_all));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            MutableAccessRulesTestRunner::BLUEPRINT_NAME,
+            "new_globalized_with_access_rules",
+            args!(access_rules),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+    receipt.expect_commit_success();
+    let component_address = receipt.new_component_addresses()[0];
+
+    // Assert
+    let manifest = ManifestBuilder::new()
+        .call_method(component_address, "deposit_funds", args!())
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest.clone(), vec![]);
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+    let receipt = test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![virtual_badge_non_fungible_global_id]);
+    receipt.expect_commit_success();
+}
+
 struct MutableAccessRulesTestRunner {
     test_runner: TestRunner,
     package_address: PackageAddress,
@@ -0,0 +1,64 @@
+use radix_engine_interface::api::Invokable;
+use scrypto::engine::scrypto_env::*;
+use scrypto::prelude::*;
+
+#[blueprint]
+mod access_controller_role_prover {
+    struct AccessControllerRoleProver {
+        access_controller: ComponentAddress,
+        badge_vault: Vault,
+    }
+
+    impl AccessControllerRoleProver {
+        pub fn new(access_controller: ComponentAddress, badge: Bucket) -> ComponentAddress {
+            Self {
+                access_controller,
+                badge_vault: Vault::with_bucket(badge),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn prove_recovery_role_and_quick_confirm_primary_role_recovery_proposal(
+            &self,
+            primary_role: AccessRule,
+            recovery_role: AccessRule,
+            confirmation_role: AccessRule,
+            timed_recovery_delay_in_minutes: Option<u32>,
+            proposal_nonce: u64,
+        ) {
+            ComponentAuthZone::prove_role_for_controller(
+                self.access_controller,
+                Role::Recovery,
+                &self.badge_vault,
+            )
+            .unwrap();
+
+            ScryptoEnv
+                .invoke(
+                    AccessControllerQuickConfirmPrimaryRoleRecoveryProposalInvocation {
+                        receiver: self.access_controller,
+                        proposal_to_confirm: RecoveryProposal {
+                            rule_set: RuleSet {
+                                primary_role,
+                                recovery_role,
+                                confirmation_role,
+                            },
+                            timed_recovery_delay_in_minutes,
+                        },
+                        proposal_nonce,
+                    },
+                )
+                .unwrap()
+        }
+
+        pub fn can_prove_confirmation_role(&self) -> bool {
+            ComponentAuthZone::prove_role_for_controller(
+                self.access_controller,
+                Role::Confirmation,
+                &self.badge_vault,
+            )
+            .is_ok()
+        }
+    }
+}
@@ -13,6 +13,12 @@ mod mutable_access_rules_component {
             component.globalize()
         }
 
+        pub fn new_globalized_with_access_rules(access_rules: AccessRules) -> ComponentAddress {
+            Self {}
+                .instantiate()
+                .globalize_with_access_rules(access_rules)
+        }
+
         pub fn access_rules_function(
             component_address: ComponentAddress,
         ) -> Vec<ComponentAccessRules> {
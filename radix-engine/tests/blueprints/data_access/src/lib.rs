@@ -3,6 +3,8 @@ use scrypto::engine::scrypto_env::*;
 use scrypto::prelude::*;
 use scrypto::radix_engine_interface::api::EngineApi;
 
+pub mod state_field_access;
+
 #[blueprint]
 mod data_access {
     struct DataAccess {}
@@ -34,6 +36,35 @@ mod data_access {
                 .unwrap();
         }
 
+        pub fn write_kv_store_entries_in_batch_with_read_only_second_lock() {
+            let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
+                scrypto_encode(&1u32).unwrap(),
+            ));
+            let first_store = ScryptoEnv
+                .sys_create_node(ScryptoRENode::KeyValueStore)
+                .unwrap();
+            let second_store = ScryptoEnv
+                .sys_create_node(ScryptoRENode::KeyValueStore)
+                .unwrap();
+            let first_lock_handle: LockHandle = ScryptoEnv
+                .sys_lock_substate(first_store, offset.clone(), true)
+                .unwrap();
+            let second_lock_handle: LockHandle = ScryptoEnv
+                .sys_lock_substate(second_store, offset, false)
+                .unwrap();
+
+            let entry = scrypto_encode(&KeyValueStoreEntrySubstate(Some(
+                scrypto_encode(&1u32).unwrap(),
+            )))
+            .unwrap();
+            ScryptoEnv
+                .sys_write_batch(vec![
+                    (first_lock_handle, entry.clone()),
+                    (second_lock_handle, entry),
+                ])
+                .unwrap();
+        }
+
         pub fn create_component_and_read_info() {
             let component_address = Self {}.instantiate().globalize();
             let lock_handle: LockHandle = ScryptoEnv
@@ -0,0 +1,39 @@
+use radix_engine_interface::api::types::*;
+use scrypto::engine::scrypto_env::*;
+use scrypto::prelude::*;
+use scrypto::radix_engine_interface::api::EngineApi;
+
+#[blueprint]
+mod state_field_access {
+    struct StateFieldAccess {
+        id: u32,
+        inner: (u8, String),
+    }
+
+    impl StateFieldAccess {
+        pub fn read_nested_state_field(path: Vec<usize>) -> String {
+            let component = Self {
+                id: 1,
+                inner: (2, "nested".to_owned()),
+            }
+            .instantiate();
+            let field: Vec<u8> = component.call("read_state_field", args!(component.0, path));
+            component.globalize();
+
+            scrypto_decode(&field).unwrap()
+        }
+
+        pub fn read_state_field(&self, component_id: ComponentId, path: Vec<usize>) -> Vec<u8> {
+            let lock_handle: LockHandle = ScryptoEnv
+                .sys_lock_substate(
+                    RENodeId::Component(component_id),
+                    SubstateOffset::Component(ComponentOffset::State),
+                    false,
+                )
+                .unwrap();
+            let field = ScryptoEnv.sys_read_field(lock_handle, path).unwrap();
+            ScryptoEnv.sys_drop_lock(lock_handle).unwrap();
+            field
+        }
+    }
+}
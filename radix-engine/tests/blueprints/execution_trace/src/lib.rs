@@ -1 +1,2 @@
 pub mod execution_trace;
+pub mod substate_access;
@@ -0,0 +1,25 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod substate_access_test {
+    struct SubstateAccessTest {
+        store: KeyValueStore<u32, u32>,
+    }
+
+    impl SubstateAccessTest {
+        pub fn new() -> ComponentAddress {
+            let store = KeyValueStore::new();
+            store.insert(0, 1);
+            store.insert(1, 2);
+            Self { store }.instantiate().globalize()
+        }
+
+        pub fn noop(&self) {}
+
+        pub fn read_two_write_one(&self) {
+            let first = *self.store.get(&0).unwrap();
+            let second = *self.store.get(&1).unwrap();
+            self.store.insert(2, first + second);
+        }
+    }
+}
@@ -1,4 +1,8 @@
+use native_sdk::resource::SysBucket;
+use scrypto::engine::scrypto_env::ScryptoEnv;
 use scrypto::prelude::*;
+use scrypto::radix_engine_interface::api::types::RENodeId;
+use scrypto::radix_engine_interface::api::EngineApi;
 
 #[blueprint]
 mod bucket_proof {
@@ -19,6 +23,33 @@ mod bucket_proof {
             bucket
         }
 
+        pub fn drop_one_of_many_bucket_proofs(bucket: Bucket, amount: Decimal) -> Bucket {
+            let proof = bucket.create_proof();
+            let clone1 = proof.clone();
+            let clone2 = proof.clone();
+
+            clone1.drop();
+
+            assert_eq!(proof.amount(), amount);
+            assert_eq!(clone2.amount(), amount);
+
+            clone2.drop();
+            proof.drop();
+            bucket
+        }
+
+        pub fn drop_bucket_proofs_in_batch(bucket: Bucket) -> Bucket {
+            let proof = bucket.create_proof();
+            let proofs = vec![proof.clone(), proof.clone(), proof];
+            let node_ids: Vec<RENodeId> = proofs.iter().map(|p| RENodeId::Proof(p.0)).collect();
+
+            Proof::drop_all(proofs);
+
+            let visible_nodes = ScryptoEnv.sys_get_visible_nodes().unwrap();
+            assert!(node_ids.iter().all(|id| !visible_nodes.contains(id)));
+            bucket
+        }
+
         pub fn use_bucket_proof_for_auth(bucket: Bucket, to_burn: Bucket) -> Bucket {
             bucket.authorize(|| {
                 to_burn.burn();
@@ -31,5 +62,40 @@ mod bucket_proof {
             let _proof = bucket.create_proof();
             bucket
         }
+
+        pub fn push_one_shot_bucket_proof_twice(bucket: Bucket) -> Bucket {
+            let proof = bucket.create_one_shot_proof();
+            ComponentAuthZone::push(proof);
+            let proof = ComponentAuthZone::pop();
+            ComponentAuthZone::push(proof);
+            ComponentAuthZone::pop().drop();
+            bucket
+        }
+
+        pub fn clone_one_shot_bucket_proof(bucket: Bucket) -> Bucket {
+            let proof = bucket.create_one_shot_proof();
+            let clone = proof.clone();
+            clone.drop();
+            proof.drop();
+            bucket
+        }
+
+        pub fn create_and_push_bucket_proof(bucket: Bucket, amount: Decimal) -> Bucket {
+            let resource_address = bucket.resource_address();
+
+            bucket.sys_create_and_push_proof(&mut ScryptoEnv).unwrap();
+            assert_eq!(ComponentAuthZone::list(), vec![(resource_address, amount)]);
+
+            bucket.create_and_push_proof();
+            assert_eq!(
+                ComponentAuthZone::list(),
+                vec![(resource_address, amount), (resource_address, amount)]
+            );
+
+            assert_eq!(bucket.amount(), amount);
+            ComponentAuthZone::pop().drop();
+            ComponentAuthZone::pop().drop();
+            bucket
+        }
     }
 }
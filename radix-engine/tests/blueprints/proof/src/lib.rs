@@ -1,3 +1,5 @@
 pub mod bucket_proof;
 pub mod receiver;
+pub mod role_check;
+pub mod stored_rule;
 pub mod vault_proof;
@@ -1,5 +1,13 @@
+use scrypto::engine::scrypto_env::ScryptoEnv;
 use scrypto::prelude::*;
 
+#[derive(NonFungibleData)]
+pub struct Membership {
+    pub tier: String,
+    #[mutable]
+    pub active: bool,
+}
+
 #[blueprint]
 mod receiver {
     struct Receiver {
@@ -32,5 +40,461 @@ mod receiver {
             assert_eq!(proof.non_fungible_local_ids(), ids);
             assert_eq!(proof.resource_address(), resource_address);
         }
+
+        pub fn assert_validation_queries(
+            proof: Proof,
+            ids: BTreeSet<NonFungibleLocalId>,
+            resource_address: ResourceAddress,
+        ) {
+            let mut queries = Vec::new();
+            proof
+                .validate_with_query_hook(
+                    ProofValidationMode::ValidateContainsNonFungibles(resource_address, ids),
+                    |query| queries.push(query),
+                )
+                .unwrap();
+            assert_eq!(
+                queries,
+                vec![
+                    ProofValidationQuery::Presence,
+                    ProofValidationQuery::ResourceAddress,
+                    ProofValidationQuery::NonFungibleLocalIds
+                ]
+            );
+            proof.drop();
+        }
+
+        pub fn assert_contains_n_times(proof: Proof, resource_address: ResourceAddress, n: u32) {
+            let proof = proof.unsafe_skip_proof_validation();
+            for _ in 0..n {
+                assert!(proof.contains(resource_address));
+            }
+            proof.drop();
+        }
+
+        pub fn assert_validated_parts(
+            proof: Proof,
+            resource_address: ResourceAddress,
+            amount: Decimal,
+        ) {
+            let (proof, validated_resource_address, validated_amount) = proof
+                .validate_into_parts(ProofValidationMode::ValidateContainsAmount(
+                    resource_address,
+                    amount,
+                ))
+                .unwrap();
+            assert_eq!(validated_resource_address, resource_address);
+            assert_eq!(validated_amount, amount);
+            assert_eq!(proof.resource_address(), resource_address);
+            assert_eq!(proof.amount(), amount);
+            proof.drop();
+        }
+
+        pub fn validate_contains_amount(
+            proof: Proof,
+            resource_address: ResourceAddress,
+            amount: Decimal,
+            into_parts: bool,
+        ) {
+            let validation_mode =
+                ProofValidationMode::ValidateContainsAmount(resource_address, amount);
+            let proof = if into_parts {
+                proof.validate_into_parts(validation_mode).unwrap().0
+            } else {
+                proof.validate_proof(validation_mode).unwrap()
+            };
+            proof.drop();
+        }
+
+        pub fn assert_excess(
+            proof: Proof,
+            resource_address: ResourceAddress,
+            required_amount: Decimal,
+            expected_excess: Decimal,
+        ) {
+            let (proof, excess) = proof
+                .validate_with_excess(resource_address, required_amount)
+                .unwrap();
+            assert_eq!(excess, expected_excess);
+            assert_eq!(proof.amount(), required_amount + expected_excess);
+            proof.drop();
+        }
+
+        pub fn assert_amount_divisibility(
+            proof: Proof,
+            resource_address: ResourceAddress,
+            amount: Decimal,
+            over_precise_amount: Decimal,
+        ) {
+            let expected_error = ProofValidationError::AmountExceedsDivisibility {
+                amount: over_precise_amount,
+                divisibility: 2,
+            };
+            assert!(proof.validate_amount_divisibility(amount).is_ok());
+            assert_eq!(
+                proof.validate_amount_divisibility(over_precise_amount),
+                Err(expected_error.clone())
+            );
+            assert_eq!(
+                proof.validate(ProofValidationMode::ValidateContainsAmount(
+                    resource_address,
+                    over_precise_amount
+                )),
+                Err(expected_error)
+            );
+            let proof = proof
+                .validate_proof(ProofValidationMode::ValidateContainsAmount(
+                    resource_address,
+                    amount,
+                ))
+                .unwrap();
+            proof.drop();
+        }
+
+        pub fn assert_contains_global_id(
+            proof: Proof,
+            non_fungible_global_id: NonFungibleGlobalId,
+            other_non_fungible_global_id: NonFungibleGlobalId,
+        ) {
+            assert!(proof
+                .validate_contains_global_id(&non_fungible_global_id)
+                .is_ok());
+            assert_eq!(
+                proof.validate_contains_global_id(&other_non_fungible_global_id),
+                Err(ProofValidationError::NonFungibleLocalIdNotFound)
+            );
+            let proof = proof.validate_proof(non_fungible_global_id).unwrap();
+            proof.drop();
+        }
+
+        pub fn assert_resource_proof_hides_ids(
+            proof: Proof,
+            resource_address: ResourceAddress,
+            amount: Decimal,
+        ) {
+            let proof = proof.validate_proof(resource_address).unwrap();
+            assert!(!proof.non_fungible_local_ids().is_empty());
+
+            let proof = proof.into_resource_proof();
+            assert_eq!(proof.resource_address(), resource_address);
+            assert_eq!(proof.amount(), amount);
+            assert!(proof.non_fungible_local_ids().is_empty());
+            proof.drop();
+        }
+
+        pub fn assert_controlled_by(
+            proof: Proof,
+            access_controller: ComponentAddress,
+            is_controlled: bool,
+        ) {
+            let mut queries = Vec::new();
+            let result = proof.validate_with_query_hook(
+                ProofValidationMode::ValidateControlledBy(access_controller),
+                |query| queries.push(query),
+            );
+            assert_eq!(
+                queries,
+                vec![
+                    ProofValidationQuery::Presence,
+                    ProofValidationQuery::ControlledResource,
+                    ProofValidationQuery::ResourceAddress
+                ]
+            );
+            if is_controlled {
+                assert!(result.is_ok());
+            } else {
+                assert_eq!(
+                    result,
+                    Err(ProofValidationError::NotControlledBy(access_controller))
+                );
+            }
+            proof.drop();
+        }
+
+        pub fn assert_validated_non_empty(proof: Proof, resource_address: ResourceAddress) {
+            let proof = proof
+                .validate_proof_non_empty(resource_address)
+                .expect("Proof should be valid and non-empty");
+            proof.drop();
+        }
+
+        pub fn assert_validate_all_reports_every_failure(
+            proof: Proof,
+            resource_address: ResourceAddress,
+            other_resource_address: ResourceAddress,
+        ) {
+            let outcomes = proof.validate_all(&[
+                ProofValidationMode::ValidateResourceAddress(other_resource_address),
+                ProofValidationMode::ValidateResourceAddress(resource_address),
+                ProofValidationMode::ValidateContainsAmount(resource_address, dec!("1000")),
+            ]);
+            assert_eq!(
+                outcomes,
+                vec![
+                    Err(ProofValidationError::InvalidResourceAddress(
+                        other_resource_address
+                    )),
+                    Ok(()),
+                    Err(ProofValidationError::InvalidAmount(dec!("1000"))),
+                ]
+            );
+            proof.drop();
+        }
+
+        pub fn assert_min_non_fungible_count(
+            proof: Proof,
+            resource_address: ResourceAddress,
+            required: usize,
+            actual: usize,
+        ) {
+            let result = proof.validate(ProofValidationMode::ValidateMinNonFungibleCount(
+                resource_address,
+                required,
+            ));
+            if actual >= required {
+                assert_eq!(result, Ok(()));
+            } else {
+                assert_eq!(
+                    result,
+                    Err(ProofValidationError::InsufficientNonFungibleCount { required, actual })
+                );
+            }
+            proof.drop();
+        }
+
+        pub fn assert_amount_with_symbol(
+            proof: Proof,
+            symbol: String,
+            amount: Decimal,
+            symbol_matches: bool,
+            amount_sufficient: bool,
+        ) {
+            let result = proof.validate(
+                AmountWithSymbolValidationBuilder::new(symbol)
+                    .min_amount(amount)
+                    .build(),
+            );
+            let expected = match (symbol_matches, amount_sufficient) {
+                (true, true) => Ok(()),
+                (false, true) => Err(AmountWithSymbolFailure::SymbolMismatch),
+                (true, false) => Err(AmountWithSymbolFailure::AmountShortfall),
+                (false, false) => Err(AmountWithSymbolFailure::SymbolMismatchAndAmountShortfall),
+            };
+            assert_eq!(
+                result,
+                expected.map_err(ProofValidationError::AmountWithSymbolNotMet)
+            );
+            proof.drop();
+        }
+
+        pub fn assert_resource_behavior(
+            proof: Proof,
+            resource_address: ResourceAddress,
+            behavior: BehaviorFlags,
+            missing: BehaviorFlags,
+        ) {
+            let result = proof.validate(ProofValidationMode::ValidateResourceBehavior(
+                resource_address,
+                behavior,
+            ));
+            if missing.is_empty() {
+                assert_eq!(result, Ok(()));
+            } else {
+                assert_eq!(result, Err(ProofValidationError::BehaviorMismatch(missing)));
+            }
+            proof.drop();
+        }
+
+        pub fn assert_matches_vault(
+            proof: Proof,
+            vault_resource_address: ResourceAddress,
+            matches: bool,
+        ) {
+            let result = proof.validate_matches_vault(vault_resource_address);
+            if matches {
+                assert_eq!(result, Ok(()));
+            } else {
+                assert_eq!(
+                    result,
+                    Err(ProofValidationError::InvalidResourceAddress(
+                        vault_resource_address
+                    ))
+                );
+            }
+            proof.drop();
+        }
+
+        pub fn assert_not_frozen(proof: Proof, resource_address: ResourceAddress, is_frozen: bool) {
+            let result = proof.validate(ProofValidationMode::ValidateNotFrozen(resource_address));
+            if is_frozen {
+                assert_eq!(
+                    result,
+                    Err(ProofValidationError::ResourceFrozen(resource_address))
+                );
+            } else {
+                assert_eq!(result, Ok(()));
+            }
+            proof.drop();
+        }
+
+        pub fn assert_non_fungible_data_field(expected_tier: String, matches: bool) -> Bucket {
+            let bucket = ResourceBuilder::new_integer_non_fungible().mint_initial_supply([
+                (
+                    1u64.into(),
+                    Membership {
+                        tier: "gold".to_owned(),
+                        active: true,
+                    },
+                ),
+                (
+                    2u64.into(),
+                    Membership {
+                        tier: "gold".to_owned(),
+                        active: true,
+                    },
+                ),
+            ]);
+            let resource_address = bucket.resource_address();
+            let proof = bucket.create_proof();
+
+            let result = proof.validate(
+                NonFungibleDataFieldValidationBuilder::new(
+                    resource_address,
+                    "tier",
+                    &expected_tier,
+                )
+                .build::<Membership>(),
+            );
+            if matches {
+                assert_eq!(result, Ok(()));
+            } else {
+                assert_eq!(
+                    result,
+                    Err(ProofValidationError::DataFieldMismatch {
+                        field: "tier".to_owned()
+                    })
+                );
+            }
+
+            // Fields of the mutable part of the data are located in the same way
+            let result = proof.validate(
+                NonFungibleDataFieldValidationBuilder::new(resource_address, "active", &true)
+                    .build::<Membership>(),
+            );
+            assert_eq!(result, Ok(()));
+
+            proof.drop();
+            bucket
+        }
+
+        pub fn assert_proof_set_amount(
+            proofs: Vec<Proof>,
+            resource_address: ResourceAddress,
+            min_total: Decimal,
+            actual_total: Decimal,
+        ) {
+            let proofs: Vec<ValidatedProof> = proofs
+                .into_iter()
+                .map(|proof| proof.unsafe_skip_proof_validation())
+                .collect();
+            let result = validate_proof_set_amount(&proofs, resource_address, min_total);
+            if actual_total >= min_total {
+                assert_eq!(result, Ok(()));
+            } else {
+                assert_eq!(
+                    result,
+                    Err(ProofValidationError::InsufficientTotalAmount {
+                        required: min_total,
+                        actual: actual_total,
+                    })
+                );
+            }
+            for proof in proofs {
+                proof.drop();
+            }
+        }
+
+        pub fn assert_all_proofs_validate(
+            proofs: Vec<Proof>,
+            resource_address: ResourceAddress,
+            all_valid: bool,
+        ) {
+            let count = proofs.len();
+            let result = validate_all_proofs(
+                proofs,
+                &ProofValidationMode::ValidateResourceAddress(resource_address),
+            );
+            match result {
+                Ok(validated_proofs) => {
+                    assert!(all_valid);
+                    assert_eq!(validated_proofs.len(), count);
+                    for proof in validated_proofs {
+                        proof.drop();
+                    }
+                }
+                Err((proofs, error)) => {
+                    assert!(!all_valid);
+                    assert_eq!(
+                        error,
+                        ProofValidationError::InvalidResourceAddress(resource_address)
+                    );
+                    assert_eq!(proofs.len(), count);
+                    Proof::drop_all(proofs);
+                }
+            }
+        }
+
+        pub fn assert_contents_eq(proof: Proof, other_proof: Proof) {
+            let proof = proof.unsafe_skip_proof_validation();
+            let other_proof = other_proof.unsafe_skip_proof_validation();
+            assert!(proof != other_proof);
+            assert!(proof.contents_eq(&other_proof));
+            proof.drop();
+            other_proof.drop();
+        }
+
+        pub fn assert_if_proves(
+            proofs: Vec<Proof>,
+            resource_address: ResourceAddress,
+            expected_to_prove: bool,
+        ) {
+            let count = proofs.len();
+            for proof in proofs {
+                ComponentAuthZone::push(proof);
+            }
+
+            let proves = native_sdk::resource::ComponentAuthZone::sys_if_proves(
+                resource_address,
+                &mut ScryptoEnv,
+                || true,
+                || false,
+            )
+            .unwrap();
+            assert_eq!(proves, expected_to_prove);
+
+            // Checking provability must leave the auth zone untouched
+            assert_eq!(ComponentAuthZone::list().len(), count);
+            for _ in 0..count {
+                ComponentAuthZone::pop().drop();
+            }
+        }
+
+        pub fn assert_auth_zone_listing(
+            proofs: Vec<Proof>,
+            expected: Vec<(ResourceAddress, Decimal)>,
+        ) {
+            let count = proofs.len();
+            for proof in proofs {
+                ComponentAuthZone::push(proof);
+            }
+
+            // Listing the auth zone must not remove any of its proofs
+            assert_eq!(ComponentAuthZone::list(), expected);
+            assert_eq!(ComponentAuthZone::list(), expected);
+
+            for _ in 0..count {
+                ComponentAuthZone::pop().drop();
+            }
+        }
     }
 }
@@ -0,0 +1,28 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod role_check {
+    struct RoleCheck {}
+
+    impl RoleCheck {
+        pub fn satisfied_roles(
+            proof: Proof,
+            primary_role: AccessRule,
+            recovery_role: AccessRule,
+            confirmation_role: AccessRule,
+        ) -> Vec<String> {
+            let rule_set = RuleSet {
+                primary_role,
+                recovery_role,
+                confirmation_role,
+            };
+            let roles = proof
+                .satisfied_roles(&rule_set)
+                .into_iter()
+                .map(|role| format!("{:?}", role))
+                .collect();
+            proof.drop();
+            roles
+        }
+    }
+}
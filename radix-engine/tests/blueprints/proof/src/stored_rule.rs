@@ -0,0 +1,26 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod stored_rule {
+    struct StoredRule {
+        rules: KeyValueStore<String, AccessRule>,
+    }
+
+    impl StoredRule {
+        pub fn new(rule: AccessRule) -> ComponentAddress {
+            let rules = KeyValueStore::new();
+            rules.insert("withdraw".to_owned(), rule);
+            Self { rules }.instantiate().globalize()
+        }
+
+        pub fn assert_validates_against_stored_rule(&self, proof: Proof, is_satisfied: bool) {
+            let result = proof.validate_against_stored_rule(&self.rules, &"withdraw".to_owned());
+            if is_satisfied {
+                assert_eq!(result, Ok(()));
+            } else {
+                assert_eq!(result, Err(ProofValidationError::StoredRuleNotSatisfied));
+            }
+            proof.drop();
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use scrypto::engine::scrypto_env::ScryptoEnv;
 use scrypto::prelude::*;
 
 #[blueprint]
@@ -15,6 +16,14 @@ mod vault_proof {
             .globalize()
         }
 
+        pub fn new_empty(resource_address: ResourceAddress) -> ComponentAddress {
+            Self {
+                vault: Vault::new(resource_address),
+            }
+            .instantiate()
+            .globalize()
+        }
+
         pub fn create_clone_drop_vault_proof(&self, amount: Decimal) {
             let proof = self.vault.create_proof();
             let proof = proof.validate_proof(self.vault.resource_address()).unwrap();
@@ -62,6 +71,81 @@ mod vault_proof {
             proof.drop();
         }
 
+        pub fn create_validate_drop_presence_proof(&self) {
+            let proof = self.vault.create_presence_proof();
+            assert_eq!(
+                proof.validate_non_empty(),
+                Err(ProofValidationError::EmptyProof)
+            );
+            let proof = proof
+                .validate_proof(ProofValidationMode::ValidatePresence(
+                    self.vault.resource_address(),
+                ))
+                .unwrap();
+
+            assert_eq!(proof.amount(), Decimal::zero());
+
+            proof.drop();
+        }
+
+        pub fn create_validate_drop_auth_zone_presence_proof(&self) {
+            ComponentAuthZone::push(self.vault.create_proof());
+            let proof = ComponentAuthZone::create_presence_proof(self.vault.resource_address());
+            let proof = proof
+                .validate_proof(ProofValidationMode::ValidatePresence(
+                    self.vault.resource_address(),
+                ))
+                .unwrap();
+
+            assert_eq!(proof.amount(), Decimal::zero());
+
+            proof.drop();
+            ComponentAuthZone::pop().drop();
+        }
+
+        pub fn assert_presence_proof_rejected_by_other_modes(&self) {
+            let resource_address = self.vault.resource_address();
+            let proof = self.vault.create_presence_proof();
+            let results = proof.validate_all(&[
+                ProofValidationMode::ValidateResourceAddress(resource_address),
+                ProofValidationMode::ValidateResourceAddressBelongsTo(
+                    [resource_address].into_iter().collect(),
+                ),
+                ProofValidationMode::ValidateNotFrozen(resource_address),
+            ]);
+            assert!(results
+                .into_iter()
+                .all(|result| result == Err(ProofValidationError::PresenceProofNotAllowed)));
+
+            let (proof, error) = proof.validate_proof(resource_address).unwrap_err();
+            assert_eq!(error, ProofValidationError::PresenceProofNotAllowed);
+
+            proof.drop();
+        }
+
+        pub fn mint_with_presence_proof(&self, resource_address: ResourceAddress) -> Bucket {
+            let proof = self.vault.create_presence_proof();
+            let bucket = proof.authorize(|| borrow_resource_manager!(resource_address).mint(1));
+            proof.drop();
+            bucket
+        }
+
+        pub fn create_vault_proof_by_ids_with_native_sdk(&self, ids: BTreeSet<NonFungibleLocalId>) {
+            let proof = native_sdk::resource::Vault(self.vault.0)
+                .sys_create_proof_by_ids(&ids, &mut ScryptoEnv)
+                .unwrap();
+            let proof = proof
+                .validate_proof(ProofValidationMode::ValidateContainsNonFungibles(
+                    self.vault.resource_address(),
+                    ids.clone(),
+                ))
+                .unwrap();
+
+            assert_eq!(proof.non_fungible_local_ids(), ids);
+
+            proof.drop();
+        }
+
         pub fn use_vault_proof_for_auth(&self, to_burn: Bucket) {
             self.vault.authorize(|| {
                 to_burn.burn();
@@ -1,7 +1,9 @@
 use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
 use radix_engine_interface::model::FromPublicKey;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::TestTransaction;
 
 #[test]
 fn test_process_and_transaction() {
@@ -16,6 +18,28 @@ fn test_process_and_transaction() {
     receipt1.expect_commit_success();
 }
 
+#[test]
+fn test_transaction_hash_matches_executing_transaction() {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/core");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "CoreTest", "query", args![])
+        .build();
+    let transaction = TestTransaction::new(
+        manifest,
+        test_runner.next_transaction_nonce(),
+        DEFAULT_COST_UNIT_LIMIT,
+    );
+    let executable = transaction.get_executable(vec![]);
+    let expected_transaction_hash = executable.transaction_hash().clone();
+    let receipt = test_runner.execute_transaction(executable);
+
+    let (_, transaction_hash, _, _): (PackageAddress, Hash, u64, u128) = receipt.output(1);
+    assert_eq!(transaction_hash, expected_transaction_hash);
+}
+
 #[test]
 fn test_call() {
     let mut test_runner = TestRunner::builder().build();
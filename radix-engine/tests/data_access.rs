@@ -105,3 +105,80 @@ fn should_not_be_able_to_write_component_info() {
         )
     });
 }
+
+#[test]
+fn substate_write_batch_fails_as_a_whole_when_second_write_is_invalid() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/data_access");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "DataAccess",
+            "write_kv_store_entries_in_batch_with_read_only_second_lock",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::LockNotMutable(..))
+        )
+    });
+}
+
+#[test]
+fn should_be_able_to_read_nested_component_state_field() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/data_access");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "StateFieldAccess",
+            "read_nested_state_field",
+            args!(vec![1usize, 1usize]),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let field: String = receipt.output(1);
+    assert_eq!(field, "nested");
+}
+
+#[test]
+fn reading_component_state_field_with_invalid_path_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/data_access");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "StateFieldAccess",
+            "read_nested_state_field",
+            args!(vec![1usize, 2usize]),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::InvalidSubstateFieldPath(..))
+        )
+    });
+}
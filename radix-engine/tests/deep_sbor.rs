@@ -1,6 +1,7 @@
-use radix_engine::engine::{InterpreterError, KernelError, RuntimeError};
+use radix_engine::engine::{InterpreterError, RuntimeError};
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
+use radix_engine::wasm::WasmTrapKind;
 use radix_engine_interface::data::MAX_SCRYPTO_SBOR_DEPTH;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -40,7 +41,9 @@ fn deep_auth_rules_on_component_create_creation_fails() {
     receipt.expect_specific_failure(|f| {
         matches!(
             f,
-            RuntimeError::KernelError(KernelError::WasmRuntimeError(_))
+            RuntimeError::WasmTrap {
+                kind: WasmTrapKind::Unreachable
+            }
         )
     });
 
@@ -83,7 +86,9 @@ fn setting_struct_with_deep_recursive_data_panics_inside_component() {
     receipt.expect_specific_failure(|f| {
         matches!(
             f,
-            RuntimeError::KernelError(KernelError::WasmRuntimeError(_))
+            RuntimeError::WasmTrap {
+                kind: WasmTrapKind::Unreachable
+            }
         )
     });
 
@@ -1,9 +1,12 @@
 use radix_engine::engine::*;
 use radix_engine::model::*;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
 use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
 use radix_engine_interface::model::FromPublicKey;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::TestTransaction;
 
 #[test]
 fn test_trace_resource_transfers() {
@@ -174,6 +177,7 @@ fn test_instruction_traces() {
         .into_iter()
         .filter_map(|e| match e {
             TrackedEvent::SysCallTrace(trace) => Some(trace),
+            _ => None,
         })
         .collect();
 
@@ -343,6 +347,89 @@ fn test_instruction_traces() {
     }
 }
 
+#[test]
+fn test_substate_access_counts() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/execution_trace");
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "SubstateAccessTest", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    let component_address = receipt.new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "noop", args!())
+        .call_method(component_address, "read_two_write_one", args!())
+        .build();
+    let transaction = TestTransaction::new(
+        manifest,
+        test_runner.next_transaction_nonce(),
+        DEFAULT_COST_UNIT_LIMIT,
+    );
+    let mut execution_config = ExecutionConfig::default();
+    execution_config.count_substate_accesses = true;
+    let receipt = test_runner.execute_transaction_with_config(
+        transaction.get_executable(vec![]),
+        &FeeReserveConfig::default(),
+        &execution_config,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let counts_of = |ident: &str| {
+        receipt
+            .execution
+            .events
+            .iter()
+            .find_map(|e| match e {
+                TrackedEvent::SubstateAccessTrace(SubstateAccessTrace {
+                    actor:
+                        ResolvedActor {
+                            identifier: FnIdentifier::Scrypto(identifier),
+                            ..
+                        },
+                    counts,
+                    ..
+                }) if identifier.ident == ident => Some(*counts),
+                _ => None,
+            })
+            .unwrap()
+    };
+    // Both methods share the same overhead of being invoked, so any difference in their counts
+    // comes from the accesses made by the method body.
+    let noop_counts = counts_of("noop");
+    let counts = counts_of("read_two_write_one");
+    assert_eq!(counts.reads - noop_counts.reads, 2);
+    assert_eq!(counts.writes - noop_counts.writes, 1);
+}
+
+#[test]
+fn test_substate_access_counts_are_not_reported_by_default() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/execution_trace");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "SubstateAccessTest", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(!receipt
+        .execution
+        .events
+        .iter()
+        .any(|e| matches!(e, TrackedEvent::SubstateAccessTrace(..))));
+}
+
 fn traces_for_instruction(
     traces: &Vec<SysCallTrace>,
     instruction_index: u32,
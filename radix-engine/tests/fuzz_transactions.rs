@@ -29,6 +29,7 @@ fn execute_single_transaction(transaction: NotarizedTransaction) {
         wasm_engine: DefaultWasmEngine::default(),
         wasm_instrumenter: WasmInstrumenter::default(),
         wasm_metering_config: WasmMeteringConfig::V0,
+        fee_reserve_check: None,
     };
     let mut store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
     let execution_config = ExecutionConfig::default();
@@ -91,6 +91,37 @@ fn overflow_return_len_should_cause_memory_access_error() {
     });
 }
 
+#[test]
+fn out_of_bounds_memory_access_should_cause_memory_trap() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let code = wat2wasm(include_str!("wasm/memory_trap.wat"));
+    let package_address = test_runner.publish_package(
+        code,
+        generate_single_function_abi("Test", "f", Type::Any),
+        BTreeMap::new(),
+        BTreeMap::new(),
+        AccessRules::new(),
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "Test", "f", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::WasmTrap {
+                kind: WasmTrapKind::MemoryAccessOutOfBounds
+            }
+        )
+    });
+}
+
 #[test]
 fn zero_return_len_should_cause_data_validation_error() {
     // Arrange
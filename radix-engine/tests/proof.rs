@@ -1,5 +1,6 @@
 use radix_engine::engine::node_move_module::NodeMoveError;
-use radix_engine::engine::{ModuleError, RuntimeError};
+use radix_engine::engine::{ApplicationError, AuthError, ModuleError, RuntimeError};
+use radix_engine::model::{AuthZoneError, ProofError, VaultError};
 use radix_engine::types::*;
 use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::model::FromPublicKey;
@@ -44,6 +45,196 @@ fn can_create_clone_and_drop_bucket_proof() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn can_create_and_push_bucket_proof() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 0, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 3.into(), resource_address)
+        .take_from_worktop(resource_address, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "BucketProof",
+                "create_and_push_bucket_proof",
+                args!(bucket_id, dec!("3")),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    println!("{}", receipt.display(&Bech32Encoder::for_simulator()));
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cant_push_one_shot_proof_onto_auth_zone_twice() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), resource_address)
+        .take_from_worktop(resource_address, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "BucketProof",
+                "push_one_shot_bucket_proof_twice",
+                args!(bucket_id),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::ProofError(ProofError::OneShotProofAlreadyUsed)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cant_clone_one_shot_proof() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), resource_address)
+        .take_from_worktop(resource_address, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "BucketProof",
+                "clone_one_shot_bucket_proof",
+                args!(bucket_id),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ProofError(
+                ProofError::OneShotProofCannotBeCloned
+            ))
+        )
+    });
+}
+
+#[test]
+fn can_drop_one_bucket_proof_while_others_remain_usable() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), resource_address)
+        .take_from_worktop(resource_address, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "BucketProof",
+                "drop_one_of_many_bucket_proofs",
+                args!(bucket_id, dec!("1")),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_drop_several_bucket_proofs_in_one_batch() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 0, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), resource_address)
+        .take_from_worktop(resource_address, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "BucketProof",
+                "drop_bucket_proofs_in_batch",
+                args!(bucket_id),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
 #[test]
 fn can_create_clone_and_drop_vault_proof() {
     // Arrange
@@ -152,6 +343,43 @@ fn can_create_clone_and_drop_vault_proof_by_ids() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn can_create_vault_proof_by_ids_with_native_sdk() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let component_address = test_runner.instantiate_component(
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        |builder| {
+            builder
+                .withdraw_from_account_by_amount(account, 3.into(), resource_address)
+                .take_from_worktop(resource_address, |builder, bucket_id| {
+                    builder.call_function(package_address, "VaultProof", "new", args!(bucket_id))
+                })
+        },
+    );
+
+    // Act
+    let proof_ids = BTreeSet::from([
+        NonFungibleLocalId::integer(1),
+        NonFungibleLocalId::integer(3),
+    ]);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "create_vault_proof_by_ids_with_native_sdk",
+            args!(proof_ids),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
 #[test]
 fn can_use_bucket_for_authorization() {
     // Arrange
@@ -565,3 +793,1257 @@ fn can_create_auth_zone_proof_by_amount_from_non_fungibles() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn proof_validation_query_hook_reports_queries_of_composite_validation_mode() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_ids(
+            account,
+            &BTreeSet::from([NonFungibleLocalId::integer(1)]),
+            resource_address,
+        )
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_validation_queries",
+                args!(
+                    proof_id,
+                    BTreeSet::from([NonFungibleLocalId::integer(1)]),
+                    resource_address
+                ),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn validated_proof_reads_resource_address_from_engine_only_once() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let mut execute_with_checks = |n: u32| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10u32.into())
+            .create_proof_from_account_by_amount(account, 1u32.into(), resource_address)
+            .pop_from_auth_zone(|builder, proof_id| {
+                builder.call_function(
+                    package_address,
+                    "Receiver",
+                    "assert_contains_n_times",
+                    args!(proof_id, resource_address, n),
+                )
+            })
+            .build();
+        test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+    };
+
+    // Act
+    let receipt_single_check = execute_with_checks(1);
+    let receipt_many_checks = execute_with_checks(3);
+
+    // Assert
+    receipt_single_check.expect_commit_success();
+    receipt_many_checks.expect_commit_success();
+    assert_eq!(
+        receipt_single_check
+            .execution
+            .fee_summary
+            .execution_cost_unit_breakdown["invoke"],
+        receipt_many_checks
+            .execution
+            .fee_summary
+            .execution_cost_unit_breakdown["invoke"]
+    );
+}
+
+#[test]
+fn validate_into_parts_returns_resource_address_and_amount_of_proof() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, 5u32.into(), resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_validated_parts",
+                args!(proof_id, resource_address, Decimal::from(5u32)),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn validate_into_parts_does_not_read_proof_again_after_validating() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let mut execute_validation = |into_parts: bool| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10u32.into())
+            .create_proof_from_account_by_amount(account, 5u32.into(), resource_address)
+            .pop_from_auth_zone(|builder, proof_id| {
+                builder.call_function(
+                    package_address,
+                    "Receiver",
+                    "validate_contains_amount",
+                    args!(proof_id, resource_address, Decimal::from(5u32), into_parts),
+                )
+            })
+            .build();
+        test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+    };
+
+    // Act
+    let receipt_validate = execute_validation(false);
+    let receipt_into_parts = execute_validation(true);
+
+    // Assert
+    receipt_validate.expect_commit_success();
+    receipt_into_parts.expect_commit_success();
+    assert_eq!(
+        receipt_validate
+            .execution
+            .fee_summary
+            .execution_cost_unit_breakdown["invoke"],
+        receipt_into_parts
+            .execution
+            .fee_summary
+            .execution_cost_unit_breakdown["invoke"]
+    );
+}
+
+#[test]
+fn validate_with_excess_of_exactly_enough_proof_has_no_excess() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, 5u32.into(), resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_excess",
+                args!(
+                    proof_id,
+                    resource_address,
+                    Decimal::from(5u32),
+                    Decimal::from(0u32)
+                ),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn validate_with_excess_of_more_than_enough_proof_returns_excess() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, 8u32.into(), resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_excess",
+                args!(
+                    proof_id,
+                    resource_address,
+                    Decimal::from(5u32),
+                    Decimal::from(3u32)
+                ),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn amount_with_more_decimal_places_than_divisibility_fails_validation() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100u32.into(), 2, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, 5u32.into(), resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_amount_divisibility",
+                args!(proof_id, resource_address, dec!("1.25"), dec!("1.001")),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_validate_proof_against_non_fungible_global_id() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let non_fungible_global_id =
+        NonFungibleGlobalId::new(resource_address, NonFungibleLocalId::integer(1));
+    let other_non_fungible_global_id =
+        NonFungibleGlobalId::new(resource_address, NonFungibleLocalId::integer(2));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_ids(
+            account,
+            &BTreeSet::from([NonFungibleLocalId::integer(1)]),
+            resource_address,
+        )
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_contains_global_id",
+                args!(
+                    proof_id,
+                    non_fungible_global_id,
+                    other_non_fungible_global_id
+                ),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn resource_proof_hides_non_fungible_ids_but_keeps_resource_and_amount() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_ids(
+            account,
+            &BTreeSet::from([
+                NonFungibleLocalId::integer(1),
+                NonFungibleLocalId::integer(2),
+            ]),
+            resource_address,
+        )
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_resource_proof_hides_ids",
+                args!(proof_id, resource_address, Decimal::from(2)),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn distinct_proofs_of_identical_contents_have_equal_contents() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let non_fungible_local_ids = BTreeSet::from([
+        NonFungibleLocalId::integer(1),
+        NonFungibleLocalId::integer(2),
+    ]);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_ids(account, &non_fungible_local_ids, resource_address)
+        .create_proof_from_account_by_ids(account, &non_fungible_local_ids, resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.pop_from_auth_zone(|builder, other_proof_id| {
+                builder.call_function(
+                    package_address,
+                    "Receiver",
+                    "assert_contents_eq",
+                    args!(proof_id, other_proof_id),
+                )
+            })
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn proof_of_resource_held_by_access_controller_validates_as_controlled_by_it() {
+    validate_proof_against_access_controller(true);
+}
+
+#[test]
+fn proof_of_other_resource_fails_validation_as_controlled_by_access_controller() {
+    validate_proof_against_access_controller(false);
+}
+
+fn validate_proof_against_access_controller(is_controlled: bool) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let controlled_resource_address = test_runner.create_fungible_resource(2.into(), 0, account);
+    let other_resource_address = test_runner.create_fungible_resource(1.into(), 0, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .withdraw_from_account_by_amount(account, 1.into(), controlled_resource_address)
+        .take_from_worktop(controlled_resource_address, |builder, bucket_id| {
+            builder.create_access_controller(
+                bucket_id,
+                rule!(allow_all),
+                rule!(allow_all),
+                rule!(allow_all),
+                None,
+                AccessControllerSettings::default(),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+    let access_controller = receipt.new_component_addresses()[0];
+
+    // Act
+    let proof_resource_address = if is_controlled {
+        controlled_resource_address
+    } else {
+        other_resource_address
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account(account, proof_resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_controlled_by",
+                args!(proof_id, access_controller, is_controlled),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_validate_non_empty_proof_of_positive_amount() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, 1u32.into(), resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_validated_non_empty",
+                args!(proof_id, resource_address),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn zero_amount_proof_cannot_reach_non_empty_validation() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, Decimal::zero(), resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_validated_non_empty",
+                args!(proof_id, resource_address),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::ProofError(
+                ProofError::EmptyProofNotAllowed
+            )))
+        )
+    });
+}
+
+#[test]
+fn validate_all_reports_every_failing_validation_mode() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let other_resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, 5u32.into(), resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_validate_all_reports_every_failure",
+                args!(proof_id, resource_address, other_resource_address),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn min_non_fungible_count_validation_compares_distinct_ids_against_threshold() {
+    // Fewer, equal, and more ids than the threshold
+    for required in [3usize, 2, 1] {
+        // Arrange
+        let mut test_runner = TestRunner::builder().build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+        let resource_address = test_runner.create_non_fungible_resource(account);
+        let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+        // Act
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10u32.into())
+            .create_proof_from_account_by_ids(
+                account,
+                &BTreeSet::from([
+                    NonFungibleLocalId::integer(1),
+                    NonFungibleLocalId::integer(2),
+                ]),
+                resource_address,
+            )
+            .pop_from_auth_zone(|builder, proof_id| {
+                builder.call_function(
+                    package_address,
+                    "Receiver",
+                    "assert_min_non_fungible_count",
+                    args!(proof_id, resource_address, required, 2usize),
+                )
+            })
+            .build();
+        let receipt = test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        );
+
+        // Assert
+        receipt.expect_commit_success();
+    }
+}
+
+fn create_fungible_resource_with_symbol(
+    test_runner: &mut TestRunner,
+    symbol: &str,
+    account: ComponentAddress,
+) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .new_token_fixed(
+            BTreeMap::from([("symbol".to_owned(), symbol.to_owned())]),
+            100u32.into(),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0]
+}
+
+fn test_amount_with_symbol_validation(
+    symbol: &str,
+    amount: Decimal,
+    symbol_matches: bool,
+    amount_sufficient: bool,
+) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_fungible_resource_with_symbol(&mut test_runner, "TKN", account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, 5u32.into(), resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_amount_with_symbol",
+                args!(
+                    proof_id,
+                    symbol.to_owned(),
+                    amount,
+                    symbol_matches,
+                    amount_sufficient
+                ),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn amount_with_symbol_validation_accepts_matching_symbol_and_sufficient_amount() {
+    test_amount_with_symbol_validation("TKN", 5u32.into(), true, true);
+}
+
+#[test]
+fn amount_with_symbol_validation_reports_symbol_mismatch() {
+    test_amount_with_symbol_validation("XRD", 5u32.into(), false, true);
+}
+
+#[test]
+fn amount_with_symbol_validation_reports_amount_shortfall() {
+    test_amount_with_symbol_validation("TKN", 6u32.into(), true, false);
+}
+
+#[test]
+fn amount_with_symbol_validation_reports_both_failures() {
+    test_amount_with_symbol_validation("XRD", 6u32.into(), false, false);
+}
+
+fn test_validation_against_stored_rule(is_satisfied: bool) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let required_resource_address = test_runner.create_fungible_resource(1.into(), 0, account);
+    let other_resource_address = test_runner.create_fungible_resource(1.into(), 0, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_function(
+            package_address,
+            "StoredRule",
+            "new",
+            args!(rule!(require(required_resource_address))),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    let component_address = receipt.new_component_addresses()[0];
+
+    // Act
+    let proof_resource_address = if is_satisfied {
+        required_resource_address
+    } else {
+        other_resource_address
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account(account, proof_resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_method(
+                component_address,
+                "assert_validates_against_stored_rule",
+                args!(proof_id, is_satisfied),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn proof_satisfying_stored_rule_passes_validation() {
+    test_validation_against_stored_rule(true);
+}
+
+#[test]
+fn proof_not_satisfying_stored_rule_fails_validation() {
+    test_validation_against_stored_rule(false);
+}
+
+fn test_satisfied_roles<F>(rule_set: F, expected_roles: Vec<&str>)
+where
+    F: FnOnce(ResourceAddress, ResourceAddress) -> RuleSet,
+{
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let proven_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let other_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let rule_set = rule_set(proven_badge, other_badge);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account(account, proven_badge)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "RoleCheck",
+                "satisfied_roles",
+                args!(
+                    proof_id,
+                    rule_set.primary_role,
+                    rule_set.recovery_role,
+                    rule_set.confirmation_role
+                ),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let satisfied_roles: Vec<String> = receipt.output(3);
+    assert_eq!(satisfied_roles, expected_roles);
+}
+
+#[test]
+fn proof_satisfying_only_primary_role_maps_to_primary() {
+    test_satisfied_roles(
+        |proven_badge, other_badge| RuleSet {
+            primary_role: rule!(require(proven_badge)),
+            recovery_role: rule!(require(other_badge)),
+            confirmation_role: rule!(require(other_badge)),
+        },
+        vec!["Primary"],
+    );
+}
+
+#[test]
+fn proof_satisfying_only_recovery_role_maps_to_recovery() {
+    test_satisfied_roles(
+        |proven_badge, other_badge| RuleSet {
+            primary_role: rule!(require(other_badge)),
+            recovery_role: rule!(require(proven_badge)),
+            confirmation_role: rule!(require(other_badge)),
+        },
+        vec!["Recovery"],
+    );
+}
+
+#[test]
+fn proof_satisfying_several_roles_maps_to_all_of_them() {
+    test_satisfied_roles(
+        |proven_badge, other_badge| RuleSet {
+            primary_role: rule!(require(proven_badge)),
+            recovery_role: rule!(require(other_badge)),
+            confirmation_role: rule!(require(proven_badge) || require(other_badge)),
+        },
+        vec!["Primary", "Confirmation"],
+    );
+}
+
+fn test_resource_behavior_validation(
+    resource_address: ResourceAddress,
+    behavior: BehaviorFlags,
+    missing: BehaviorFlags,
+    test_runner: &mut TestRunner,
+    public_key: EcdsaSecp256k1PublicKey,
+    account: ComponentAddress,
+) {
+    // Arrange
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account(account, resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_resource_behavior",
+                args!(proof_id, resource_address, behavior, missing),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn proof_of_recallable_resource_validates_as_recallable() {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_recallable_token(account);
+
+    test_resource_behavior_validation(
+        resource_address,
+        BehaviorFlags::RECALLABLE,
+        BehaviorFlags::empty(),
+        &mut test_runner,
+        public_key,
+        account,
+    );
+}
+
+#[test]
+fn proof_of_non_recallable_resource_fails_validation_as_recallable() {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(1.into(), 0, account);
+
+    test_resource_behavior_validation(
+        resource_address,
+        BehaviorFlags::RECALLABLE,
+        BehaviorFlags::RECALLABLE,
+        &mut test_runner,
+        public_key,
+        account,
+    );
+}
+
+#[test]
+fn behavior_mismatch_reports_only_the_missing_behaviors() {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, resource_address) = test_runner.create_restricted_transfer_token(account);
+
+    test_resource_behavior_validation(
+        resource_address,
+        BehaviorFlags::RESTRICTED_WITHDRAW | BehaviorFlags::MINTABLE,
+        BehaviorFlags::MINTABLE,
+        &mut test_runner,
+        public_key,
+        account,
+    );
+}
+
+fn test_matches_vault_validation(matches: bool) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 0, account);
+    let vault_resource_address = if matches {
+        resource_address
+    } else {
+        test_runner.create_fungible_resource(100.into(), 0, account)
+    };
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account(account, resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_matches_vault",
+                args!(proof_id, vault_resource_address, matches),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn proof_of_vault_resource_passes_matches_vault_validation() {
+    test_matches_vault_validation(true);
+}
+
+#[test]
+fn proof_of_other_resource_fails_matches_vault_validation() {
+    test_matches_vault_validation(false);
+}
+
+fn test_not_frozen_validation(
+    resource_address: ResourceAddress,
+    is_frozen: bool,
+    test_runner: &mut TestRunner,
+    public_key: EcdsaSecp256k1PublicKey,
+    account: ComponentAddress,
+) {
+    // Arrange
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account(account, resource_address)
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_not_frozen",
+                args!(proof_id, resource_address, is_frozen),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn proof_of_frozen_resource_fails_not_frozen_validation() {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, resource_address) = test_runner.create_frozen_token(account);
+
+    test_not_frozen_validation(
+        resource_address,
+        true,
+        &mut test_runner,
+        public_key,
+        account,
+    );
+}
+
+#[test]
+fn proof_of_withdrawable_resource_passes_not_frozen_validation() {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(1.into(), 0, account);
+
+    test_not_frozen_validation(
+        resource_address,
+        false,
+        &mut test_runner,
+        public_key,
+        account,
+    );
+}
+
+fn test_non_fungible_data_field_validation(expected_tier: &str, matches: bool) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_function(
+            package_address,
+            "Receiver",
+            "assert_non_fungible_data_field",
+            args!(expected_tier.to_string(), matches),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn proof_of_non_fungibles_with_matching_data_field_passes_validation() {
+    test_non_fungible_data_field_validation("gold", true);
+}
+
+#[test]
+fn proof_of_non_fungibles_with_other_data_field_value_fails_validation() {
+    test_non_fungible_data_field_validation("silver", false);
+}
+
+fn test_proof_set_amount_validation(min_total: Decimal) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!("100"), 18, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, dec!("3"), resource_address)
+        .create_proof_from_account_by_amount(account, dec!("1"), RADIX_TOKEN)
+        .create_proof_from_account_by_amount(account, dec!("4"), resource_address)
+        .call_function(
+            package_address,
+            "Receiver",
+            "assert_proof_set_amount",
+            args!(
+                ManifestExpression::EntireAuthZone,
+                resource_address,
+                min_total,
+                dec!("7")
+            ),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn proof_set_summing_to_just_enough_passes_amount_validation() {
+    test_proof_set_amount_validation(dec!("7"));
+}
+
+#[test]
+fn proof_set_summing_to_not_enough_fails_amount_validation() {
+    test_proof_set_amount_validation(dec!("7.000000000000000001"));
+}
+
+fn test_all_proofs_validation(all_valid: bool) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!("100"), 18, account);
+    let other_resource_address = if all_valid {
+        resource_address
+    } else {
+        RADIX_TOKEN
+    };
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_proof_from_account_by_amount(account, dec!("3"), resource_address)
+        .create_proof_from_account_by_amount(account, dec!("1"), other_resource_address)
+        .call_function(
+            package_address,
+            "Receiver",
+            "assert_all_proofs_validate",
+            args!(
+                ManifestExpression::EntireAuthZone,
+                resource_address,
+                all_valid
+            ),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn proofs_all_of_the_resource_are_all_validated() {
+    test_all_proofs_validation(true);
+}
+
+#[test]
+fn proofs_with_one_of_another_resource_are_all_given_back() {
+    test_all_proofs_validation(false);
+}
+
+#[test]
+fn can_create_and_validate_presence_proof_of_empty_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(1.into(), 0, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let component_address = test_runner.instantiate_component(vec![], |builder| {
+        builder.call_function(
+            package_address,
+            "VaultProof",
+            "new_empty",
+            args!(resource_address),
+        )
+    });
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "create_validate_drop_presence_proof",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_create_and_validate_presence_proof_from_auth_zone() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let component_address = test_runner.instantiate_component(
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        |builder| {
+            builder
+                .withdraw_from_account_by_amount(account, 1.into(), resource_address)
+                .take_from_worktop(resource_address, |builder, bucket_id| {
+                    builder.call_function(package_address, "VaultProof", "new", args!(bucket_id))
+                })
+        },
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "create_validate_drop_auth_zone_presence_proof",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn presence_proof_is_rejected_by_validation_modes_other_than_presence() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(1.into(), 0, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let component_address = test_runner.instantiate_component(vec![], |builder| {
+        builder.call_function(
+            package_address,
+            "VaultProof",
+            "new_empty",
+            args!(resource_address),
+        )
+    });
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "assert_presence_proof_rejected_by_other_modes",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn presence_proof_does_not_satisfy_access_rule() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (token_address, mint_auth, _, _, _, _, _) = test_runner.create_restricted_token(account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let component_address = test_runner.instantiate_component(vec![], |builder| {
+        builder.call_function(package_address, "VaultProof", "new_empty", args!(mint_auth))
+    });
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "mint_with_presence_proof",
+            args!(token_address),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
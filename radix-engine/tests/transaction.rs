@@ -172,6 +172,85 @@ fn test_entire_auth_zone() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_list_auth_zone() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!("100"), 18, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, dec!("10"))
+        .create_proof_from_account_by_amount(account, dec!("1"), RADIX_TOKEN)
+        .create_proof_from_account_by_amount(account, dec!("5"), resource_address)
+        .call_function(
+            package_address,
+            "Receiver",
+            "assert_auth_zone_listing",
+            args!(
+                ManifestExpression::EntireAuthZone,
+                vec![(RADIX_TOKEN, dec!("1")), (resource_address, dec!("5"))]
+            ),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_if_proves_runs_then_branch_when_auth_zone_can_prove_resource() {
+    test_if_proves(true);
+}
+
+#[test]
+fn test_if_proves_runs_else_branch_when_auth_zone_can_not_prove_resource() {
+    test_if_proves(false);
+}
+
+fn test_if_proves(expected_to_prove: bool) {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!("100"), 18, account);
+    let other_resource_address = test_runner.create_fungible_resource(dec!("100"), 18, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let proven_resource_address = if expected_to_prove {
+        resource_address
+    } else {
+        other_resource_address
+    };
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, dec!("10"))
+        .create_proof_from_account_by_amount(account, dec!("5"), proven_resource_address)
+        .call_function(
+            package_address,
+            "Receiver",
+            "assert_if_proves",
+            args!(
+                ManifestExpression::EntireAuthZone,
+                resource_address,
+                expected_to_prove
+            ),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
 #[test]
 fn test_faucet_drain_attempt_should_fail() {
     // Arrange
@@ -0,0 +1,16 @@
+(module
+
+  ;; Function which reads past the end of its single page of memory
+  (func $Test_f (param $0 i64) (result i64)
+    (drop
+      (i32.load (i32.const 131072))
+    )
+
+    ;; Return slice (ptr = 0, len = 3)
+    (i64.const 3)
+  )
+
+  (memory $0 1)
+  (export "memory" (memory $0))
+  (export "Test_f" (func $Test_f))
+)
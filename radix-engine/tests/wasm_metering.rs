@@ -1,4 +1,8 @@
+use radix_engine::engine::{ModuleError, RejectionError, RuntimeError, ScryptoInterpreter};
+use radix_engine::fee::FeeReserveError;
+use radix_engine::model::CostingError;
 use radix_engine::types::*;
+use radix_engine::wasm::DefaultWasmEngine;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
 
@@ -184,3 +188,83 @@ fn test_grow_memory_out_of_cost_unit() {
     // Assert
     receipt.expect_specific_failure(is_costing_error)
 }
+
+fn abort_below_ten_million_cost_units(remaining_cost_units: u32) -> Result<(), FeeReserveError> {
+    if remaining_cost_units < 10_000_000 {
+        Err(FeeReserveError::LimitExceeded)
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_fee_reserve_check_aborts_wasm_invocation_below_threshold() {
+    // Arrange
+    let mut test_runner = TestRunner::builder()
+        .with_fee_reserve_check(abort_below_ten_million_cost_units)
+        .build();
+    let code = wat2wasm(&include_str!("wasm/loop.wat").replace("${n}", "1000"));
+    let package_address = test_runner.publish_package(
+        code,
+        generate_single_function_abi(
+            "Test",
+            "f",
+            Type::Tuple {
+                element_types: vec![],
+            },
+        ),
+        BTreeMap::new(),
+        BTreeMap::new(),
+        AccessRules::new(),
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "Test", "f", args!())
+        .build();
+
+    // Act
+    let above_threshold_receipt =
+        test_runner.execute_manifest_with_cost_unit_limit(manifest.clone(), vec![], 15_000_000);
+    let below_threshold_receipt =
+        test_runner.execute_manifest_with_cost_unit_limit(manifest, vec![], 5_000_000);
+
+    // Assert
+    above_threshold_receipt.expect_commit_success();
+    below_threshold_receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::ErrorBeforeFeeLoanRepaid(RuntimeError::ModuleError(
+                ModuleError::CostingError(CostingError::FeeReserveError(
+                    FeeReserveError::LimitExceeded
+                ))
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_instrumentation_stats_of_small_module() {
+    // Arrange
+    let code = wat2wasm(&include_str!("wasm/loop.wat").replace("${n}", "1000"));
+    let scrypto_interpreter = ScryptoInterpreter::<DefaultWasmEngine>::default();
+
+    // Act
+    let stats = scrypto_interpreter.instrumentation_stats(&code);
+    let (instrumented_code, stats_with_code) = scrypto_interpreter
+        .wasm_instrumenter
+        .instrument_with_stats(&code, scrypto_interpreter.wasm_metering_config.parameters());
+
+    // Assert
+    assert!(stats.injected_instruction_metering_instructions > 0);
+    assert!(stats.injected_stack_metering_instructions > 0);
+    assert!(stats.size_delta() > 0);
+    assert_eq!(stats.original_size, code.len());
+    assert_eq!(stats.instrumented_size, instrumented_code.len());
+    assert_eq!(stats, stats_with_code);
+    assert_eq!(
+        instrumented_code,
+        scrypto_interpreter
+            .wasm_instrumenter
+            .instrument_no_cache(&code, scrypto_interpreter.wasm_metering_config.parameters())
+    );
+}
@@ -39,6 +39,7 @@ pub fn handle_describe(
             syn::Fields::Named(FieldsNamed { named, .. }) => {
                 let unskipped_fields: Vec<&Field> =
                     named.iter().filter(|f| !is_encoding_skipped(f)).collect();
+                let deprecation = build_deprecation_modifiers(&attrs, &unskipped_fields);
                 let field_types: Vec<_> = unskipped_fields.iter().map(|f| &f.ty).collect();
                 let unique_field_types: Vec<_> = get_unique_types(&field_types);
                 let field_names: Vec<_> = unskipped_fields
@@ -74,7 +75,7 @@ pub fn handle_describe(
                                 ::sbor::rust::vec![
                                     #((#field_names, <#field_types as ::sbor::Describe<#custom_type_kind_generic>>::TYPE_ID),)*
                                 ],
-                            ))
+                            )#deprecation)
                         }
 
                         fn add_all_dependencies(aggregator: &mut ::sbor::TypeAggregator<#custom_type_kind_generic>) {
@@ -86,6 +87,7 @@ pub fn handle_describe(
             syn::Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
                 let unskipped_fields: Vec<&Field> =
                     unnamed.iter().filter(|f| !is_encoding_skipped(f)).collect();
+                let deprecation = build_deprecation_modifiers(&attrs, &unskipped_fields);
                 let field_types: Vec<_> = unskipped_fields.iter().map(|f| &f.ty).collect();
                 let unique_field_types: Vec<_> = get_unique_types(&field_types);
 
@@ -113,7 +115,7 @@ pub fn handle_describe(
                                 ::sbor::rust::vec![
                                     #(<#field_types as ::sbor::Describe<#custom_type_kind_generic>>::TYPE_ID,)*
                                 ],
-                            ))
+                            )#deprecation)
                         }
 
                         fn add_all_dependencies(aggregator: &mut ::sbor::TypeAggregator<#custom_type_kind_generic>) {
@@ -123,6 +125,7 @@ pub fn handle_describe(
                 }
             }
             syn::Fields::Unit => {
+                let deprecation = build_deprecation_modifiers(&attrs, &[]);
                 quote! {
                     impl #impl_generics ::sbor::Describe <#custom_type_kind_generic> for #ident #ty_generics #where_clause {
                         const TYPE_ID: ::sbor::GlobalTypeId = ::sbor::GlobalTypeId::novel_with_code(
@@ -132,7 +135,7 @@ pub fn handle_describe(
                         );
 
                         fn type_data() -> Option<::sbor::TypeData<#custom_type_kind_generic, ::sbor::GlobalTypeId>> {
-                            Some(::sbor::TypeData::named_unit(stringify!(#ident)))
+                            Some(::sbor::TypeData::named_unit(stringify!(#ident))#deprecation)
                         }
                     }
                 }
@@ -143,6 +146,7 @@ pub fn handle_describe(
                 .len()
                 .try_into()
                 .expect("Too many variants in enum");
+            // Variant indices follow declaration order, consistent with the Encode derive.
             let variant_indices: Vec<u8> = (0..n).into_iter().collect();
             let mut all_field_types = Vec::new();
 
@@ -155,6 +159,8 @@ pub fn handle_describe(
                             Fields::Named(FieldsNamed { named, .. }) => {
                                 let unskipped_fields: Vec<&Field> =
                                     named.iter().filter(|f| !is_encoding_skipped(f)).collect();
+                                let deprecation =
+                                    build_deprecation_modifiers(&v.attrs, &unskipped_fields);
                                 let field_types: Vec<_> =
                                     unskipped_fields.iter().map(|f| &f.ty).collect();
                                 all_field_types.extend_from_slice(&field_types);
@@ -173,12 +179,14 @@ pub fn handle_describe(
                                         ::sbor::rust::vec![
                                             #((#field_names, <#field_types as ::sbor::Describe<#custom_type_kind_generic>>::TYPE_ID),)*
                                         ],
-                                    )
+                                    )#deprecation
                                 }
                             }
                             Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
                                 let unskipped_fields: Vec<&Field> =
                                     unnamed.iter().filter(|f| !is_encoding_skipped(f)).collect();
+                                let deprecation =
+                                    build_deprecation_modifiers(&v.attrs, &unskipped_fields);
                                 let field_types: Vec<_> =
                                     unskipped_fields.iter().map(|f| &f.ty).collect();
                                 all_field_types.extend_from_slice(&field_types);
@@ -188,12 +196,13 @@ pub fn handle_describe(
                                         ::sbor::rust::vec![
                                             #(<#field_types as ::sbor::Describe<#custom_type_kind_generic>>::TYPE_ID,)*
                                         ],
-                                    )
+                                    )#deprecation
                                 }
                             }
                             Fields::Unit => {
+                                let deprecation = build_deprecation_modifiers(&v.attrs, &[]);
                                 quote! {
-                                    ::sbor::TypeData::named_unit(#variant_name)
+                                    ::sbor::TypeData::named_unit(#variant_name)#deprecation
                                 }
                             }
                         }
@@ -202,6 +211,7 @@ pub fn handle_describe(
             };
 
             let unique_field_types: Vec<_> = get_unique_types(&all_field_types);
+            let deprecation = build_deprecation_modifiers(&attrs, &[]);
 
             quote! {
                 impl #impl_generics ::sbor::Describe <#custom_type_kind_generic> for #ident #ty_generics #where_clause {
@@ -218,7 +228,7 @@ pub fn handle_describe(
                             ::sbor::rust::collections::btree_map::btreemap![
                                 #(#variant_indices => #variant_type_data,)*
                             ],
-                        ))
+                        )#deprecation)
                     }
 
                     fn add_all_dependencies(aggregator: &mut ::sbor::TypeAggregator<#custom_type_kind_generic>) {
@@ -239,6 +249,28 @@ pub fn handle_describe(
     Ok(output)
 }
 
+/// Builds the calls marking the described type or variant, and any of its fields, as deprecated,
+/// following `#[sbor(deprecated)]` annotations. Nothing is generated for types without any, so
+/// their `Describe` implementations are unchanged.
+fn build_deprecation_modifiers(attrs: &[Attribute], fields: &[&Field]) -> TokenStream {
+    let mut modifiers = TokenStream::new();
+    if is_deprecated(attrs) {
+        modifiers.extend(quote! { .deprecated() });
+    }
+    let deprecated_field_indices: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| is_deprecated(&f.attrs))
+        .map(|(index, _)| index)
+        .collect();
+    if !deprecated_field_indices.is_empty() {
+        modifiers.extend(quote! {
+            .with_deprecated_fields(&[#(#deprecated_field_indices,)*])
+        });
+    }
+    modifiers
+}
+
 #[cfg(test)]
 mod tests {
     use proc_macro2::TokenStream;
@@ -75,7 +75,22 @@ pub fn extract_attributes(
     None
 }
 
+/// Returns true if the field is a `PhantomData` marker. Such fields carry no data, so they are
+/// skipped when encoding, decoding and describing, as if annotated with `#[sbor(skip)]`.
+pub fn is_phantom_data(f: &Field) -> bool {
+    match &f.ty {
+        Type::Path(TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
 pub fn is_decoding_skipped(f: &Field) -> bool {
+    if is_phantom_data(f) {
+        return true;
+    }
     if let Some(fields) = extract_attributes(&f.attrs, "sbor") {
         fields.contains_key("skip") || fields.contains_key("skip_decode")
     } else {
@@ -84,6 +99,9 @@ pub fn is_decoding_skipped(f: &Field) -> bool {
 }
 
 pub fn is_encoding_skipped(f: &Field) -> bool {
+    if is_phantom_data(f) {
+        return true;
+    }
     if let Some(fields) = extract_attributes(&f.attrs, "sbor") {
         fields.contains_key("skip") || fields.contains_key("skip_encode")
     } else {
@@ -92,6 +110,9 @@ pub fn is_encoding_skipped(f: &Field) -> bool {
 }
 
 pub fn is_describing_skipped(f: &Field) -> bool {
+    if is_phantom_data(f) {
+        return true;
+    }
     if let Some(fields) = extract_attributes(&f.attrs, "sbor") {
         fields.contains_key("skip") || fields.contains_key("skip_describe")
     } else {
@@ -99,6 +120,15 @@ pub fn is_describing_skipped(f: &Field) -> bool {
     }
 }
 
+/// Returns true if the type, variant or field is annotated with `#[sbor(deprecated)]`.
+pub fn is_deprecated(attributes: &[Attribute]) -> bool {
+    if let Some(fields) = extract_attributes(attributes, "sbor") {
+        fields.contains_key("deprecated")
+    } else {
+        false
+    }
+}
+
 pub fn get_custom_value_kind(attributes: &[Attribute]) -> Option<String> {
     if let Some(fields) = extract_attributes(attributes, "sbor") {
         fields.get("custom_value_kind").cloned().unwrap_or_default()
@@ -25,6 +25,10 @@ pub fn decode(input: TokenStream) -> TokenStream {
 }
 
 /// Derive code that describes the SBOR type.
+///
+/// Enum variants are described in source declaration order: each variant's index is its position
+/// in the enum definition (matching the discriminator written by `Encode`), never an order derived
+/// from the variant names. Reordering variants is therefore a breaking change to the schema.
 #[proc_macro_derive(Describe, attributes(sbor))]
 pub fn describe(input: TokenStream) -> TokenStream {
     sbor_derive_common::describe::handle_describe(proc_macro2::TokenStream::from(input), None)
@@ -1,5 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use sbor::rust::string::String;
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
 use sbor::*;
@@ -88,3 +89,40 @@ fn test_encode_enum() {
         bytes
     );
 }
+
+#[test]
+fn test_encoded_len_matches_encoded_payload() {
+    fn assert_encoded_len<T: BasicEncode + Encode<NoCustomValueKind, BasicLengthEncoder>>(
+        value: &T,
+    ) {
+        assert_eq!(
+            basic_encoded_len(value).unwrap(),
+            basic_encode(value).unwrap().len()
+        );
+    }
+
+    assert_encoded_len(&TestStructNamed { state: 3 });
+    assert_encoded_len(&TestStructUnnamed(3));
+    assert_encoded_len(&TestStructUnit);
+    assert_encoded_len(&TestEnum::A { x: 1, y: 2 });
+    assert_encoded_len(&TestEnum::C);
+    assert_encoded_len(&String::from("hello"));
+    assert_encoded_len(&vec![1u64; 200]);
+    assert_encoded_len(&(Some(1u128), Vec::<u8>::new(), true));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_encode_to_writer_matches_encode_to_vec() {
+    let value = (
+        TestStructNamed { state: 3 },
+        TestEnum::A { x: 1, y: 2 },
+        String::from("hello"),
+        vec![1u64; 200],
+    );
+
+    let mut written = Vec::new();
+    basic_encode_to_writer(&value, &mut written).unwrap();
+
+    assert_eq!(written, basic_encode(&value).unwrap());
+}
@@ -4,6 +4,7 @@ use sbor::rust::borrow::Cow;
 use sbor::rust::boxed::Box;
 use sbor::rust::collections::*;
 use sbor::rust::string::String;
+use sbor::rust::vec;
 use sbor::rust::vec::Vec;
 use sbor::*;
 
@@ -57,6 +58,48 @@ pub enum IndirectRecursiveEnum3 {
     },
 }
 
+#[derive(Categorize, Encode, Decode, Describe)]
+pub enum EmptyEnum {}
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub struct ContainsEmptyEnum {
+    pub a: u32,
+    pub b: Option<EmptyEnum>,
+}
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub enum UnsortedVariantsEnum {
+    Zebra,
+    Apple(u8),
+    Mango { x: u32 },
+}
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub struct DeprecatedFieldSample {
+    pub a: u32,
+    #[sbor(deprecated)]
+    pub b: u8,
+}
+
+#[derive(Categorize, Encode, Decode, Describe)]
+#[sbor(deprecated)]
+pub enum DeprecatedEnum {
+    Current(u8),
+    #[sbor(deprecated)]
+    Legacy {
+        x: u8,
+        #[sbor(deprecated)]
+        y: u8,
+    },
+}
+
+type Nested4<T> = Vec<Vec<Vec<Vec<T>>>>;
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub struct DeeplyNested(
+    Nested4<Nested4<Nested4<Nested4<Nested4<Nested4<Nested4<Nested4<Nested4<u8>>>>>>>>>,
+);
+
 #[test]
 fn create_unit_struct_schema_works_correctly() {
     let (type_ref, schema) =
@@ -201,6 +244,21 @@ fn creating_schema_from_multiple_types_works_correctly() {
     assert_eq!(field_types[6], unit_struct_type_ref); // T = UnitStruct is the 7th field in AdvancedSample<UnitStruct, u128>
 }
 
+#[test]
+fn reset_aggregator_produces_same_schema_as_fresh_aggregator() {
+    let mut aggregator = TypeAggregator::<NoCustomTypeKind>::new();
+    aggregator.add_child_type_and_descendents::<AdvancedSample<UnitStruct, u128>>();
+    aggregator.reset();
+    let basic_sample_type_ref = aggregator.add_child_type_and_descendents::<BasicSample>();
+    let reused_schema = generate_full_schema(aggregator);
+
+    let (fresh_type_ref, fresh_schema) =
+        generate_full_schema_from_single_type::<BasicSample, NoCustomTypeExtension>();
+
+    assert_eq!(basic_sample_type_ref, fresh_type_ref);
+    assert_eq!(reused_schema, fresh_schema);
+}
+
 #[test]
 fn create_recursive_schema_works_correctly() {
     // Most of this test is checking that such recursive schemas can: (A) happily compile and (B) don't panic when a schema is generated
@@ -213,3 +271,203 @@ fn create_recursive_schema_works_correctly() {
     let type_data = schema.resolve(LocalTypeIndex::SchemaLocalIndex(0)).unwrap();
     assert_eq!(type_data.metadata.type_name, "IndirectRecursive1");
 }
+
+#[test]
+fn schema_index_by_name_works_correctly() {
+    let (_, schema) = generate_full_schema_from_single_type::<BasicSample, NoCustomTypeExtension>();
+
+    assert!(matches!(
+        schema.index_by_name("BasicSample"),
+        Some(LocalTypeIndex::SchemaLocalIndex(0))
+    ));
+    assert!(matches!(
+        schema.index_by_name("UnitStruct"),
+        Some(LocalTypeIndex::SchemaLocalIndex(1))
+    ));
+    assert!(schema.index_by_name("MissingType").is_none());
+}
+
+#[test]
+fn checked_schema_generation_rejects_empty_enums() {
+    let mut aggregator = TypeAggregator::<NoCustomTypeKind>::new();
+    aggregator.add_child_type_and_descendents::<ContainsEmptyEnum>();
+
+    let result = generate_full_schema_checked(aggregator);
+
+    assert!(matches!(result, Err(SchemaError::EmptyEnum(type_name)) if type_name == "EmptyEnum"));
+}
+
+#[test]
+fn checked_schema_generation_accepts_valid_schemas() {
+    let mut aggregator = TypeAggregator::<NoCustomTypeKind>::new();
+    aggregator.add_child_type_and_descendents::<IndirectRecursive1>();
+
+    assert!(generate_full_schema_checked(aggregator).is_ok());
+}
+
+#[test]
+fn enum_schema_lists_variants_in_declaration_order() {
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<UnsortedVariantsEnum, NoCustomTypeExtension>();
+
+    let type_data = schema.resolve(type_ref).unwrap();
+    let variant_names: Vec<(u8, &str)> = match &type_data.metadata.child_names {
+        ChildNames::VariantNames(variants) => variants
+            .iter()
+            .map(|(index, metadata)| (*index, metadata.type_name.as_ref()))
+            .collect(),
+        _ => panic!("Expected variant names"),
+    };
+    assert_eq!(
+        variant_names,
+        vec![(0, "Zebra"), (1, "Apple"), (2, "Mango")]
+    );
+    let variant_field_types: Vec<(u8, Vec<LocalTypeIndex>)> = match type_data.kind.into_owned() {
+        TypeKind::Enum { variants } => variants.into_iter().collect(),
+        _ => panic!("Expected an enum type kind"),
+    };
+    assert_eq!(
+        variant_field_types,
+        vec![
+            (0, vec![]),
+            (
+                1,
+                vec![LocalTypeIndex::WellKnown(basic_well_known_types::U8_ID)]
+            ),
+            (
+                2,
+                vec![LocalTypeIndex::WellKnown(basic_well_known_types::U32_ID)]
+            ),
+        ]
+    );
+
+    // The variant indices agree with the discriminators written by the Encode derive
+    let payload = basic_encode(&UnsortedVariantsEnum::Apple(7)).unwrap();
+    assert_eq!(payload[2], 1);
+}
+
+#[test]
+fn result_round_trips_as_an_ok_err_enum() {
+    let ok: Result<u32, String> = Ok(7);
+    let err: Result<u32, String> = Err(String::from("failed"));
+
+    let ok_payload = basic_encode(&ok).unwrap();
+    let err_payload = basic_encode(&err).unwrap();
+
+    // A `Result` is encoded in the same way as an enum with `Ok` and `Err` variants of one field
+    assert_eq!(ok_payload[1..3], [RESULT_VARIANT_OK, 1]);
+    assert_eq!(err_payload[1..3], [RESULT_VARIANT_ERR, 1]);
+    assert_eq!(basic_decode::<Result<u32, String>>(&ok_payload), Ok(ok));
+    assert_eq!(basic_decode::<Result<u32, String>>(&err_payload), Ok(err));
+}
+
+#[test]
+fn result_schema_names_the_ok_and_err_variants() {
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<Result<u32, String>, NoCustomTypeExtension>();
+
+    let type_data = schema.resolve(type_ref).unwrap();
+    assert_eq!(type_data.metadata.type_name, "Result");
+    let variant_names: Vec<(u8, &str)> = match &type_data.metadata.child_names {
+        ChildNames::VariantNames(variants) => variants
+            .iter()
+            .map(|(index, metadata)| (*index, metadata.type_name.as_ref()))
+            .collect(),
+        _ => panic!("Expected variant names"),
+    };
+    assert_eq!(
+        variant_names,
+        vec![(RESULT_VARIANT_OK, "Ok"), (RESULT_VARIANT_ERR, "Err")]
+    );
+    let variant_field_types: Vec<(u8, Vec<LocalTypeIndex>)> = match type_data.kind.into_owned() {
+        TypeKind::Enum { variants } => variants.into_iter().collect(),
+        _ => panic!("Expected an enum type kind"),
+    };
+    assert_eq!(
+        variant_field_types,
+        vec![
+            (
+                RESULT_VARIANT_OK,
+                vec![LocalTypeIndex::WellKnown(basic_well_known_types::U32_ID)]
+            ),
+            (
+                RESULT_VARIANT_ERR,
+                vec![LocalTypeIndex::WellKnown(basic_well_known_types::STRING_ID)]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn deprecated_field_is_flagged_in_schema() {
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<DeprecatedFieldSample, NoCustomTypeExtension>();
+
+    let type_data = schema.resolve(type_ref).unwrap();
+    assert!(!type_data.metadata.deprecated);
+    assert_eq!(type_data.metadata.deprecated_fields, BTreeSet::from([1]));
+    assert!(!type_data.metadata.is_field_deprecated(0));
+    assert!(type_data.metadata.is_field_deprecated(1));
+
+    // Deprecation doesn't change the encoding
+    let payload = basic_encode(&DeprecatedFieldSample { a: 1, b: 2 }).unwrap();
+    assert_eq!(payload, basic_encode(&(1u32, 2u8)).unwrap());
+}
+
+#[test]
+fn deprecated_enum_and_variant_are_flagged_in_schema() {
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<DeprecatedEnum, NoCustomTypeExtension>();
+
+    let type_data = schema.resolve(type_ref).unwrap();
+    assert!(type_data.metadata.deprecated);
+    let variants = match &type_data.metadata.child_names {
+        ChildNames::VariantNames(variants) => variants,
+        _ => panic!("Expected variant names"),
+    };
+    assert!(!variants[&0].deprecated);
+    assert!(variants[&1].deprecated);
+    assert_eq!(variants[&1].deprecated_fields, BTreeSet::from([1]));
+}
+
+#[test]
+fn schema_lint_computes_max_depth() {
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<BasicSample, NoCustomTypeExtension>();
+    assert_eq!(lint::max_depth(&schema, type_ref), 2);
+
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<DeeplyNested, NoCustomTypeExtension>();
+    assert_eq!(lint::max_depth(&schema, type_ref), 38);
+
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<IndirectRecursive1, NoCustomTypeExtension>();
+    assert_eq!(lint::max_depth(&schema, type_ref), usize::MAX);
+}
+
+#[test]
+fn schema_lint_flags_deeply_nested_types() {
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<BasicSample, NoCustomTypeExtension>();
+    assert_eq!(
+        lint::lint(&schema, type_ref, lint::DEFAULT_MAX_ALLOWED_DEPTH),
+        vec![]
+    );
+
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<DeeplyNested, NoCustomTypeExtension>();
+    assert_eq!(
+        lint::lint(&schema, type_ref, lint::DEFAULT_MAX_ALLOWED_DEPTH),
+        vec![lint::SchemaLint::DeeplyNested {
+            type_name: "DeeplyNested".to_string(),
+            depth: 38,
+        }]
+    );
+    lint::assert_no_lints::<BasicSample, NoCustomTypeExtension>();
+}
+
+#[test]
+#[should_panic]
+fn schema_lint_assertion_panics_on_deeply_nested_types() {
+    lint::assert_no_lints::<DeeplyNested, NoCustomTypeExtension>();
+}
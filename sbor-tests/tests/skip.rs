@@ -1,10 +1,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod utils;
+
+use sbor::rust::marker::PhantomData;
+use sbor::rust::string::ToString;
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
 use sbor::*;
+use serde_json::json;
+use utils::{assert_decode_never_panics, assert_describe_stable};
 
-#[derive(Debug, PartialEq, Categorize, Encode, Decode)]
+#[derive(Debug, PartialEq, Categorize, Encode, Decode, Describe)]
 pub struct TestStructNamed {
     #[allow(unused_variables)]
     #[sbor(skip)]
@@ -29,6 +35,15 @@ pub enum TestEnum {
     C,
 }
 
+#[derive(Debug, PartialEq, Categorize, Encode, Decode, Describe)]
+pub struct TestStructWithPhantomData<T> {
+    pub x: u32,
+    pub marker: PhantomData<T>,
+}
+
+#[derive(Debug, PartialEq, Categorize, Encode, Decode)]
+pub struct TestStructUnnamedWithPhantomData<T>(PhantomData<T>, u32);
+
 #[test]
 fn test_struct_with_skip() {
     let a = TestStructNamed { x: 1, y: 2 };
@@ -109,3 +124,154 @@ fn test_enum_with_skip() {
     assert_eq!(TestEnum::B(0, 4), b);
     assert_eq!(TestEnum::C, c);
 }
+
+#[test]
+fn test_describe_of_struct_with_skip_is_stable() {
+    assert_describe_stable::<TestStructNamed>(json!({
+        "type_index": "SchemaLocalIndex(0)",
+        "types": [
+            {
+                "name": "TestStructNamed",
+                "child_names": "FieldNames([\"y\"])",
+                "kind": "Tuple { field_types: [WellKnown(9)] }"
+            }
+        ]
+    }));
+}
+
+#[test]
+fn test_struct_with_phantom_data() {
+    let a = TestStructWithPhantomData::<u64> {
+        x: 1,
+        marker: PhantomData,
+    };
+    let b = TestStructUnnamedWithPhantomData::<u64>(PhantomData, 2);
+
+    let mut bytes = Vec::with_capacity(512);
+    let mut encoder = BasicEncoder::new(&mut bytes);
+    encoder.encode(&a).unwrap();
+    encoder.encode(&b).unwrap();
+
+    #[rustfmt::skip]
+    assert_eq!(
+        vec![
+          33, // tuple type
+          1, // number of fields
+          9, 1, 0, 0, 0, // field value
+
+          33, // tuple type
+          1, // number of fields
+          9, 2, 0, 0, 0, // field value
+        ],
+        bytes
+    );
+
+    let mut decoder = BasicDecoder::new(&bytes);
+    assert_eq!(
+        decoder.decode::<TestStructWithPhantomData<u64>>().unwrap(),
+        a
+    );
+    assert_eq!(
+        decoder
+            .decode::<TestStructUnnamedWithPhantomData<u64>>()
+            .unwrap(),
+        b
+    );
+}
+
+#[test]
+fn test_describe_of_struct_with_phantom_data_omits_it() {
+    assert_describe_stable::<TestStructWithPhantomData<u64>>(json!({
+        "type_index": "SchemaLocalIndex(0)",
+        "types": [
+            {
+                "name": "TestStructWithPhantomData",
+                "child_names": "FieldNames([\"x\"])",
+                "kind": "Tuple { field_types: [WellKnown(9)] }"
+            }
+        ]
+    }));
+}
+
+#[test]
+fn test_schema_validating_encoder_accepts_struct_with_skip() {
+    let (type_index, schema) =
+        generate_full_schema_from_single_type::<TestStructNamed, NoCustomTypeExtension>();
+    let value = TestStructNamed { x: 1, y: 2 };
+
+    let mut bytes = Vec::new();
+    let mut encoder =
+        BasicSchemaValidatingEncoder::new(BasicEncoder::new(&mut bytes), &schema, type_index);
+    encoder.encode(&value).unwrap();
+
+    assert_eq!(bytes, basic_encode(&value).unwrap());
+}
+
+#[test]
+fn test_schema_validating_encoder_rejects_skipped_field_being_written() {
+    let (type_index, schema) =
+        generate_full_schema_from_single_type::<TestStructNamed, NoCustomTypeExtension>();
+
+    // Both fields of the struct, where the schema only has the field which isn't skipped
+    let mut bytes = Vec::new();
+    let mut encoder =
+        BasicSchemaValidatingEncoder::new(BasicEncoder::new(&mut bytes), &schema, type_index);
+    let result = encoder.encode(&(1u32, 2u32));
+
+    assert_eq!(
+        result,
+        Err(EncodeError::SchemaMismatch {
+            type_name: "TestStructNamed".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_schema_validating_encoder_rejects_field_of_wrong_kind() {
+    let (type_index, schema) =
+        generate_full_schema_from_single_type::<TestStructNamed, NoCustomTypeExtension>();
+
+    let mut bytes = Vec::new();
+    let mut encoder =
+        BasicSchemaValidatingEncoder::new(BasicEncoder::new(&mut bytes), &schema, type_index);
+    let result = encoder.encode(&(2u64,));
+
+    assert!(matches!(result, Err(EncodeError::SchemaMismatch { .. })));
+}
+
+/// The valid payload encodings of the values used by the tests above.
+fn fuzz_corpus() -> Vec<Vec<u8>> {
+    vec![
+        basic_encode(&TestStructNamed { x: 1, y: 2 }).unwrap(),
+        basic_encode(&TestStructUnnamed(3, 4)).unwrap(),
+        basic_encode(&TestStructUnit).unwrap(),
+        basic_encode(&TestEnum::A { x: 1, y: 2 }).unwrap(),
+        basic_encode(&TestEnum::B(3, 4)).unwrap(),
+        basic_encode(&TestEnum::C).unwrap(),
+        basic_encode(&TestStructWithPhantomData::<u64> {
+            x: 1,
+            marker: PhantomData,
+        })
+        .unwrap(),
+        basic_encode(&TestStructUnnamedWithPhantomData::<u64>(PhantomData, 2)).unwrap(),
+    ]
+}
+
+#[test]
+fn test_fuzzed_decoding_of_types_with_skip_never_panics() {
+    let corpus = fuzz_corpus();
+    let iterations = 2000;
+
+    let decoded = assert_decode_never_panics::<TestStructNamed>(&corpus, 1, iterations)
+        + assert_decode_never_panics::<TestStructUnnamed>(&corpus, 2, iterations)
+        + assert_decode_never_panics::<TestStructUnit>(&corpus, 3, iterations)
+        + assert_decode_never_panics::<TestEnum>(&corpus, 4, iterations)
+        + assert_decode_never_panics::<TestStructWithPhantomData<u64>>(&corpus, 5, iterations)
+        + assert_decode_never_panics::<TestStructUnnamedWithPhantomData<u64>>(
+            &corpus, 6, iterations,
+        )
+        + assert_decode_never_panics::<BasicValue>(&corpus, 7, iterations);
+
+    // Some of the mutated inputs must still be valid, or the decoders were never exercised past the prefix
+    assert!(decoded > 0);
+}
@@ -0,0 +1,170 @@
+//! Helpers shared between the sbor integration tests.
+
+use sbor::rust::format;
+use sbor::rust::string::String;
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+use sbor::*;
+use serde_json::{json, to_string_pretty, Value};
+
+/// Renders the schema generated from `T`'s `Describe` implementation as JSON, with one entry per
+/// novel type of the schema.
+pub fn describe_to_json<T: Describe<NoCustomTypeKind>>() -> Value {
+    let (type_index, schema) = generate_full_schema_from_single_type::<T, NoCustomTypeExtension>();
+    let types: Vec<Value> = schema
+        .type_kinds
+        .iter()
+        .zip(schema.type_metadata.iter())
+        .map(|(type_kind, novel_metadata)| {
+            json!({
+                "name": novel_metadata.type_metadata.type_name.as_ref(),
+                "child_names": format!("{:?}", novel_metadata.type_metadata.child_names),
+                "kind": format!("{:?}", type_kind),
+            })
+        })
+        .collect();
+
+    json!({
+        "type_index": format!("{:?}", type_index),
+        "types": types,
+    })
+}
+
+/// Asserts that the describe output of `T` (as rendered by [`describe_to_json`]) matches
+/// `expected`. On a mismatch, panics listing every JSON path which differs.
+pub fn assert_describe_stable<T: Describe<NoCustomTypeKind>>(expected: Value) {
+    let actual = describe_to_json::<T>();
+    let mut differences = Vec::new();
+    diff_json("$", &actual, &expected, &mut differences);
+    if !differences.is_empty() {
+        panic!(
+            "Describe output changed:\n{}\nActual:\n{}\n",
+            differences.join("\n"),
+            to_string_pretty(&actual).unwrap()
+        );
+    }
+}
+
+/// A deterministic xorshift pseudo-random generator, so that fuzzing needs no external crate and every failure can be
+/// reproduced from its seed.
+pub struct FuzzRng(u64);
+
+impl FuzzRng {
+    pub fn new(seed: u64) -> Self {
+        // Xorshift gets stuck on a zero state
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    pub fn next_u8(&mut self) -> u8 {
+        self.next_u64() as u8
+    }
+}
+
+/// Produces a fuzzing input from the corpus: either an entry with a few of its bytes overwritten, an entry truncated
+/// or extended with random bytes, or random bytes altogether.
+pub fn fuzz_input(corpus: &[Vec<u8>], rng: &mut FuzzRng) -> Vec<u8> {
+    let mut input = corpus[rng.below(corpus.len())].clone();
+    match rng.below(4) {
+        0 if !input.is_empty() => {
+            for _ in 0..=rng.below(3) {
+                let index = rng.below(input.len());
+                input[index] = rng.next_u8();
+            }
+        }
+        1 => input.truncate(rng.below(input.len() + 1)),
+        2 => {
+            for _ in 0..=rng.below(16) {
+                input.push(rng.next_u8());
+            }
+        }
+        _ => {
+            input = vec![0u8; rng.below(64)];
+            for byte in input.iter_mut() {
+                *byte = rng.next_u8();
+            }
+        }
+    }
+    input
+}
+
+/// Decodes `iterations` fuzzing inputs derived from `corpus` as a `T`, asserting that malformed input only ever makes
+/// decoding return a `DecodeError` - any panic in the decoder fails the calling test.
+///
+/// Returns how many of the inputs decoded successfully, so that callers can check the inputs aren't all rejected
+/// outright.
+pub fn assert_decode_never_panics<T: BasicDecode>(
+    corpus: &[Vec<u8>],
+    seed: u64,
+    iterations: usize,
+) -> usize {
+    let mut rng = FuzzRng::new(seed);
+    let mut decoded = 0;
+    for _ in 0..iterations {
+        let input = fuzz_input(corpus, &mut rng);
+        if basic_decode::<T>(&input).is_ok() {
+            decoded += 1;
+        }
+    }
+    decoded
+}
+
+fn diff_json(path: &str, actual: &Value, expected: &Value, differences: &mut Vec<String>) {
+    match (actual, expected) {
+        (Value::Object(actual_fields), Value::Object(expected_fields)) => {
+            for (key, expected_value) in expected_fields {
+                let child_path = format!("{}.{}", path, key);
+                match actual_fields.get(key) {
+                    Some(actual_value) => {
+                        diff_json(&child_path, actual_value, expected_value, differences)
+                    }
+                    None => differences.push(format!(
+                        "{}: missing, expected {}",
+                        child_path, expected_value
+                    )),
+                }
+            }
+            for (key, actual_value) in actual_fields {
+                if !expected_fields.contains_key(key) {
+                    differences.push(format!("{}.{}: unexpected {}", path, key, actual_value));
+                }
+            }
+        }
+        (Value::Array(actual_elements), Value::Array(expected_elements)) => {
+            for i in 0..actual_elements.len().max(expected_elements.len()) {
+                let child_path = format!("{}[{}]", path, i);
+                match (actual_elements.get(i), expected_elements.get(i)) {
+                    (Some(actual_value), Some(expected_value)) => {
+                        diff_json(&child_path, actual_value, expected_value, differences)
+                    }
+                    (None, Some(expected_value)) => differences.push(format!(
+                        "{}: missing, expected {}",
+                        child_path, expected_value
+                    )),
+                    (Some(actual_value), None) => {
+                        differences.push(format!("{}: unexpected {}", child_path, actual_value))
+                    }
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+        _ => {
+            if actual != expected {
+                differences.push(format!(
+                    "{}: expected {}, actual {}",
+                    path, expected, actual
+                ));
+            }
+        }
+    }
+}
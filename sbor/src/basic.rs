@@ -19,6 +19,9 @@ pub enum NoCustomValue {}
 
 pub const DEFAULT_BASIC_MAX_DEPTH: u8 = 64;
 pub type BasicEncoder<'a> = VecEncoder<'a, NoCustomValueKind, DEFAULT_BASIC_MAX_DEPTH>;
+pub type BasicLengthEncoder = LengthEncoder<NoCustomValueKind, DEFAULT_BASIC_MAX_DEPTH>;
+#[cfg(not(feature = "alloc"))]
+pub type BasicWriteEncoder<'a, W> = WriteEncoder<'a, W, NoCustomValueKind, DEFAULT_BASIC_MAX_DEPTH>;
 pub type BasicDecoder<'a> = VecDecoder<'a, NoCustomValueKind, DEFAULT_BASIC_MAX_DEPTH>;
 pub type BasicValue = Value<NoCustomValueKind, NoCustomValue>;
 pub type BasicValueKind = ValueKind<NoCustomValueKind>;
@@ -54,6 +57,28 @@ pub fn basic_encode<T: BasicEncode + ?Sized>(v: &T) -> Result<Vec<u8>, EncodeErr
     Ok(buf)
 }
 
+/// Encode a `T` as a full payload directly into `writer`.
+#[cfg(not(feature = "alloc"))]
+pub fn basic_encode_to_writer<'a, W, T>(v: &T, writer: &'a mut W) -> Result<(), EncodeError>
+where
+    W: std::io::Write,
+    T: Encode<NoCustomValueKind, BasicWriteEncoder<'a, W>> + ?Sized,
+{
+    let encoder = BasicWriteEncoder::new(writer);
+    encoder.encode_payload(v, BASIC_SBOR_V1_PAYLOAD_PREFIX)
+}
+
+/// Computes the length of the payload that `basic_encode` would produce for a `T`, without
+/// allocating it.
+pub fn basic_encoded_len<T: Encode<NoCustomValueKind, BasicLengthEncoder> + ?Sized>(
+    v: &T,
+) -> Result<usize, EncodeError> {
+    let mut encoder = BasicLengthEncoder::new();
+    encoder.write_payload_prefix(BASIC_SBOR_V1_PAYLOAD_PREFIX)?;
+    encoder.encode(v)?;
+    Ok(encoder.encoded_len())
+}
+
 /// Decode an instance of `T` from a slice.
 pub fn basic_decode<T: BasicDecode>(buf: &[u8]) -> Result<T, DecodeError> {
     BasicDecoder::new(buf).decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
@@ -131,4 +156,6 @@ mod schema {
 
     pub type BasicTypeKind<L> = TypeKind<NoCustomValueKind, NoCustomTypeKind, L>;
     pub type BasicSchema = Schema<NoCustomTypeExtension>;
+    pub type BasicSchemaValidatingEncoder<'s, Enc> =
+        SchemaValidatingEncoder<'s, NoCustomTypeExtension, Enc, DEFAULT_BASIC_MAX_DEPTH>;
 }
@@ -420,4 +420,38 @@ mod tests {
         let value2 = decoder.decode::<[NFA; 2]>().unwrap();
         assert_eq!(value1, value2);
     }
+
+    #[test]
+    pub fn test_fixed_size_byte_array_round_trip() {
+        let value1 = [7u8; 32];
+
+        // Encode
+        let mut bytes = Vec::with_capacity(512);
+        let mut encoder = BasicEncoder::new(&mut bytes);
+        encoder.encode(&value1).unwrap();
+
+        let mut decoder = BasicDecoder::new(&bytes);
+        let value2 = decoder.decode::<[u8; 32]>().unwrap();
+        assert_eq!(value1, value2);
+    }
+
+    #[test]
+    pub fn test_fixed_size_array_with_wrong_length_fails_to_decode() {
+        let value = vec![7u8; 31];
+
+        // Encode
+        let mut bytes = Vec::with_capacity(512);
+        let mut encoder = BasicEncoder::new(&mut bytes);
+        encoder.encode(&value).unwrap();
+
+        let mut decoder = BasicDecoder::new(&bytes);
+        let result = decoder.decode::<[u8; 32]>();
+        assert_eq!(
+            result,
+            Err(DecodeError::UnexpectedSize {
+                expected: 32,
+                actual: 31
+            })
+        );
+    }
 }
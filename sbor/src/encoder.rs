@@ -1,4 +1,5 @@
 use crate::rust::marker::PhantomData;
+use crate::rust::string::String;
 use crate::rust::vec::Vec;
 use crate::*;
 
@@ -6,7 +7,15 @@ use crate::*;
 #[derive(Debug, Clone, PartialEq, Eq, Categorize, Encode, Decode)]
 pub enum EncodeError {
     MaxDepthExceeded(u8),
-    SizeTooLarge { actual: usize, max_allowed: usize },
+    SizeTooLarge {
+        actual: usize,
+        max_allowed: usize,
+    },
+    WriteFailed,
+    /// The value diverged from the schema it was being validated against, at a value of the named type.
+    SchemaMismatch {
+        type_name: String,
+    },
 }
 
 pub trait Encoder<X: CustomValueKind>: Sized {
@@ -157,6 +166,148 @@ impl<'a, X: CustomValueKind, const MAX_DEPTH: u8> Encoder<X> for VecEncoder<'a,
     }
 }
 
+/// An `Encoder` which streams the encoded bytes into a [`std::io::Write`], so that large payloads
+/// can be written to a file or socket without first being buffered in memory.
+///
+/// If the writer fails, encoding stops with [`EncodeError::WriteFailed`] and the underlying
+/// [`std::io::Error`] can be retrieved with [`WriteEncoder::take_write_error`].
+#[cfg(not(feature = "alloc"))]
+pub struct WriteEncoder<'a, W: std::io::Write, X: CustomValueKind, const MAX_DEPTH: u8> {
+    writer: &'a mut W,
+    write_error: Option<std::io::Error>,
+    stack_depth: u8,
+    phantom: PhantomData<X>,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<'a, W: std::io::Write, X: CustomValueKind, const MAX_DEPTH: u8>
+    WriteEncoder<'a, W, X, MAX_DEPTH>
+{
+    pub fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer,
+            write_error: None,
+            stack_depth: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Takes the error returned by the writer when encoding failed with
+    /// [`EncodeError::WriteFailed`].
+    pub fn take_write_error(&mut self) -> Option<std::io::Error> {
+        self.write_error.take()
+    }
+
+    #[inline]
+    fn track_stack_depth_increase(&mut self) -> Result<(), EncodeError> {
+        self.stack_depth += 1;
+        if self.stack_depth > MAX_DEPTH {
+            return Err(EncodeError::MaxDepthExceeded(MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn track_stack_depth_decrease(&mut self) -> Result<(), EncodeError> {
+        self.stack_depth -= 1;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<'a, W: std::io::Write, X: CustomValueKind, const MAX_DEPTH: u8> Encoder<X>
+    for WriteEncoder<'a, W, X, MAX_DEPTH>
+{
+    fn encode_deeper_body<T: Encode<X, Self> + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        self.track_stack_depth_increase()?;
+        value.encode_body(self)?;
+        self.track_stack_depth_decrease()
+    }
+
+    #[inline]
+    fn write_byte(&mut self, n: u8) -> Result<(), EncodeError> {
+        self.write_slice(&[n])
+    }
+
+    #[inline]
+    fn write_slice(&mut self, slice: &[u8]) -> Result<(), EncodeError> {
+        self.writer.write_all(slice).map_err(|error| {
+            self.write_error = Some(error);
+            EncodeError::WriteFailed
+        })
+    }
+}
+
+/// An `Encoder` which doesn't write anything, but instead counts the number of bytes that would
+/// be written - so that a buffer can be allocated with the exact size up front.
+pub struct LengthEncoder<X: CustomValueKind, const MAX_DEPTH: u8> {
+    len: usize,
+    stack_depth: u8,
+    phantom: PhantomData<X>,
+}
+
+impl<X: CustomValueKind, const MAX_DEPTH: u8> LengthEncoder<X, MAX_DEPTH> {
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            stack_depth: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The number of bytes encoded so far
+    pub fn encoded_len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn track_stack_depth_increase(&mut self) -> Result<(), EncodeError> {
+        self.stack_depth += 1;
+        if self.stack_depth > MAX_DEPTH {
+            return Err(EncodeError::MaxDepthExceeded(MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn track_stack_depth_decrease(&mut self) -> Result<(), EncodeError> {
+        self.stack_depth -= 1;
+        Ok(())
+    }
+}
+
+impl<X: CustomValueKind, const MAX_DEPTH: u8> Default for LengthEncoder<X, MAX_DEPTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<X: CustomValueKind, const MAX_DEPTH: u8> Encoder<X> for LengthEncoder<X, MAX_DEPTH> {
+    fn encode_deeper_body<T: Encode<X, Self> + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        self.track_stack_depth_increase()?;
+        value.encode_body(self)?;
+        self.track_stack_depth_decrease()
+    }
+
+    #[inline]
+    fn write_byte(&mut self, _n: u8) -> Result<(), EncodeError> {
+        self.len += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_slice(&mut self, slice: &[u8]) -> Result<(), EncodeError> {
+        self.len += slice.len();
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
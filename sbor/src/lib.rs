@@ -40,7 +40,9 @@ pub use constants::*;
 pub use decode::Decode;
 pub use decoder::{DecodeError, Decoder, VecDecoder};
 pub use encode::Encode;
-pub use encoder::{EncodeError, Encoder, VecEncoder};
+#[cfg(not(feature = "alloc"))]
+pub use encoder::WriteEncoder;
+pub use encoder::{EncodeError, Encoder, LengthEncoder, VecEncoder};
 pub use path::{SborPath, SborPathBuf};
 
 pub use schema::*;
@@ -0,0 +1,107 @@
+use super::*;
+use crate::rust::collections::*;
+use crate::rust::string::{String, ToString};
+use crate::rust::vec;
+use crate::rust::vec::Vec;
+
+/// The default depth above which [`lint`] flags a type as [`SchemaLint::DeeplyNested`]. This is well under the
+/// depth guard of the decoders, so that a flagged type can still be wrapped a few times before values of it fail to
+/// decode.
+pub const DEFAULT_MAX_ALLOWED_DEPTH: usize = 32;
+
+/// A warning about a type which is valid, but likely to be costly or risky to use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaLint {
+    /// Values of the type can be nested deeper than allowed. Holds the type name and the type's maximum depth, which
+    /// is `usize::MAX` for recursive types.
+    DeeplyNested { type_name: String, depth: usize },
+}
+
+/// Computes the maximum depth of the values of the given type, counting one level for every type on the way down to
+/// a leaf - so a `u8` has a depth of 1, and a `Vec<u8>` a depth of 2.
+///
+/// A recursive type has no maximum depth, in which case `usize::MAX` is returned. Custom type kinds are counted as
+/// leaves.
+///
+/// # Panics
+/// If the schema is missing a type referenced from `root`.
+pub fn max_depth<E: CustomTypeExtension>(schema: &Schema<E>, root: LocalTypeIndex) -> usize {
+    let mut depths = HashMap::new();
+    let mut in_progress = HashSet::new();
+    depth_of(schema, root, &mut depths, &mut in_progress)
+}
+
+fn depth_of<E: CustomTypeExtension>(
+    schema: &Schema<E>,
+    type_index: LocalTypeIndex,
+    depths: &mut HashMap<LocalTypeIndex, usize>,
+    in_progress: &mut HashSet<LocalTypeIndex>,
+) -> usize {
+    if let Some(depth) = depths.get(&type_index) {
+        return *depth;
+    }
+    // A type which is reached again while its children are being walked is recursive
+    if !in_progress.insert(type_index) {
+        return usize::MAX;
+    }
+
+    let type_data = schema
+        .resolve(type_index)
+        .expect("Schema is missing a referenced type");
+    let children = match type_data.kind.as_ref() {
+        TypeKind::Array { element_type } => vec![*element_type],
+        TypeKind::Tuple { field_types } => field_types.clone(),
+        TypeKind::Enum { variants } => variants.values().flatten().cloned().collect(),
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => vec![*key_type, *value_type],
+        _ => Vec::new(),
+    };
+    let depth = children
+        .into_iter()
+        .map(|child| depth_of(schema, child, depths, in_progress))
+        .max()
+        .unwrap_or(0)
+        .saturating_add(1);
+
+    in_progress.remove(&type_index);
+    depths.insert(type_index, depth);
+    depth
+}
+
+/// Lints the given type of the schema, flagging it as [`SchemaLint::DeeplyNested`] if its values can be nested
+/// deeper than `max_allowed_depth`.
+pub fn lint<E: CustomTypeExtension>(
+    schema: &Schema<E>,
+    root: LocalTypeIndex,
+    max_allowed_depth: usize,
+) -> Vec<SchemaLint> {
+    let mut lints = Vec::new();
+
+    let depth = max_depth(schema, root);
+    if depth > max_allowed_depth {
+        let type_data = schema
+            .resolve(root)
+            .expect("Schema is missing a referenced type");
+        lints.push(SchemaLint::DeeplyNested {
+            type_name: type_data.metadata.type_name.to_string(),
+            depth,
+        });
+    }
+
+    lints
+}
+
+/// Asserts that the schema of `T` has no lints at [`DEFAULT_MAX_ALLOWED_DEPTH`]. This is intended to be called from
+/// a test of the crate defining `T`, so that its CI catches types becoming too deeply nested.
+///
+/// # Panics
+/// If the schema of `T` has any lints.
+pub fn assert_no_lints<T: Describe<E::CustomTypeKind<GlobalTypeId>>, E: CustomTypeExtension>() {
+    let (type_index, schema) = generate_full_schema_from_single_type::<T, E>();
+    let lints = lint(&schema, type_index, DEFAULT_MAX_ALLOWED_DEPTH);
+    if !lints.is_empty() {
+        panic!("Schema has lints: {:?}", lints);
+    }
+}
@@ -1,10 +1,12 @@
 mod custom_traits;
 mod describe;
+pub mod lint;
 mod macros;
 mod schema;
 mod type_aggregator;
 mod type_data;
 mod type_link;
+mod validating_encoder;
 mod well_known_types;
 
 pub use custom_traits::*;
@@ -14,4 +16,5 @@ pub use schema::*;
 pub use type_aggregator::*;
 pub use type_data::*;
 pub use type_link::*;
+pub use validating_encoder::*;
 pub use well_known_types::*;
@@ -39,4 +39,16 @@ impl<E: CustomTypeExtension> Schema<E> {
             }
         }
     }
+
+    /// Finds the index of the novel type in the schema with the given type name.
+    ///
+    /// Type names aren't guaranteed to be unique within a schema (eg two types with the same name
+    /// from different modules, or different instantiations of a generic type), in which case the
+    /// index of the first type with a matching name is returned.
+    pub fn index_by_name(&self, name: &str) -> Option<LocalTypeIndex> {
+        self.type_metadata
+            .iter()
+            .position(|novel_metadata| novel_metadata.type_metadata.type_name == name)
+            .map(LocalTypeIndex::SchemaLocalIndex)
+    }
 }
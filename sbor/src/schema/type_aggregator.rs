@@ -1,5 +1,6 @@
 use super::*;
 use sbor::rust::collections::*;
+use sbor::rust::string::{String, ToString};
 
 pub fn generate_full_schema_from_single_type<
     T: Describe<E::CustomTypeKind<GlobalTypeId>>,
@@ -32,6 +33,34 @@ pub fn generate_full_schema<C: CustomTypeKind<GlobalTypeId>>(
     }
 }
 
+/// Represents an error in a schema generated by [`generate_full_schema_checked`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaError {
+    /// An enum type with no variants, which can never be constructed. Holds the type name.
+    EmptyEnum(String),
+}
+
+/// Generates the full schema as per [`generate_full_schema`], and then validates it.
+///
+/// Only the novel types of the schema are validated - well-known types are exempt.
+pub fn generate_full_schema_checked<C: CustomTypeKind<GlobalTypeId>>(
+    aggregator: TypeAggregator<C>,
+) -> Result<Schema<C::CustomTypeExtension>, SchemaError> {
+    let schema = generate_full_schema(aggregator);
+
+    for (type_kind, novel_metadata) in schema.type_kinds.iter().zip(&schema.type_metadata) {
+        if let TypeKind::Enum { variants } = type_kind {
+            if variants.is_empty() {
+                return Err(SchemaError::EmptyEnum(
+                    novel_metadata.type_metadata.type_name.to_string(),
+                ));
+            }
+        }
+    }
+
+    Ok(schema)
+}
+
 fn linearize<E: CustomTypeExtension>(
     type_kind: TypeKind<E::CustomValueKind, E::CustomTypeKind<GlobalTypeId>, GlobalTypeId>,
     type_indices: &BTreeMap<TypeHash, usize>,
@@ -118,6 +147,15 @@ impl<C: CustomTypeKind<GlobalTypeId>> TypeAggregator<C> {
         }
     }
 
+    /// Clears all aggregated types, so that the `TypeAggregator` can be reused for a new set of root types.
+    ///
+    /// Any [`LocalTypeIndex`] previously returned by this aggregator is invalidated by a reset, as the
+    /// indices of types added afterwards start again from zero.
+    pub fn reset(&mut self) {
+        self.already_read_dependencies.clear();
+        self.types.clear();
+    }
+
     /// Adds the dependent type (and its dependencies) to the `TypeAggregator`.
     pub fn add_child_type_and_descendents<T: Describe<C>>(&mut self) -> LocalTypeIndex {
         let schema_type_index = self.add_child_type(T::TYPE_ID, || T::type_data());
@@ -44,6 +44,20 @@ impl<C: CustomTypeKind<L>, L: SchemaTypeLink + Categorize<C::CustomValueKind>> T
         }
     }
 
+    /// Marks the type as deprecated in its metadata.
+    pub fn deprecated(mut self) -> Self {
+        self.metadata.deprecated = true;
+        self
+    }
+
+    /// Marks the fields at the given indices as deprecated in the type's metadata.
+    pub fn with_deprecated_fields(mut self, field_indices: &[usize]) -> Self {
+        self.metadata
+            .deprecated_fields
+            .extend(field_indices.iter().cloned());
+        self
+    }
+
     pub fn named_no_child_names(
         name: &'static str,
         schema: TypeKind<C::CustomValueKind, C, L>,
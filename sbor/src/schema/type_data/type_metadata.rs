@@ -1,10 +1,10 @@
 use crate::rust::borrow::Cow;
-use crate::rust::collections::BTreeMap;
+use crate::rust::collections::{BTreeMap, BTreeSet};
 use crate::rust::vec::Vec;
-use crate::TypeHash;
+use crate::*;
 
 /// This is the struct used in the Schema
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Categorize, Encode, Decode)]
 pub struct NovelTypeMetadata {
     pub type_hash: TypeHash,
     pub type_metadata: TypeMetadata,
@@ -12,10 +12,15 @@ pub struct NovelTypeMetadata {
 
 /// This enables the type to be represented as eg JSON
 /// Also used to facilitate type reconstruction
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Categorize, Encode, Decode)]
 pub struct TypeMetadata {
     pub type_name: Cow<'static, str>,
     pub child_names: ChildNames,
+    /// Whether the type (or enum variant) is marked with `#[sbor(deprecated)]`, so that tooling
+    /// can warn its consumers. Deprecation has no effect on encoding or decoding.
+    pub deprecated: bool,
+    /// The indices of the fields marked with `#[sbor(deprecated)]`.
+    pub deprecated_fields: BTreeSet<usize>,
 }
 
 impl TypeMetadata {
@@ -30,6 +35,8 @@ impl TypeMetadata {
         Self {
             type_name: Cow::Borrowed(name),
             child_names: ChildNames::None,
+            deprecated: false,
+            deprecated_fields: BTreeSet::new(),
         }
     }
 
@@ -41,6 +48,8 @@ impl TypeMetadata {
         Self {
             type_name: Cow::Borrowed(name),
             child_names: ChildNames::FieldNames(field_names),
+            deprecated: false,
+            deprecated_fields: BTreeSet::new(),
         }
     }
 
@@ -51,11 +60,18 @@ impl TypeMetadata {
         Self {
             type_name: Cow::Borrowed(name),
             child_names: ChildNames::VariantNames(variant_naming),
+            deprecated: false,
+            deprecated_fields: BTreeSet::new(),
         }
     }
+
+    /// Whether the field at the given index is marked as deprecated.
+    pub fn is_field_deprecated(&self, field_index: usize) -> bool {
+        self.deprecated_fields.contains(&field_index)
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Categorize, Encode, Decode)]
 pub enum ChildNames {
     #[default]
     None,
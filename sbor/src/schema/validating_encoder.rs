@@ -0,0 +1,336 @@
+use super::*;
+use crate::rust::collections::*;
+use crate::rust::string::ToString;
+use crate::rust::vec::Vec;
+
+/// An [`Encoder`] which checks the value being encoded against a type of a schema as it is written, failing with
+/// [`EncodeError::SchemaMismatch`] as soon as a value kind, enum discriminator or field count diverges from the schema.
+/// Everything written is passed on to the wrapped encoder, so this is a development aid for catching a type whose
+/// `Encode` and `Describe` implementations disagree, rather than something to encode payloads with in production.
+///
+/// Values of [`TypeKind::Any`] and custom types are not validated beyond their own value kind being custom.
+///
+/// # Panics
+/// If the schema is missing a type referenced from the root type.
+pub struct SchemaValidatingEncoder<'s, E: CustomTypeExtension, Enc, const MAX_DEPTH: u8> {
+    inner: Enc,
+    schema: &'s Schema<E>,
+    root: Option<LocalTypeIndex>,
+    /// The composite values whose bodies are being written, innermost last.
+    frames: Vec<Frame>,
+    /// The type of the value whose value kind was just written, and whose body is written next. `Some(None)` stands
+    /// for a value which isn't validated.
+    next_value_type: Option<Option<LocalTypeIndex>>,
+    stack_depth: u8,
+}
+
+enum Frame {
+    /// A value whose children (if any) are not validated.
+    Unchecked,
+    /// A value without children, such as an integer or a string.
+    Leaf,
+    Tuple {
+        type_index: LocalTypeIndex,
+        field_types: Vec<LocalTypeIndex>,
+        next_field: usize,
+    },
+    Enum {
+        type_index: LocalTypeIndex,
+        variants: BTreeMap<u8, Vec<LocalTypeIndex>>,
+        field_types: Option<Vec<LocalTypeIndex>>,
+        next_field: usize,
+    },
+    Array {
+        element_type: LocalTypeIndex,
+        element_kind_written: bool,
+    },
+    Map {
+        key_type: LocalTypeIndex,
+        value_type: LocalTypeIndex,
+        kinds_written: u8,
+        next_is_key: bool,
+    },
+}
+
+impl<'s, E: CustomTypeExtension, Enc: Encoder<E::CustomValueKind>, const MAX_DEPTH: u8>
+    SchemaValidatingEncoder<'s, E, Enc, MAX_DEPTH>
+{
+    pub fn new(inner: Enc, schema: &'s Schema<E>, root: LocalTypeIndex) -> Self {
+        Self {
+            inner,
+            schema,
+            root: Some(root),
+            frames: Vec::new(),
+            next_value_type: None,
+            stack_depth: 0,
+        }
+    }
+
+    /// Returns the wrapped encoder.
+    pub fn into_inner(self) -> Enc {
+        self.inner
+    }
+
+    fn mismatch(&self, type_index: LocalTypeIndex) -> EncodeError {
+        let type_data = self
+            .schema
+            .resolve(type_index)
+            .expect("Schema is missing a referenced type");
+        EncodeError::SchemaMismatch {
+            type_name: type_data.metadata.type_name.to_string(),
+        }
+    }
+
+    fn check_value_kind(
+        &self,
+        type_index: LocalTypeIndex,
+        value_kind: ValueKind<E::CustomValueKind>,
+    ) -> Result<(), EncodeError> {
+        let type_data = self
+            .schema
+            .resolve(type_index)
+            .expect("Schema is missing a referenced type");
+        let matches = match (type_data.kind.as_ref(), value_kind) {
+            (TypeKind::Any, _) => true,
+            (TypeKind::Bool, ValueKind::Bool)
+            | (TypeKind::I8, ValueKind::I8)
+            | (TypeKind::I16, ValueKind::I16)
+            | (TypeKind::I32, ValueKind::I32)
+            | (TypeKind::I64, ValueKind::I64)
+            | (TypeKind::I128, ValueKind::I128)
+            | (TypeKind::U8, ValueKind::U8)
+            | (TypeKind::U16, ValueKind::U16)
+            | (TypeKind::U32, ValueKind::U32)
+            | (TypeKind::U64, ValueKind::U64)
+            | (TypeKind::U128, ValueKind::U128)
+            | (TypeKind::String, ValueKind::String)
+            | (TypeKind::Array { .. }, ValueKind::Array)
+            | (TypeKind::Tuple { .. }, ValueKind::Tuple)
+            | (TypeKind::Enum { .. }, ValueKind::Enum)
+            | (TypeKind::Map { .. }, ValueKind::Map)
+            | (TypeKind::Custom(_), ValueKind::Custom(_)) => true,
+            _ => false,
+        };
+        if matches {
+            Ok(())
+        } else {
+            Err(self.mismatch(type_index))
+        }
+    }
+
+    fn frame_for(&self, type_index: Option<LocalTypeIndex>) -> Frame {
+        let type_index = match type_index {
+            Some(type_index) => type_index,
+            None => return Frame::Unchecked,
+        };
+        let type_data = self
+            .schema
+            .resolve(type_index)
+            .expect("Schema is missing a referenced type");
+        match type_data.kind.as_ref() {
+            TypeKind::Any | TypeKind::Custom(_) => Frame::Unchecked,
+            TypeKind::Tuple { field_types } => Frame::Tuple {
+                type_index,
+                field_types: field_types.clone(),
+                next_field: 0,
+            },
+            TypeKind::Enum { variants } => Frame::Enum {
+                type_index,
+                variants: variants.clone(),
+                field_types: None,
+                next_field: 0,
+            },
+            TypeKind::Array { element_type } => Frame::Array {
+                element_type: *element_type,
+                element_kind_written: false,
+            },
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => Frame::Map {
+                key_type: *key_type,
+                value_type: *value_type,
+                kinds_written: 0,
+                next_is_key: true,
+            },
+            _ => Frame::Leaf,
+        }
+    }
+
+    /// The type of the next child of the innermost value, whose value kind is about to be written.
+    fn next_child_type(&mut self) -> Result<Option<LocalTypeIndex>, EncodeError> {
+        let next_child_type = match self.frames.last_mut() {
+            None => Ok(self.root.take()),
+            Some(Frame::Tuple {
+                type_index,
+                field_types,
+                next_field,
+            })
+            | Some(Frame::Enum {
+                type_index,
+                field_types: Some(field_types),
+                next_field,
+                ..
+            }) => match field_types.get(*next_field).cloned() {
+                Some(field_type) => {
+                    *next_field += 1;
+                    Ok(Some(field_type))
+                }
+                None => Err(*type_index),
+            },
+            // A field was written before the discriminator
+            Some(Frame::Enum { type_index, .. }) => Err(*type_index),
+            Some(_) => Ok(None),
+        };
+        next_child_type.map_err(|type_index| self.mismatch(type_index))
+    }
+
+    #[inline]
+    fn track_stack_depth_increase(&mut self) -> Result<(), EncodeError> {
+        self.stack_depth += 1;
+        if self.stack_depth > MAX_DEPTH {
+            return Err(EncodeError::MaxDepthExceeded(MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn track_stack_depth_decrease(&mut self) -> Result<(), EncodeError> {
+        self.stack_depth -= 1;
+        Ok(())
+    }
+}
+
+impl<'s, E: CustomTypeExtension, Enc: Encoder<E::CustomValueKind>, const MAX_DEPTH: u8>
+    Encoder<E::CustomValueKind> for SchemaValidatingEncoder<'s, E, Enc, MAX_DEPTH>
+{
+    fn encode_deeper_body<T: Encode<E::CustomValueKind, Self> + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        self.track_stack_depth_increase()?;
+
+        // The bodies of array elements and map entries follow a single value kind written up front
+        let type_index = match self.next_value_type.take() {
+            Some(type_index) => type_index,
+            None => match self.frames.last_mut() {
+                Some(Frame::Array { element_type, .. }) => Some(*element_type),
+                Some(Frame::Map {
+                    key_type,
+                    value_type,
+                    next_is_key,
+                    ..
+                }) => {
+                    let type_index = if *next_is_key { *key_type } else { *value_type };
+                    *next_is_key = !*next_is_key;
+                    Some(type_index)
+                }
+                _ => None,
+            },
+        };
+        let frame = self.frame_for(type_index);
+        self.frames.push(frame);
+        value.encode_body(self)?;
+        self.frames.pop();
+
+        self.track_stack_depth_decrease()
+    }
+
+    fn write_value_kind(&mut self, ty: ValueKind<E::CustomValueKind>) -> Result<(), EncodeError> {
+        // Arrays and maps write the value kinds of their elements once, ahead of the element bodies
+        let header_type = match self.frames.last_mut() {
+            Some(Frame::Array {
+                element_type,
+                element_kind_written,
+            }) if !*element_kind_written => {
+                *element_kind_written = true;
+                Some(*element_type)
+            }
+            Some(Frame::Map {
+                key_type,
+                value_type,
+                kinds_written,
+                ..
+            }) if *kinds_written < 2 => {
+                *kinds_written += 1;
+                Some(if *kinds_written == 1 {
+                    *key_type
+                } else {
+                    *value_type
+                })
+            }
+            _ => None,
+        };
+
+        match header_type {
+            Some(type_index) => self.check_value_kind(type_index, ty)?,
+            None => {
+                let type_index = self.next_child_type()?;
+                if let Some(type_index) = type_index {
+                    self.check_value_kind(type_index, ty)?;
+                }
+                self.next_value_type = Some(type_index);
+            }
+        }
+
+        self.inner.write_value_kind(ty)
+    }
+
+    fn write_discriminator(&mut self, discriminator: u8) -> Result<(), EncodeError> {
+        let mismatched_type = match self.frames.last_mut() {
+            Some(Frame::Enum {
+                type_index,
+                variants,
+                field_types,
+                ..
+            }) => match (field_types.is_none(), variants.get(&discriminator).cloned()) {
+                (true, Some(variant_field_types)) => {
+                    *field_types = Some(variant_field_types);
+                    None
+                }
+                _ => Some(*type_index),
+            },
+            _ => None,
+        };
+        if let Some(type_index) = mismatched_type {
+            return Err(self.mismatch(type_index));
+        }
+
+        self.inner.write_discriminator(discriminator)
+    }
+
+    fn write_size(&mut self, size: usize) -> Result<(), EncodeError> {
+        // The only size written directly within a tuple or an enum variant is its field count
+        let expected = match self.frames.last() {
+            Some(Frame::Tuple {
+                type_index,
+                field_types,
+                next_field: 0,
+            })
+            | Some(Frame::Enum {
+                type_index,
+                field_types: Some(field_types),
+                next_field: 0,
+                ..
+            }) => Some((*type_index, field_types.len())),
+            _ => None,
+        };
+        if let Some((type_index, field_count)) = expected {
+            if size != field_count {
+                return Err(self.mismatch(type_index));
+            }
+        }
+
+        self.inner.write_size(size)
+    }
+
+    #[inline]
+    fn write_byte(&mut self, n: u8) -> Result<(), EncodeError> {
+        self.inner.write_byte(n)
+    }
+
+    #[inline]
+    fn write_slice(&mut self, slice: &[u8]) -> Result<(), EncodeError> {
+        self.inner.write_slice(slice)
+    }
+}
@@ -0,0 +1,835 @@
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::rule;
+use radix_engine_interface::time::Instant;
+use transaction::builder::ManifestBuilder;
+use transaction::model::TransactionManifest;
+
+use crate::TestRunner;
+
+/// A test fixture owning a [`TestRunner`] with a single access controller whose primary,
+/// recovery and confirmation roles are each guarded by a dedicated fungible badge held in the
+/// fixture's account.
+///
+/// The role helpers build a manifest which creates a proof of the acting role's badge before
+/// calling the access controller, so tests only need to state which role is acting.
+pub struct AccessControllerTestFixture {
+    pub test_runner: TestRunner,
+
+    pub account: (ComponentAddress, PublicKey),
+
+    pub access_controller_component_address: ComponentAddress,
+    pub primary_role_badge: ResourceAddress,
+    pub recovery_role_badge: ResourceAddress,
+    pub confirmation_role_badge: ResourceAddress,
+
+    /// A non-fungible resource whose non-fungibles can be made recovery delegates.
+    pub delegate_badge: ResourceAddress,
+
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+}
+
+impl AccessControllerTestFixture {
+    pub fn new(timed_recovery_delay_in_minutes: Option<u32>) -> Self {
+        Self::new_with_primary_role_lock_grace_period(timed_recovery_delay_in_minutes, None)
+    }
+
+    pub fn new_with_primary_role_lock_grace_period(
+        timed_recovery_delay_in_minutes: Option<u32>,
+        primary_role_lock_grace_period_in_minutes: Option<u32>,
+    ) -> Self {
+        Self::new_with_config(
+            timed_recovery_delay_in_minutes,
+            primary_role_lock_grace_period_in_minutes,
+            None,
+        )
+    }
+
+    pub fn new_with_minimum_recovery_interval(
+        timed_recovery_delay_in_minutes: Option<u32>,
+        minimum_recovery_interval_in_minutes: Option<u32>,
+    ) -> Self {
+        Self::new_with_config(
+            timed_recovery_delay_in_minutes,
+            None,
+            minimum_recovery_interval_in_minutes,
+        )
+    }
+
+    pub fn new_with_config(
+        timed_recovery_delay_in_minutes: Option<u32>,
+        primary_role_lock_grace_period_in_minutes: Option<u32>,
+        minimum_recovery_interval_in_minutes: Option<u32>,
+    ) -> Self {
+        let mut test_runner = TestRunner::builder().build();
+
+        // Creating a new account - this is where the badges will be held
+        let (public_key, _, account_component) = test_runner.new_account(false);
+
+        // Creating the resource to be protected
+        let controlled_asset = test_runner.create_fungible_resource(1.into(), 0, account_component);
+
+        // Creating three badges for the three roles.
+        let primary_role_badge =
+            test_runner.create_fungible_resource(1.into(), 0, account_component);
+        let recovery_role_badge =
+            test_runner.create_fungible_resource(1.into(), 0, account_component);
+        let confirmation_role_badge =
+            test_runner.create_fungible_resource(1.into(), 0, account_component);
+        let delegate_badge = test_runner.create_non_fungible_resource(account_component);
+
+        // Creating the access controller component
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account_component, 10.into())
+            .withdraw_from_account(account_component, controlled_asset)
+            .take_from_worktop(controlled_asset, |builder, bucket| {
+                builder.create_access_controller(
+                    bucket,
+                    rule!(require(primary_role_badge)),
+                    rule!(require(recovery_role_badge)),
+                    rule!(require(confirmation_role_badge)),
+                    timed_recovery_delay_in_minutes,
+                    AccessControllerSettings {
+                        primary_role_lock_grace_period_in_minutes,
+                        minimum_recovery_interval_in_minutes,
+                        ..Default::default()
+                    },
+                )
+            })
+            .build();
+        let receipt = test_runner.execute_manifest(
+            manifest,
+            [NonFungibleGlobalId::from_public_key(&public_key)].into(),
+        );
+        receipt.expect_commit_success();
+
+        let access_controller_component_address =
+            receipt.new_component_addresses().get(0).unwrap().clone();
+
+        Self {
+            test_runner,
+            account: (account_component, public_key.into()),
+
+            access_controller_component_address,
+            primary_role_badge,
+            recovery_role_badge,
+            confirmation_role_badge,
+            delegate_badge,
+
+            timed_recovery_delay_in_minutes,
+        }
+    }
+
+    /// Returns the badge which satisfies the given role's rule in the initial rule set.
+    pub fn role_badge(&self, role: Role) -> ResourceAddress {
+        match role {
+            Role::Primary => self.primary_role_badge,
+            Role::Recovery => self.recovery_role_badge,
+            Role::Confirmation => self.confirmation_role_badge,
+        }
+    }
+
+    /// Returns the rule set the access controller was created with.
+    pub fn initial_rule_set(&self) -> RuleSet {
+        RuleSet {
+            primary_role: rule!(require(self.primary_role_badge)),
+            recovery_role: rule!(require(self.recovery_role_badge)),
+            confirmation_role: rule!(require(self.confirmation_role_badge)),
+        }
+    }
+
+    pub fn create_proof(&mut self, as_role: Role) -> TransactionReceipt {
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                "create_proof",
+                scrypto_encode(&AccessControllerCreateProofMethodArgs {}).unwrap(),
+            )
+            .pop_from_auth_zone(|builder, _| builder)
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn initiate_recovery(
+        &mut self,
+        as_role: Role,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> TransactionReceipt {
+        let method_name = match as_role {
+            Role::Primary => AccessControllerFn::InitiateRecoveryAsPrimary,
+            Role::Recovery => AccessControllerFn::InitiateRecoveryAsRecovery,
+            Role::Confirmation => panic!("Confirmation Role can't initiate recovery!"),
+        };
+
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                &method_name.to_string(),
+                scrypto_encode(&AccessControllerInitiateRecoveryAsPrimaryMethodArgs {
+                    rule_set: RuleSet {
+                        primary_role: proposed_primary_role,
+                        recovery_role: proposed_recovery_role,
+                        confirmation_role: proposed_confirmation_role,
+                    },
+                    timed_recovery_delay_in_minutes,
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn quick_confirm_recovery(
+        &mut self,
+        as_role: Role,
+        proposer: Role,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> TransactionReceipt {
+        let proposal_nonce = self.recovery_proposal_nonce(Self::proposer(proposer));
+        self.quick_confirm_recovery_with_nonce(
+            as_role,
+            proposer,
+            proposed_primary_role,
+            proposed_recovery_role,
+            proposed_confirmation_role,
+            timed_recovery_delay_in_minutes,
+            proposal_nonce,
+        )
+    }
+
+    pub fn quick_confirm_recovery_with_nonce(
+        &mut self,
+        as_role: Role,
+        proposer: Role,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+        proposal_nonce: u64,
+    ) -> TransactionReceipt {
+        let proposer = Self::proposer(proposer);
+
+        let method_name = match proposer {
+            Proposer::Primary => AccessControllerFn::QuickConfirmPrimaryRoleRecoveryProposal,
+            Proposer::Recovery => AccessControllerFn::QuickConfirmRecoveryRoleRecoveryProposal,
+        };
+
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                &method_name.to_string(),
+                scrypto_encode(
+                    &AccessControllerQuickConfirmPrimaryRoleRecoveryProposalMethodArgs {
+                        rule_set: RuleSet {
+                            primary_role: proposed_primary_role,
+                            recovery_role: proposed_recovery_role,
+                            confirmation_role: proposed_confirmation_role,
+                        },
+                        timed_recovery_delay_in_minutes,
+                        proposal_nonce,
+                    },
+                )
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn batch_confirm_recovery(
+        &mut self,
+        as_roles: &[Role],
+        proposer: Role,
+        confirmors: Vec<Role>,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> TransactionReceipt {
+        let proposer = Self::proposer(proposer);
+        let proposal_nonce = self.recovery_proposal_nonce(proposer);
+        let manifest = self
+            .multi_role_manifest_builder(as_roles)
+            .call_method(
+                self.access_controller_component_address,
+                &AccessControllerFn::BatchConfirmRecovery.to_string(),
+                scrypto_encode(&AccessControllerBatchConfirmRecoveryMethodArgs {
+                    proposer,
+                    confirmors,
+                    rule_set: RuleSet {
+                        primary_role: proposed_primary_role,
+                        recovery_role: proposed_recovery_role,
+                        confirmation_role: proposed_confirmation_role,
+                    },
+                    timed_recovery_delay_in_minutes,
+                    proposal_nonce,
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    /// Proposes the rule set currently in place as primary and quick-confirms it as recovery,
+    /// returning the receipt of the confirmation.
+    pub fn recover_with_current_rule_set(&mut self) -> TransactionReceipt {
+        let primary_role = rule!(require(self.primary_role_badge));
+        let recovery_role = rule!(require(self.recovery_role_badge));
+        let confirmation_role = rule!(require(self.confirmation_role_badge));
+
+        self.initiate_recovery(
+            Role::Primary,
+            primary_role.clone(),
+            recovery_role.clone(),
+            confirmation_role.clone(),
+            self.timed_recovery_delay_in_minutes,
+        )
+        .expect_commit_success();
+        self.quick_confirm_recovery(
+            Role::Recovery,
+            Role::Primary,
+            primary_role,
+            recovery_role,
+            confirmation_role,
+            self.timed_recovery_delay_in_minutes,
+        )
+    }
+
+    pub fn timed_confirm_recovery(
+        &mut self,
+        as_role: Role,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> TransactionReceipt {
+        let method_name = AccessControllerFn::TimedConfirmRecovery;
+        let proposal_nonce = self.recovery_proposal_nonce(Proposer::Recovery);
+
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                &method_name.to_string(),
+                scrypto_encode(&AccessControllerTimedConfirmRecoveryMethodArgs {
+                    rule_set: RuleSet {
+                        primary_role: proposed_primary_role,
+                        recovery_role: proposed_recovery_role,
+                        confirmation_role: proposed_confirmation_role,
+                    },
+                    timed_recovery_delay_in_minutes,
+                    proposal_nonce,
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn cancel_recovery(&mut self, as_role: Role) -> TransactionReceipt {
+        let method_name = match as_role {
+            Role::Primary => AccessControllerFn::CancelPrimaryRoleRecoveryProposal,
+            Role::Recovery => AccessControllerFn::CancelRecoveryRoleRecoveryProposal,
+            Role::Confirmation => panic!("No method for the given role"),
+        };
+
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                &method_name.to_string(),
+                scrypto_encode(&AccessControllerCancelPrimaryRoleRecoveryProposalMethodArgs)
+                    .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn lock_primary_role(&mut self, as_role: Role) -> TransactionReceipt {
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                "lock_primary_role",
+                scrypto_encode(&AccessControllerLockPrimaryRoleMethodArgs {}).unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn unlock_primary_role(&mut self, as_role: Role) -> TransactionReceipt {
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                "unlock_primary_role",
+                scrypto_encode(&AccessControllerUnlockPrimaryRoleMethodArgs {}).unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn stop_timed_recovery(
+        &mut self,
+        as_role: Role,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> TransactionReceipt {
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                "stop_timed_recovery",
+                scrypto_encode(&AccessControllerStopTimedRecoveryMethodArgs {
+                    rule_set: RuleSet {
+                        primary_role: proposed_primary_role,
+                        recovery_role: proposed_recovery_role,
+                        confirmation_role: proposed_confirmation_role,
+                    },
+                    timed_recovery_delay_in_minutes,
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn extend_timed_recovery(
+        &mut self,
+        as_role: Role,
+        additional_minutes: i64,
+    ) -> TransactionReceipt {
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                "extend_timed_recovery",
+                scrypto_encode(&AccessControllerExtendTimedRecoveryMethodArgs {
+                    additional_minutes,
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn export_state(&mut self) -> AccessControllerStateSnapshot {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "export_state",
+                scrypto_encode(&AccessControllerExportStateMethodArgs).unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    /// The nonce which a confirmation of the current recovery proposal of the proposer must
+    /// reference, as read from the exported state of the controller.
+    pub fn recovery_proposal_nonce(&mut self, proposer: Proposer) -> u64 {
+        self.export_state()
+            .recovery_proposal_nonces
+            .get(&proposer)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn get_config(&mut self) -> AccessControllerConfig {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "get_config",
+                scrypto_encode(&AccessControllerGetConfigMethodArgs).unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn get_timing_info(&mut self) -> AccessControllerTimingInfo {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "get_timing_info",
+                scrypto_encode(&AccessControllerGetTimingInfoMethodArgs).unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn get_proposal_authors(&mut self) -> BTreeMap<Proposer, Option<NonFungibleGlobalId>> {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "get_proposal_authors",
+                scrypto_encode(&AccessControllerGetProposalAuthorsMethodArgs).unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn get_timeline(&mut self) -> AccessControllerTimeline {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "get_timeline",
+                scrypto_encode(&AccessControllerGetTimelineMethodArgs).unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn get_default_rule(&mut self) -> AccessControllerDefaultRule {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "get_default_rule",
+                scrypto_encode(&AccessControllerGetDefaultRuleMethodArgs).unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn get_role_rule(&mut self, role: Role) -> AccessRule {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "get_role_rule",
+                scrypto_encode(&AccessControllerGetRoleRuleMethodArgs { role }).unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn validate_proposal(
+        &mut self,
+        proposer: Proposer,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> ProposalValidationOutcome {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "validate_proposal",
+                scrypto_encode(&AccessControllerValidateProposalMethodArgs {
+                    proposer,
+                    rule_set: RuleSet {
+                        primary_role: proposed_primary_role,
+                        recovery_role: proposed_recovery_role,
+                        confirmation_role: proposed_confirmation_role,
+                    },
+                    timed_recovery_delay_in_minutes,
+                })
+                .unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn get_controlled_resource_manager(&mut self) -> ControlledResourceManagerInfo {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "get_controlled_resource_manager",
+                scrypto_encode(&AccessControllerGetControlledResourceManagerMethodArgs).unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn verify_controlled_asset(
+        &mut self,
+        expected_resource: ResourceAddress,
+        expected_amount: Decimal,
+    ) -> bool {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "verify_controlled_asset",
+                scrypto_encode(&AccessControllerVerifyControlledAssetMethodArgs {
+                    expected_resource,
+                    expected_amount,
+                })
+                .unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn get_controlled_vaults(&mut self) -> Vec<VaultId> {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "get_controlled_vaults",
+                scrypto_encode(&AccessControllerGetControlledVaultsMethodArgs).unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn transfer_ownership(
+        &mut self,
+        as_roles: &[Role],
+        rule_set: RuleSet,
+    ) -> TransactionReceipt {
+        let manifest = self
+            .multi_role_manifest_builder(as_roles)
+            .call_method(
+                self.access_controller_component_address,
+                "transfer_ownership",
+                scrypto_encode(&AccessControllerTransferOwnershipMethodArgs { rule_set }).unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn pause(&mut self, as_roles: &[Role]) -> TransactionReceipt {
+        let manifest = self
+            .multi_role_manifest_builder(as_roles)
+            .call_method(
+                self.access_controller_component_address,
+                "pause",
+                scrypto_encode(&AccessControllerPauseMethodArgs).unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn resume(&mut self, as_roles: &[Role]) -> TransactionReceipt {
+        let manifest = self
+            .multi_role_manifest_builder(as_roles)
+            .call_method(
+                self.access_controller_component_address,
+                "resume",
+                scrypto_encode(&AccessControllerResumeMethodArgs).unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn check_role(&mut self, proof_role: Role, role: Role) -> bool {
+        let manifest = self
+            .manifest_builder(proof_role)
+            .pop_from_auth_zone(|builder, proof_id| {
+                builder.call_method(
+                    self.access_controller_component_address,
+                    "check_role",
+                    args!(proof_id, role),
+                )
+            })
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        let (is_satisfied, _): (bool, Proof) = receipt.output(2);
+        is_satisfied
+    }
+
+    pub fn can_act_as(&mut self, non_fungible_global_id: NonFungibleGlobalId, role: Role) -> bool {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.access_controller_component_address,
+                "can_act_as",
+                scrypto_encode(&AccessControllerCanActAsMethodArgs {
+                    non_fungible_global_id,
+                    role,
+                })
+                .unwrap(),
+            )
+            .build();
+        let receipt = self.execute_manifest(manifest);
+        receipt.output(0)
+    }
+
+    pub fn add_recovery_delegate(&mut self, as_role: Role, badge_id: u64) -> TransactionReceipt {
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                &AccessControllerFn::AddRecoveryDelegate.to_string(),
+                scrypto_encode(&AccessControllerAddRecoveryDelegateMethodArgs {
+                    delegate: self.recovery_delegate(badge_id),
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn remove_recovery_delegate(&mut self, as_role: Role, badge_id: u64) -> TransactionReceipt {
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_component_address,
+                &AccessControllerFn::RemoveRecoveryDelegate.to_string(),
+                scrypto_encode(&AccessControllerRemoveRecoveryDelegateMethodArgs {
+                    delegate: self.recovery_delegate(badge_id),
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn initiate_recovery_as_delegate(
+        &mut self,
+        badge_id: u64,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> TransactionReceipt {
+        let manifest = self
+            .delegate_manifest_builder(badge_id)
+            .call_method(
+                self.access_controller_component_address,
+                &AccessControllerFn::InitiateRecoveryAsDelegate.to_string(),
+                scrypto_encode(&AccessControllerInitiateRecoveryAsDelegateMethodArgs {
+                    rule_set: RuleSet {
+                        primary_role: proposed_primary_role,
+                        recovery_role: proposed_recovery_role,
+                        confirmation_role: proposed_confirmation_role,
+                    },
+                    timed_recovery_delay_in_minutes,
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn quick_confirm_recovery_as_delegate(
+        &mut self,
+        badge_id: u64,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> TransactionReceipt {
+        let proposal_nonce = self.recovery_proposal_nonce(Proposer::Recovery);
+        let manifest = self
+            .delegate_manifest_builder(badge_id)
+            .call_method(
+                self.access_controller_component_address,
+                &AccessControllerFn::QuickConfirmRecoveryRoleRecoveryProposal.to_string(),
+                scrypto_encode(
+                    &AccessControllerQuickConfirmRecoveryRoleRecoveryProposalMethodArgs {
+                        rule_set: RuleSet {
+                            primary_role: proposed_primary_role,
+                            recovery_role: proposed_recovery_role,
+                            confirmation_role: proposed_confirmation_role,
+                        },
+                        timed_recovery_delay_in_minutes,
+                        proposal_nonce,
+                    },
+                )
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn timed_confirm_recovery_as_delegate(
+        &mut self,
+        badge_id: u64,
+        proposed_primary_role: AccessRule,
+        proposed_recovery_role: AccessRule,
+        proposed_confirmation_role: AccessRule,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> TransactionReceipt {
+        let proposal_nonce = self.recovery_proposal_nonce(Proposer::Recovery);
+        let manifest = self
+            .delegate_manifest_builder(badge_id)
+            .call_method(
+                self.access_controller_component_address,
+                &AccessControllerFn::TimedConfirmRecovery.to_string(),
+                scrypto_encode(&AccessControllerTimedConfirmRecoveryMethodArgs {
+                    rule_set: RuleSet {
+                        primary_role: proposed_primary_role,
+                        recovery_role: proposed_recovery_role,
+                        confirmation_role: proposed_confirmation_role,
+                    },
+                    timed_recovery_delay_in_minutes,
+                    proposal_nonce,
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn execute_manifest(&mut self, manifest: TransactionManifest) -> TransactionReceipt {
+        self.test_runner.execute_manifest_ignoring_fee(
+            manifest,
+            [NonFungibleGlobalId::from_public_key(&self.account.1)].into(),
+        )
+    }
+
+    fn manifest_builder(&self, role: Role) -> ManifestBuilder {
+        let mut manifest_builder = ManifestBuilder::new();
+        manifest_builder.create_proof_from_account(self.account.0, self.role_badge(role));
+        manifest_builder
+    }
+
+    fn multi_role_manifest_builder(&self, roles: &[Role]) -> ManifestBuilder {
+        let mut manifest_builder = ManifestBuilder::new();
+        for role in roles {
+            manifest_builder.create_proof_from_account(self.account.0, self.role_badge(*role));
+        }
+        manifest_builder
+    }
+
+    fn delegate_manifest_builder(&self, badge_id: u64) -> ManifestBuilder {
+        let mut manifest_builder = ManifestBuilder::new();
+        manifest_builder.create_proof_from_account_by_ids(
+            self.account.0,
+            &[NonFungibleLocalId::integer(badge_id)].into(),
+            self.delegate_badge,
+        );
+        manifest_builder
+    }
+
+    fn proposer(role: Role) -> Proposer {
+        match role {
+            Role::Primary => Proposer::Primary,
+            Role::Recovery => Proposer::Recovery,
+            Role::Confirmation => panic!("Confirmation is not a valid proposer"),
+        }
+    }
+
+    pub fn recovery_delegate(&self, badge_id: u64) -> NonFungibleGlobalId {
+        NonFungibleGlobalId::new(self.delegate_badge, NonFungibleLocalId::integer(badge_id))
+    }
+
+    pub fn current_time(&mut self) -> Instant {
+        self.test_runner.get_current_time(TimePrecision::Minute)
+    }
+
+    /// Moves the ledger clock forward by the given number of minutes.
+    pub fn advance_clock(&mut self, minutes: i64) {
+        let current_time = self.test_runner.get_current_time(TimePrecision::Minute);
+        let new_time = current_time.add_minutes(minutes).unwrap();
+        self.test_runner
+            .set_current_time(new_time.seconds_since_unix_epoch * 1000);
+    }
+}
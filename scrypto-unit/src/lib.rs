@@ -1,3 +1,5 @@
+mod access_controller;
 mod test_runner;
 
+pub use access_controller::*;
 pub use test_runner::*;
@@ -5,7 +5,7 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use radix_engine::engine::RuntimeError;
-use radix_engine::engine::{KernelError, ModuleError, ScryptoInterpreter};
+use radix_engine::engine::{FeeReserveCheck, KernelError, ModuleError, ScryptoInterpreter};
 use radix_engine::ledger::*;
 use radix_engine::model::{
     export_abi, export_abi_by_component, extract_abi, GlobalAddressSubstate, MetadataSubstate,
@@ -108,6 +108,7 @@ pub struct TestRunnerBuilder {
     custom_genesis: Option<SystemTransaction>,
     trace: bool,
     state_hashing: bool,
+    fee_reserve_check: Option<FeeReserveCheck>,
 }
 
 impl TestRunnerBuilder {
@@ -126,12 +127,19 @@ impl TestRunnerBuilder {
         self
     }
 
+    /// Sets a check which is run before every WASM invocation after genesis.
+    pub fn with_fee_reserve_check(mut self, fee_reserve_check: FeeReserveCheck) -> Self {
+        self.fee_reserve_check = Some(fee_reserve_check);
+        self
+    }
+
     pub fn build(self) -> TestRunner {
         let mut runner = TestRunner {
             scrypto_interpreter: ScryptoInterpreter {
                 wasm_metering_config: WasmMeteringConfig::V0,
                 wasm_engine: DefaultWasmEngine::default(),
                 wasm_instrumenter: WasmInstrumenter::default(),
+                fee_reserve_check: None,
             },
             substate_store: TypedInMemorySubstateStore::new(),
             state_hash_support: Some(self.state_hashing)
@@ -151,6 +159,7 @@ impl TestRunnerBuilder {
             &ExecutionConfig::default(),
         );
         receipt.expect_commit_success();
+        runner.scrypto_interpreter.fee_reserve_check = self.fee_reserve_check;
         runner
     }
 }
@@ -171,6 +180,7 @@ impl TestRunner {
             custom_genesis: None,
             trace: true,
             state_hashing: false,
+            fee_reserve_check: None,
         }
     }
 
@@ -854,6 +864,25 @@ impl TestRunner {
         (auth_resource_address, resource_address)
     }
 
+    /// Creates a token which nobody can withdraw, but whose withdraw rule can still be updated by the holder of the
+    /// returned admin badge, so that the token counts as frozen.
+    pub fn create_frozen_token(
+        &mut self,
+        account: ComponentAddress,
+    ) -> (ResourceAddress, ResourceAddress) {
+        let admin_auth = self.create_non_fungible_resource(account);
+
+        let mut access_rules = BTreeMap::new();
+        access_rules.insert(
+            ResourceMethodAuthKey::Withdraw,
+            (rule!(deny_all), MUTABLE(rule!(require(admin_auth)))),
+        );
+        access_rules.insert(ResourceMethodAuthKey::Deposit, (rule!(allow_all), LOCKED));
+        let resource_address = self.create_fungible_resource_and_deposit(access_rules, account);
+
+        (admin_auth, resource_address)
+    }
+
     pub fn create_non_fungible_resource(&mut self, account: ComponentAddress) -> ResourceAddress {
         let mut access_rules = BTreeMap::new();
         access_rules.insert(ResourceMethodAuthKey::Withdraw, (rule!(allow_all), LOCKED));
@@ -1097,6 +1126,7 @@ pub fn is_wasm_error(e: &RuntimeError) -> bool {
     matches!(
         e,
         RuntimeError::KernelError(KernelError::WasmRuntimeError(..))
+            | RuntimeError::WasmTrap { .. }
     )
 }
 
@@ -151,6 +151,16 @@ impl Component {
             .unwrap()
     }
 
+    /// Globalize with the given access rules added to the component's access rules chain.
+    pub fn globalize_with_access_rules(self, access_rules: AccessRules) -> ComponentAddress {
+        ScryptoEnv
+            .invoke(ComponentGlobalizeWithAccessRulesInvocation {
+                component_id: self.0,
+                access_rules,
+            })
+            .unwrap()
+    }
+
     /// Returns the layers of access rules on this component.
     pub fn access_rules_chain(&self) -> Vec<ComponentAccessRules> {
         let mut env = ScryptoEnv;
@@ -70,6 +70,14 @@ impl EngineApi<EngineApiError> for ScryptoEnv {
         Ok(())
     }
 
+    fn sys_drop_nodes(&mut self, node_ids: Vec<RENodeId>) -> Result<(), EngineApiError> {
+        let node_ids = scrypto_encode(&node_ids).unwrap();
+
+        unsafe { drop_nodes(node_ids.as_ptr(), node_ids.len()) };
+
+        Ok(())
+    }
+
     fn sys_get_visible_nodes(&mut self) -> Result<Vec<RENodeId>, EngineApiError> {
         let node_ids = copy_buffer(unsafe { get_visible_nodes() });
 
@@ -104,6 +112,18 @@ impl EngineApi<EngineApiError> for ScryptoEnv {
         Ok(substate)
     }
 
+    fn sys_read_field(
+        &mut self,
+        lock_handle: LockHandle,
+        path: Vec<usize>,
+    ) -> Result<Vec<u8>, EngineApiError> {
+        let path = scrypto_encode(&path).unwrap();
+        let field =
+            copy_buffer(unsafe { read_substate_field(lock_handle, path.as_ptr(), path.len()) });
+
+        Ok(field)
+    }
+
     fn sys_write(
         &mut self,
         lock_handle: LockHandle,
@@ -114,6 +134,16 @@ impl EngineApi<EngineApiError> for ScryptoEnv {
         Ok(())
     }
 
+    fn sys_write_batch(
+        &mut self,
+        writes: Vec<(LockHandle, Vec<u8>)>,
+    ) -> Result<(), EngineApiError> {
+        let writes = scrypto_encode(&writes).unwrap();
+        unsafe { write_substate_batch(writes.as_ptr(), writes.len()) };
+
+        Ok(())
+    }
+
     fn sys_drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), EngineApiError> {
         unsafe { unlock_substate(lock_handle) };
 
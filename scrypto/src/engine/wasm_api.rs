@@ -65,6 +65,9 @@ extern "C" {
     /// Destroys a node.
     pub fn drop_node(node_id_ptr: *const u8, node_id_len: usize);
 
+    /// Destroys several nodes, stopping at the first one which can't be destroyed.
+    pub fn drop_nodes(node_ids_ptr: *const u8, node_ids_len: usize);
+
     //===============
     // Substate API
     //===============
@@ -81,9 +84,15 @@ extern "C" {
     // Reads a substate
     pub fn read_substate(handle: u32) -> Buffer;
 
+    // Reads a field of a substate
+    pub fn read_substate_field(handle: u32, path_ptr: *const u8, path_len: usize) -> Buffer;
+
     // Writes into a substate
     pub fn write_substate(handle: u32, data_ptr: *const u8, data_len: usize);
 
+    // Writes into several substates, all or nothing
+    pub fn write_substate_batch(data_ptr: *const u8, data_len: usize);
+
     // Releases a lock
     pub fn unlock_substate(handle: u32);
 
@@ -132,6 +141,11 @@ pub unsafe fn drop_node(_node_id_ptr: *const u8, _node_id_len: usize) {
     todo!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn drop_nodes(_node_ids_ptr: *const u8, _node_ids_len: usize) {
+    todo!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn lock_substate(
     _node_id: *const u8,
@@ -148,9 +162,17 @@ pub unsafe fn read_substate(_handle: u32) -> Buffer {
     todo!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn read_substate_field(_handle: u32, _path_ptr: *const u8, _path_len: usize) -> Buffer {
+    todo!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn write_substate(_handle: u32, _data_ptr: *const u8, _data_len: usize) {}
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn write_substate_batch(_data_ptr: *const u8, _data_len: usize) {}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn unlock_substate(_handle: u32) {
     todo!()
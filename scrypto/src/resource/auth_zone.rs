@@ -4,7 +4,19 @@ use radix_engine_interface::api::{EngineApi, Invokable};
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
 use sbor::rust::collections::BTreeSet;
+use sbor::rust::vec::Vec;
 use scrypto::engine::scrypto_env::ScryptoEnv;
+use scrypto::resource::ScryptoVault;
+
+/// Represents an error when proving a role of an access controller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProveRoleError {
+    /// The badge vault holds no resources, so no proof can be created from it.
+    BadgeNotHeld,
+    /// The rule of the role on the access controller makes no reference to the resource of the
+    /// badge vault.
+    BadgeNotReferencedByRoleRule,
+}
 
 /// Represents the auth zone, which is used by system for checking
 /// if this component is allowed to
@@ -36,6 +48,17 @@ impl ComponentAuthZone {
         .unwrap()
     }
 
+    /// Lists the resource address and amount of every proof in the auth zone, without removing
+    /// any of the proofs.
+    pub fn list() -> Vec<(ResourceAddress, Decimal)> {
+        let mut env = ScryptoEnv;
+        let node_id = Self::auth_zone_node_id(&mut env).expect("Auth Zone doesn't exist");
+        env.invoke(AuthZoneListInvocation {
+            receiver: node_id.into(),
+        })
+        .unwrap()
+    }
+
     pub fn create_proof(resource_address: ResourceAddress) -> Proof {
         let mut env = ScryptoEnv;
         let node_id = Self::auth_zone_node_id(&mut env).expect("Auth Zone doesn't exist");
@@ -53,6 +76,21 @@ impl ComponentAuthZone {
             receiver: node_id.into(),
             amount,
             resource_address,
+            allow_zero_amount: false,
+        })
+        .unwrap()
+    }
+
+    /// Creates a zero-amount presence proof of the given resource, backed by one of the proofs in the auth zone. See
+    /// [`ProofValidationMode::ValidatePresence`] for what such a proof does and doesn't prove.
+    pub fn create_presence_proof(resource_address: ResourceAddress) -> Proof {
+        let mut env = ScryptoEnv;
+        let node_id = Self::auth_zone_node_id(&mut env).expect("Auth Zone doesn't exist");
+        env.invoke(AuthZoneCreateProofByAmountInvocation {
+            receiver: node_id.into(),
+            amount: Decimal::zero(),
+            resource_address,
+            allow_zero_amount: true,
         })
         .unwrap()
     }
@@ -81,6 +119,41 @@ impl ComponentAuthZone {
         .unwrap()
     }
 
+    /// Creates a proof of the badge held in `badge_vault` and pushes it onto the auth zone, so
+    /// that the given role of the access controller can then be exercised.
+    ///
+    /// Nothing is pushed if the role is accessible to everyone. An error is returned if the vault
+    /// is empty or if the rule of the role does not reference the resource of the vault.
+    pub fn prove_role_for_controller(
+        access_controller: ComponentAddress,
+        role: Role,
+        badge_vault: &Vault,
+    ) -> Result<(), ProveRoleError> {
+        let mut env = ScryptoEnv;
+        let role_rule = env
+            .invoke(AccessControllerGetRoleRuleInvocation {
+                receiver: access_controller,
+                role,
+            })
+            .unwrap();
+
+        let rule_node = match role_rule {
+            AccessRule::AllowAll => return Ok(()),
+            AccessRule::DenyAll => return Err(ProveRoleError::BadgeNotReferencedByRoleRule),
+            AccessRule::Protected(rule_node) => rule_node,
+        };
+
+        if badge_vault.is_empty() {
+            return Err(ProveRoleError::BadgeNotHeld);
+        }
+        if !rule_node_references_resource(&rule_node, badge_vault.resource_address()) {
+            return Err(ProveRoleError::BadgeNotReferencedByRoleRule);
+        }
+
+        Self::push(badge_vault.create_proof());
+        Ok(())
+    }
+
     fn auth_zone_node_id<Y, E>(api: &mut Y) -> Option<RENodeId>
     where
         Y: EngineApi<E>,
@@ -92,3 +165,44 @@ impl ComponentAuthZone {
             .find(|n| matches!(n, RENodeId::AuthZoneStack(..)))
     }
 }
+
+fn rule_node_references_resource(
+    rule_node: &AccessRuleNode,
+    resource_address: ResourceAddress,
+) -> bool {
+    match rule_node {
+        AccessRuleNode::ProofRule(proof_rule) => match proof_rule {
+            ProofRule::Require(resource) => {
+                soft_resource_or_non_fungible_is_resource(resource, resource_address)
+            }
+            ProofRule::AmountOf(_, SoftResource::Static(address)) => *address == resource_address,
+            ProofRule::AmountOf(_, SoftResource::Dynamic(..)) => false,
+            ProofRule::CountOf(_, list) | ProofRule::AllOf(list) | ProofRule::AnyOf(list) => {
+                match list {
+                    SoftResourceOrNonFungibleList::Static(resources) => {
+                        resources.iter().any(|resource| {
+                            soft_resource_or_non_fungible_is_resource(resource, resource_address)
+                        })
+                    }
+                    SoftResourceOrNonFungibleList::Dynamic(..) => false,
+                }
+            }
+        },
+        AccessRuleNode::AnyOf(rule_nodes) | AccessRuleNode::AllOf(rule_nodes) => rule_nodes
+            .iter()
+            .any(|rule_node| rule_node_references_resource(rule_node, resource_address)),
+    }
+}
+
+fn soft_resource_or_non_fungible_is_resource(
+    resource: &SoftResourceOrNonFungible,
+    resource_address: ResourceAddress,
+) -> bool {
+    match resource {
+        SoftResourceOrNonFungible::StaticNonFungible(non_fungible_global_id) => {
+            non_fungible_global_id.resource_address() == resource_address
+        }
+        SoftResourceOrNonFungible::StaticResource(address) => *address == resource_address,
+        SoftResourceOrNonFungible::Dynamic(..) => false,
+    }
+}
@@ -11,6 +11,8 @@ pub trait ScryptoBucket {
     fn new(resource_address: ResourceAddress) -> Self;
     fn burn(self);
     fn create_proof(&self) -> Proof;
+    fn create_one_shot_proof(&self) -> Proof;
+    fn create_and_push_proof(&self);
     fn resource_address(&self) -> ResourceAddress;
     fn take_internal(&mut self, amount: Decimal) -> Bucket;
     fn take_non_fungibles(
@@ -54,6 +56,21 @@ impl ScryptoBucket for Bucket {
             .unwrap()
     }
 
+    /// Creates a proof of this bucket that can be pushed onto the auth zone only once and can't be
+    /// cloned.
+    fn create_one_shot_proof(&self) -> Proof {
+        let proof = self.create_proof();
+        let mut env = ScryptoEnv;
+        env.invoke(ProofMakeOneShotInvocation { receiver: proof.0 })
+            .unwrap();
+        proof
+    }
+
+    /// Creates a proof of this bucket and pushes it onto the auth zone.
+    fn create_and_push_proof(&self) {
+        ComponentAuthZone::push(self.create_proof());
+    }
+
     fn resource_address(&self) -> ResourceAddress {
         let mut env = ScryptoEnv;
         env.invoke(BucketGetResourceAddressInvocation { receiver: self.0 })
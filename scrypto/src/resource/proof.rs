@@ -1,13 +1,20 @@
 use radix_engine_interface::api::types::{ProofId, RENodeId};
 use radix_engine_interface::api::{EngineApi, Invokable};
-use radix_engine_interface::math::Decimal;
+use radix_engine_interface::data::{
+    scrypto_decode, scrypto_encode, ScryptoDecode, ScryptoEncode, ScryptoValue,
+};
+use radix_engine_interface::math::{Decimal, RoundingMode};
 use radix_engine_interface::model::*;
+use sbor::rust::cell::Cell;
 use sbor::rust::collections::BTreeSet;
 use sbor::rust::fmt::Debug;
+use sbor::rust::hash::{Hash, Hasher};
+use sbor::rust::string::ToString;
 use sbor::rust::vec::Vec;
 use scrypto::engine::scrypto_env::ScryptoEnv;
 use scrypto::scrypto_env_native_fn;
 
+use crate::component::KeyValueStore;
 use crate::resource::*;
 use crate::*;
 
@@ -19,9 +26,35 @@ pub trait ScryptoProof: Sized {
     ) -> Result<ValidatedProof, (Self, ProofValidationError)>
     where
         T: Into<ProofValidationMode>;
+    fn validate_into_parts<T>(
+        self,
+        validation_mode: T,
+    ) -> Result<(ValidatedProof, ResourceAddress, Decimal), (Self, ProofValidationError)>
+    where
+        T: Into<ProofValidationMode>;
+    fn validate_proof_non_empty<T>(
+        self,
+        validation_mode: T,
+    ) -> Result<ValidatedProof, (Self, ProofValidationError)>
+    where
+        T: Into<ProofValidationMode>;
+    fn validate_with_excess(
+        self,
+        resource_address: ResourceAddress,
+        required_amount: Decimal,
+    ) -> Result<(ValidatedProof, Decimal), (Self, ProofValidationError)>;
     fn unsafe_skip_proof_validation(self) -> ValidatedProof;
     fn from_validated_proof(validated_proof: ValidatedProof) -> Self;
     fn validate(&self, validation_mode: ProofValidationMode) -> Result<(), ProofValidationError>;
+    fn validate_all(
+        &self,
+        validation_modes: &[ProofValidationMode],
+    ) -> Vec<Result<(), ProofValidationError>>;
+    fn validate_with_query_hook<F: FnMut(ProofValidationQuery)>(
+        &self,
+        validation_mode: ProofValidationMode,
+        query_hook: F,
+    ) -> Result<(), ProofValidationError>;
     fn validate_resource_address(
         &self,
         resource_address: ResourceAddress,
@@ -30,6 +63,10 @@ pub trait ScryptoProof: Sized {
         &self,
         resource_addresses: &BTreeSet<ResourceAddress>,
     ) -> Result<(), ProofValidationError>;
+    fn validate_matches_vault(
+        &self,
+        vault_resource_address: ResourceAddress,
+    ) -> Result<(), ProofValidationError>;
     fn validate_contains_non_fungible_local_id(
         &self,
         non_fungible_local_id: &NonFungibleLocalId,
@@ -38,11 +75,48 @@ pub trait ScryptoProof: Sized {
         &self,
         expected_non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>,
     ) -> Result<(), ProofValidationError>;
+    fn validate_contains_global_id(
+        &self,
+        non_fungible_global_id: &NonFungibleGlobalId,
+    ) -> Result<(), ProofValidationError>;
     fn validate_contains_amount(&self, amount: Decimal) -> Result<(), ProofValidationError>;
+    fn validate_min_non_fungible_count(&self, required: usize) -> Result<(), ProofValidationError>;
+    fn validate_non_empty(&self) -> Result<(), ProofValidationError>;
+    fn validate_not_presence(&self) -> Result<(), ProofValidationError>;
+    fn validate_contains_amount_with_symbol(
+        &self,
+        symbol: &str,
+        amount: Decimal,
+    ) -> Result<(), ProofValidationError>;
+    fn validate_amount_divisibility(&self, amount: Decimal) -> Result<(), ProofValidationError>;
+    fn validate_resource_behavior(
+        &self,
+        required: BehaviorFlags,
+    ) -> Result<(), ProofValidationError>;
+    fn validate_not_frozen(&self) -> Result<(), ProofValidationError>;
+    fn validate_non_fungible_data_field(
+        &self,
+        field: &str,
+        is_mutable: bool,
+        index: usize,
+        expected: &[u8],
+    ) -> Result<(), ProofValidationError>;
+    fn validate_controlled_by(
+        &self,
+        access_controller: ComponentAddress,
+    ) -> Result<(), ProofValidationError>;
+    fn validate_against_stored_rule<K: ScryptoEncode + ScryptoDecode>(
+        &self,
+        store: &KeyValueStore<K, AccessRule>,
+        key: &K,
+    ) -> Result<(), ProofValidationError>;
+    fn satisfied_roles(&self, rule_set: &RuleSet) -> Vec<Role>;
     fn amount(&self) -> Decimal;
     fn non_fungible_local_ids(&self) -> BTreeSet<NonFungibleLocalId>;
     fn resource_address(&self) -> ResourceAddress;
+    fn is_presence(&self) -> bool;
     fn drop(self);
+    fn drop_all(proofs: Vec<Self>);
     fn authorize<F: FnOnce() -> O, O>(&self, f: F) -> O;
 }
 
@@ -93,11 +167,67 @@ impl ScryptoProof for Proof {
     {
         let validation_mode: ProofValidationMode = validation_mode.into();
         match self.validate(validation_mode) {
-            Ok(()) => Ok(ValidatedProof(self)),
+            Ok(()) => Ok(ValidatedProof::new(self)),
             Err(error) => Err((self, error)),
         }
     }
 
+    /// Validates a `Proof` in the same way as [`ScryptoProof::validate_proof`], additionally returning the resource
+    /// address and amount of the proof on success. These are the values read while validating, so they aren't read
+    /// again; whichever of the two the validation mode doesn't need is read once afterwards.
+    fn validate_into_parts<T>(
+        self,
+        validation_mode: T,
+    ) -> Result<(ValidatedProof, ResourceAddress, Decimal), (Self, ProofValidationError)>
+    where
+        T: Into<ProofValidationMode>,
+    {
+        let validation_mode: ProofValidationMode = validation_mode.into();
+        let reads = ProofReads::default();
+        match validate_recording_reads(&self, validation_mode, |_| {}, &reads) {
+            Ok(()) => {
+                let resource_address = reads.resource_address(&self);
+                let amount = reads.amount(&self);
+                let validated_proof = ValidatedProof(self, Cell::new(Some(resource_address)));
+                Ok((validated_proof, resource_address, amount))
+            }
+            Err(error) => Err((self, error)),
+        }
+    }
+
+    /// Validates a `Proof` in the same way as [`ScryptoProof::validate_proof`], additionally requiring the proof to
+    /// be of a non-zero amount.
+    fn validate_proof_non_empty<T>(
+        self,
+        validation_mode: T,
+    ) -> Result<ValidatedProof, (Self, ProofValidationError)>
+    where
+        T: Into<ProofValidationMode>,
+    {
+        let validation_mode: ProofValidationMode = validation_mode.into();
+        match self
+            .validate(validation_mode)
+            .and_then(|()| self.validate_non_empty())
+        {
+            Ok(()) => Ok(ValidatedProof::new(self)),
+            Err(error) => Err((self, error)),
+        }
+    }
+
+    /// Validates that the `Proof` is of the given resource and contains at least `required_amount`, additionally
+    /// returning by how much the proof exceeds the required amount. As a proof can't be split, the whole proof is
+    /// returned validated, and the excess is only reported so that callers can reason about the surplus.
+    fn validate_with_excess(
+        self,
+        resource_address: ResourceAddress,
+        required_amount: Decimal,
+    ) -> Result<(ValidatedProof, Decimal), (Self, ProofValidationError)> {
+        let (validated_proof, _, amount) = self.validate_into_parts(
+            ProofValidationMode::ValidateContainsAmount(resource_address, required_amount),
+        )?;
+        Ok((validated_proof, amount - required_amount))
+    }
+
     /// Skips the validation process of the proof producing a validated proof **WITHOUT** performing any validation.
     ///
     /// # WARNING:
@@ -106,7 +236,7 @@ impl ScryptoProof for Proof {
     /// of of the returned `ValidatedProof` should **NOT** be trusted as the proof could potentially belong to any
     /// resource address. If you call this method, you should perform your own validation.
     fn unsafe_skip_proof_validation(self) -> ValidatedProof {
-        ValidatedProof(self)
+        ValidatedProof::new(self)
     }
 
     /// Converts a `ValidatedProof` into a `Proof`.
@@ -115,58 +245,53 @@ impl ScryptoProof for Proof {
     }
 
     fn validate(&self, validation_mode: ProofValidationMode) -> Result<(), ProofValidationError> {
-        match validation_mode {
-            ProofValidationMode::ValidateResourceAddress(resource_address) => {
-                self.validate_resource_address(resource_address)?;
-                Ok(())
-            }
-            ProofValidationMode::ValidateResourceAddressBelongsTo(resource_addresses) => {
-                self.validate_resource_address_belongs_to(&resource_addresses)?;
-                Ok(())
-            }
-            ProofValidationMode::ValidateContainsNonFungible(non_fungible_global_id) => {
-                self.validate_resource_address(non_fungible_global_id.resource_address())?;
-                self.validate_contains_non_fungible_local_id(non_fungible_global_id.local_id())?;
-                Ok(())
-            }
-            ProofValidationMode::ValidateContainsNonFungibles(
-                resource_address,
-                non_fungible_local_ids,
-            ) => {
-                self.validate_resource_address(resource_address)?;
-                self.validate_contains_non_fungible_local_ids(&non_fungible_local_ids)?;
-                Ok(())
-            }
-            ProofValidationMode::ValidateContainsAmount(resource_address, amount) => {
-                self.validate_resource_address(resource_address)?;
-                self.validate_contains_amount(amount)?;
-                Ok(())
-            }
-        }
+        self.validate_with_query_hook(validation_mode, |_| {})
+    }
+
+    /// Validates the `Proof` against every one of the given validation modes, returning the outcome of each of them in
+    /// the same order. Unlike [`ScryptoProof::validate`], a failing mode does not prevent the remaining ones from being
+    /// checked, so all of the failing constraints can be reported at once.
+    fn validate_all(
+        &self,
+        validation_modes: &[ProofValidationMode],
+    ) -> Vec<Result<(), ProofValidationError>> {
+        validation_modes
+            .iter()
+            .map(|validation_mode| self.validate(validation_mode.clone()))
+            .collect()
+    }
+
+    /// Validates the `Proof` in the same way as [`ScryptoProof::validate`], calling `query_hook` with every engine
+    /// query issued along the way. This allows blueprint authors to measure the cost of a validation mode.
+    fn validate_with_query_hook<F: FnMut(ProofValidationQuery)>(
+        &self,
+        validation_mode: ProofValidationMode,
+        query_hook: F,
+    ) -> Result<(), ProofValidationError> {
+        validate_recording_reads(self, validation_mode, query_hook, &ProofReads::default())
     }
 
     fn validate_resource_address(
         &self,
         resource_address: ResourceAddress,
     ) -> Result<(), ProofValidationError> {
-        if self.resource_address() == resource_address {
-            Ok(())
-        } else {
-            Err(ProofValidationError::InvalidResourceAddress(
-                resource_address,
-            ))
-        }
+        check_resource_address(self.resource_address(), resource_address)
     }
 
     fn validate_resource_address_belongs_to(
         &self,
         resource_addresses: &BTreeSet<ResourceAddress>,
     ) -> Result<(), ProofValidationError> {
-        if resource_addresses.contains(&self.resource_address()) {
-            Ok(())
-        } else {
-            Err(ProofValidationError::ResourceAddressDoesNotBelongToList)
-        }
+        check_resource_address_belongs_to(self.resource_address(), resource_addresses)
+    }
+
+    /// Validates that the `Proof` is of the resource held by a vault, e.g. before depositing what the proof backs a
+    /// claim to into the vault. This is [`ScryptoProof::validate_resource_address`] named for that intent.
+    fn validate_matches_vault(
+        &self,
+        vault_resource_address: ResourceAddress,
+    ) -> Result<(), ProofValidationError> {
+        self.validate_resource_address(vault_resource_address)
     }
 
     fn validate_contains_non_fungible_local_id(
@@ -204,14 +329,142 @@ impl ScryptoProof for Proof {
         }
     }
 
+    /// Validates that the `Proof` is of the resource of the given global id and contains its non-fungible. This
+    /// accepts the same global id type used in access rules.
+    fn validate_contains_global_id(
+        &self,
+        non_fungible_global_id: &NonFungibleGlobalId,
+    ) -> Result<(), ProofValidationError> {
+        self.validate_resource_address(non_fungible_global_id.resource_address())?;
+        self.validate_contains_non_fungible_local_id(non_fungible_global_id.local_id())
+    }
+
     fn validate_contains_amount(&self, amount: Decimal) -> Result<(), ProofValidationError> {
-        if self.amount() >= amount {
+        check_contains_amount(self.amount(), amount)
+    }
+
+    fn validate_min_non_fungible_count(&self, required: usize) -> Result<(), ProofValidationError> {
+        let actual = self.non_fungible_local_ids().len();
+        if actual >= required {
+            Ok(())
+        } else {
+            Err(ProofValidationError::InsufficientNonFungibleCount { required, actual })
+        }
+    }
+
+    fn validate_non_empty(&self) -> Result<(), ProofValidationError> {
+        if self.amount().is_positive() {
+            Ok(())
+        } else {
+            Err(ProofValidationError::EmptyProof)
+        }
+    }
+
+    /// Validates that the `Proof` is not a presence proof, which only
+    /// [`ProofValidationMode::ValidatePresence`] accepts.
+    fn validate_not_presence(&self) -> Result<(), ProofValidationError> {
+        if self.is_presence() {
+            Err(ProofValidationError::PresenceProofNotAllowed)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validates that the `Proof` is of a resource whose `symbol` metadata is `symbol` and that it contains at least
+    /// `amount`. Both constraints are checked so that the error reports every one that is not met.
+    fn validate_contains_amount_with_symbol(
+        &self,
+        symbol: &str,
+        amount: Decimal,
+    ) -> Result<(), ProofValidationError> {
+        check_contains_amount_with_symbol(self.resource_address(), self.amount(), symbol, amount)
+    }
+
+    /// Validates that `amount` has no more decimal places than the divisibility of the `Proof`'s resource allows.
+    /// Such an amount can never be held, so requiring it is almost certainly a bug on the caller's side.
+    fn validate_amount_divisibility(&self, amount: Decimal) -> Result<(), ProofValidationError> {
+        check_amount_divisibility(self.resource_address(), amount)
+    }
+
+    /// Validates that the `Proof`'s resource has every one of the `required` behaviors, as read from the access rules
+    /// of its resource manager.
+    fn validate_resource_behavior(
+        &self,
+        required: BehaviorFlags,
+    ) -> Result<(), ProofValidationError> {
+        check_resource_behavior(self.resource_address(), required)
+    }
+
+    /// Validates that the `Proof`'s resource is not currently frozen, as read from its resource manager. Resources
+    /// which don't support freezing are never frozen.
+    fn validate_not_frozen(&self) -> Result<(), ProofValidationError> {
+        check_not_frozen(self.resource_address())
+    }
+
+    /// Validates that the field at `index` of the mutable or immutable data of every non-fungible in the `Proof` encodes
+    /// to `expected`. The data is decoded without knowledge of its type, so this is normally reached through a
+    /// [`NonFungibleDataFieldValidationBuilder`], which locates the field by name.
+    fn validate_non_fungible_data_field(
+        &self,
+        field: &str,
+        is_mutable: bool,
+        index: usize,
+        expected: &[u8],
+    ) -> Result<(), ProofValidationError> {
+        check_non_fungible_data_field(
+            self.resource_address(),
+            self.non_fungible_local_ids(),
+            field,
+            is_mutable,
+            index,
+            expected,
+        )
+    }
+
+    /// Validates that the `Proof` is of the resource held in the vault of the given access controller.
+    fn validate_controlled_by(
+        &self,
+        access_controller: ComponentAddress,
+    ) -> Result<(), ProofValidationError> {
+        check_controlled_by(self.resource_address(), access_controller)
+    }
+
+    /// Validates that the `Proof` satisfies the `AccessRule` stored under the given key of a key value store. The rule
+    /// is read and evaluated by the engine, so it is never loaded into the blueprint's memory.
+    ///
+    /// # Panics
+    /// If no rule is stored under the given key.
+    fn validate_against_stored_rule<K: ScryptoEncode + ScryptoDecode>(
+        &self,
+        store: &KeyValueStore<K, AccessRule>,
+        key: &K,
+    ) -> Result<(), ProofValidationError> {
+        let mut env = ScryptoEnv;
+        let is_satisfied = env
+            .invoke(ProofValidateAgainstStoredRuleInvocation {
+                receiver: self.0,
+                kv_store_id: store.id,
+                key: scrypto_encode(key).unwrap(),
+            })
+            .unwrap();
+        if is_satisfied {
             Ok(())
         } else {
-            Err(ProofValidationError::InvalidAmount(amount))
+            Err(ProofValidationError::StoredRuleNotSatisfied)
         }
     }
 
+    /// Returns the roles of the `RuleSet` whose rule the `Proof` satisfies on its own, in the order primary, recovery,
+    /// confirmation. This allows a component embedding access controller like logic to branch on the caller's role.
+    fn satisfied_roles(&self, rule_set: &RuleSet) -> Vec<Role> {
+        let mut env = ScryptoEnv;
+        env.invoke(ProofSatisfiedRolesInvocation {
+            receiver: self.0,
+            rule_set: rule_set.clone(),
+        })
+        .unwrap()
+    }
+
     scrypto_env_native_fn! {
         fn amount(&self) -> Decimal {
             ProofGetAmountInvocation {
@@ -228,26 +481,417 @@ impl ScryptoProof for Proof {
                 receiver: self.0
             }
         }
+        fn is_presence(&self) -> bool {
+            ProofIsPresenceInvocation {
+                receiver: self.0
+            }
+        }
     }
 
     fn drop(self) {
         let mut env = ScryptoEnv;
         env.sys_drop_node(RENodeId::Proof(self.0)).unwrap()
     }
+
+    /// Drops all of the given proofs in a single call into the engine.
+    fn drop_all(proofs: Vec<Self>) {
+        let mut env = ScryptoEnv;
+        env.sys_drop_nodes(
+            proofs
+                .into_iter()
+                .map(|proof| RENodeId::Proof(proof.0))
+                .collect(),
+        )
+        .unwrap()
+    }
+}
+
+/// Validates the `Proof` against the validation mode, recording in `reads` the resource address and amount of the
+/// proof as they are read, so that each is read from the engine at most once.
+fn validate_recording_reads<F: FnMut(ProofValidationQuery)>(
+    proof: &Proof,
+    validation_mode: ProofValidationMode,
+    mut query_hook: F,
+    reads: &ProofReads,
+) -> Result<(), ProofValidationError> {
+    // Only presence proofs can be of a zero amount, so the amount, which is read at most once, tells them apart
+    // without querying the engine separately.
+    if !matches!(validation_mode, ProofValidationMode::ValidatePresence(..)) {
+        query_hook(ProofValidationQuery::Presence);
+        if reads.amount(proof).is_zero() {
+            return Err(ProofValidationError::PresenceProofNotAllowed);
+        }
+    }
+
+    match validation_mode {
+        ProofValidationMode::ValidateResourceAddress(resource_address) => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address(reads.resource_address(proof), resource_address)?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateResourceAddressBelongsTo(resource_addresses) => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address_belongs_to(reads.resource_address(proof), &resource_addresses)?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateContainsNonFungible(non_fungible_global_id) => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address(
+                reads.resource_address(proof),
+                non_fungible_global_id.resource_address(),
+            )?;
+            query_hook(ProofValidationQuery::NonFungibleLocalIds);
+            proof.validate_contains_non_fungible_local_id(non_fungible_global_id.local_id())?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateContainsNonFungibles(
+            resource_address,
+            non_fungible_local_ids,
+        ) => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address(reads.resource_address(proof), resource_address)?;
+            query_hook(ProofValidationQuery::NonFungibleLocalIds);
+            proof.validate_contains_non_fungible_local_ids(&non_fungible_local_ids)?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateContainsAmount(resource_address, amount) => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address(reads.resource_address(proof), resource_address)?;
+            query_hook(ProofValidationQuery::Divisibility);
+            check_amount_divisibility(reads.resource_address(proof), amount)?;
+            query_hook(ProofValidationQuery::Amount);
+            check_contains_amount(reads.amount(proof), amount)?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateMinNonFungibleCount(resource_address, required) => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address(reads.resource_address(proof), resource_address)?;
+            query_hook(ProofValidationQuery::NonFungibleLocalIds);
+            proof.validate_min_non_fungible_count(required)?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateControlledBy(access_controller) => {
+            query_hook(ProofValidationQuery::ControlledResource);
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_controlled_by(reads.resource_address(proof), access_controller)?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateContainsAmountWithSymbol { symbol, amount } => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            query_hook(ProofValidationQuery::Metadata);
+            query_hook(ProofValidationQuery::Amount);
+            check_contains_amount_with_symbol(
+                reads.resource_address(proof),
+                reads.amount(proof),
+                &symbol,
+                amount,
+            )?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateResourceBehavior(resource_address, behavior) => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address(reads.resource_address(proof), resource_address)?;
+            query_hook(ProofValidationQuery::Behavior);
+            check_resource_behavior(reads.resource_address(proof), behavior)?;
+            Ok(())
+        }
+        ProofValidationMode::ValidatePresence(resource_address) => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address(reads.resource_address(proof), resource_address)?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateNotFrozen(resource_address) => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address(reads.resource_address(proof), resource_address)?;
+            query_hook(ProofValidationQuery::FrozenStatus);
+            check_not_frozen(reads.resource_address(proof))?;
+            Ok(())
+        }
+        ProofValidationMode::ValidateNonFungibleDataField {
+            resource_address,
+            field,
+            is_mutable,
+            index,
+            expected,
+        } => {
+            query_hook(ProofValidationQuery::ResourceAddress);
+            check_resource_address(reads.resource_address(proof), resource_address)?;
+            query_hook(ProofValidationQuery::NonFungibleLocalIds);
+            query_hook(ProofValidationQuery::NonFungibleData);
+            check_non_fungible_data_field(
+                reads.resource_address(proof),
+                proof.non_fungible_local_ids(),
+                &field,
+                is_mutable,
+                index,
+                &expected,
+            )?;
+            Ok(())
+        }
+    }
+}
+
+/// The resource address and amount of a proof, read from the engine the first time they're needed.
+#[derive(Default)]
+struct ProofReads {
+    resource_address: Cell<Option<ResourceAddress>>,
+    amount: Cell<Option<Decimal>>,
+}
+
+impl ProofReads {
+    fn resource_address(&self, proof: &Proof) -> ResourceAddress {
+        if let Some(resource_address) = self.resource_address.get() {
+            return resource_address;
+        }
+
+        let resource_address = proof.resource_address();
+        self.resource_address.set(Some(resource_address));
+        resource_address
+    }
+
+    fn amount(&self, proof: &Proof) -> Decimal {
+        if let Some(amount) = self.amount.get() {
+            return amount;
+        }
+
+        let amount = proof.amount();
+        self.amount.set(Some(amount));
+        amount
+    }
+}
+
+fn check_resource_address(
+    actual: ResourceAddress,
+    expected: ResourceAddress,
+) -> Result<(), ProofValidationError> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(ProofValidationError::InvalidResourceAddress(expected))
+    }
+}
+
+fn check_resource_address_belongs_to(
+    actual: ResourceAddress,
+    resource_addresses: &BTreeSet<ResourceAddress>,
+) -> Result<(), ProofValidationError> {
+    if resource_addresses.contains(&actual) {
+        Ok(())
+    } else {
+        Err(ProofValidationError::ResourceAddressDoesNotBelongToList)
+    }
+}
+
+fn check_contains_amount(actual: Decimal, amount: Decimal) -> Result<(), ProofValidationError> {
+    if actual >= amount {
+        Ok(())
+    } else {
+        Err(ProofValidationError::InvalidAmount(amount))
+    }
+}
+
+fn check_amount_divisibility(
+    resource_address: ResourceAddress,
+    amount: Decimal,
+) -> Result<(), ProofValidationError> {
+    let divisibility = borrow_resource_manager!(resource_address)
+        .resource_type()
+        .divisibility();
+    if amount.round(divisibility.into(), RoundingMode::TowardsZero) == amount {
+        Ok(())
+    } else {
+        Err(ProofValidationError::AmountExceedsDivisibility {
+            amount,
+            divisibility,
+        })
+    }
+}
+
+fn check_resource_behavior(
+    resource_address: ResourceAddress,
+    required: BehaviorFlags,
+) -> Result<(), ProofValidationError> {
+    let actual = borrow_resource_manager!(resource_address).behavior();
+    if actual.contains(required) {
+        Ok(())
+    } else {
+        Err(ProofValidationError::BehaviorMismatch(
+            required.difference(actual),
+        ))
+    }
+}
+
+fn check_not_frozen(resource_address: ResourceAddress) -> Result<(), ProofValidationError> {
+    if borrow_resource_manager!(resource_address).is_frozen() {
+        Err(ProofValidationError::ResourceFrozen(resource_address))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_non_fungible_data_field(
+    resource_address: ResourceAddress,
+    non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+    field: &str,
+    is_mutable: bool,
+    index: usize,
+    expected: &[u8],
+) -> Result<(), ProofValidationError> {
+    let mut env = ScryptoEnv;
+    for id in non_fungible_local_ids {
+        let [immutable_data, mutable_data] = env
+            .invoke(ResourceManagerGetNonFungibleInvocation {
+                receiver: resource_address,
+                id,
+            })
+            .unwrap();
+        let data: ScryptoValue = if is_mutable {
+            scrypto_decode(&mutable_data).unwrap()
+        } else {
+            scrypto_decode(&immutable_data).unwrap()
+        };
+        let is_match = match data {
+            ScryptoValue::Tuple { fields } => fields
+                .get(index)
+                .map(|value| scrypto_encode(value).unwrap() == expected)
+                .unwrap_or(false),
+            _ => false,
+        };
+        if !is_match {
+            return Err(ProofValidationError::DataFieldMismatch {
+                field: field.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_contains_amount_with_symbol(
+    resource_address: ResourceAddress,
+    actual_amount: Decimal,
+    symbol: &str,
+    amount: Decimal,
+) -> Result<(), ProofValidationError> {
+    let actual_symbol =
+        borrow_resource_manager!(resource_address).get_metadata("symbol".to_string());
+    let symbol_matches = actual_symbol.as_deref() == Some(symbol);
+    let amount_sufficient = check_contains_amount(actual_amount, amount).is_ok();
+    let failure = match (symbol_matches, amount_sufficient) {
+        (true, true) => return Ok(()),
+        (false, true) => AmountWithSymbolFailure::SymbolMismatch,
+        (true, false) => AmountWithSymbolFailure::AmountShortfall,
+        (false, false) => AmountWithSymbolFailure::SymbolMismatchAndAmountShortfall,
+    };
+    Err(ProofValidationError::AmountWithSymbolNotMet(failure))
+}
+
+fn check_controlled_by(
+    resource_address: ResourceAddress,
+    access_controller: ComponentAddress,
+) -> Result<(), ProofValidationError> {
+    let mut env = ScryptoEnv;
+    let controlled_resource_address = env
+        .invoke(AccessControllerGetControlledResourceManagerInvocation {
+            receiver: access_controller,
+        })
+        .unwrap()
+        .resource_address;
+    if resource_address == controlled_resource_address {
+        Ok(())
+    } else {
+        Err(ProofValidationError::NotControlledBy(access_controller))
+    }
+}
+
+/// Validates that a set of proofs together prove at least `min_total` of the given resource.
+///
+/// The amounts of all proofs of the resource are summed, while proofs of any other resource are
+/// ignored, so that payments authorized by several proofs can be checked in one go.
+pub fn validate_proof_set_amount(
+    proofs: &[ValidatedProof],
+    resource_address: ResourceAddress,
+    min_total: Decimal,
+) -> Result<(), ProofValidationError> {
+    let total = proofs
+        .iter()
+        .filter(|proof| proof.resource_address() == resource_address)
+        .fold(Decimal::zero(), |total, proof| total + proof.amount());
+    if total >= min_total {
+        Ok(())
+    } else {
+        Err(ProofValidationError::InsufficientTotalAmount {
+            required: min_total,
+            actual: total,
+        })
+    }
+}
+
+/// Validates every one of the given proofs against the same validation mode, such as the proofs
+/// drained from an auth zone.
+///
+/// Either all of the proofs are validated, or none are: on the first failure, all of the proofs
+/// are returned unvalidated alongside the error, so that the caller still owns them.
+pub fn validate_all_proofs(
+    proofs: Vec<Proof>,
+    validation_mode: &ProofValidationMode,
+) -> Result<Vec<ValidatedProof>, (Vec<Proof>, ProofValidationError)> {
+    if let Some(error) = proofs
+        .iter()
+        .find_map(|proof| proof.validate(validation_mode.clone()).err())
+    {
+        return Err((proofs, error));
+    }
+    Ok(proofs.into_iter().map(ValidatedProof::new).collect())
 }
 
 /// Represents a proof of owning some resource that has had its resource address validated.
-#[derive(Debug, PartialEq, Eq, Hash)]
-pub struct ValidatedProof(pub(crate) Proof);
+///
+/// The resource address of the proof is cached after it's first read, as it can't change for the
+/// lifetime of the proof.
+#[derive(Debug)]
+pub struct ValidatedProof(pub(crate) Proof, Cell<Option<ResourceAddress>>);
+
+impl PartialEq for ValidatedProof {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for ValidatedProof {}
+
+impl Hash for ValidatedProof {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
 
 #[cfg(target_arch = "wasm32")]
 impl Clone for ValidatedProof {
     fn clone(&self) -> Self {
-        ValidatedProof(ScryptoProof::clone(&self.0))
+        ValidatedProof(ScryptoProof::clone(&self.0), self.1.clone())
     }
 }
 
 impl ValidatedProof {
+    pub(crate) fn new(proof: Proof) -> Self {
+        Self(proof, Cell::new(None))
+    }
+
+    pub fn resource_address(&self) -> ResourceAddress {
+        if let Some(resource_address) = self.1.get() {
+            return resource_address;
+        }
+
+        let mut env = ScryptoEnv;
+        let resource_address = env
+            .invoke(ProofGetResourceAddressInvocation {
+                receiver: self.proof_id(),
+            })
+            .unwrap();
+        self.1.set(Some(resource_address));
+        resource_address
+    }
+
     scrypto_env_native_fn! {
         pub fn amount(&self) -> Decimal {
             ProofGetAmountInvocation {
@@ -259,11 +903,6 @@ impl ValidatedProof {
                 receiver: self.proof_id(),
             }
         }
-        pub fn resource_address(&self) -> ResourceAddress {
-            ProofGetResourceAddressInvocation {
-                receiver: self.proof_id(),
-            }
-        }
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -271,6 +910,22 @@ impl ValidatedProof {
         self.0.drop()
     }
 
+    /// Converts this proof into one which only proves the resource address and amount.
+    ///
+    /// The returned proof lists no non-fungible ids and can't be used to satisfy non-fungible
+    /// auth rules, so it can be passed on without revealing which non-fungibles are held.
+    pub fn into_resource_proof(self) -> ValidatedProof {
+        let mut env = ScryptoEnv;
+        let resource_proof = env
+            .invoke(ProofIntoResourceProofInvocation {
+                receiver: self.proof_id(),
+            })
+            .unwrap();
+        let resource_address = self.1.get();
+        self.0.drop();
+        ValidatedProof(resource_proof, Cell::new(resource_address))
+    }
+
     /// Whether this proof includes an ownership proof of any of the given resource.
     pub fn contains(&self, resource_address: ResourceAddress) -> bool {
         self.resource_address() == resource_address
@@ -333,6 +988,23 @@ impl ValidatedProof {
         self.amount() == 0.into()
     }
 
+    /// Whether this proof and the given proof prove the same resource, amount and non-fungibles.
+    ///
+    /// Unlike `==`, which compares the identity of the proofs, this compares what they prove.
+    pub fn contents_eq(&self, other: &ValidatedProof) -> bool {
+        let resource_address = self.resource_address();
+        if resource_address != other.resource_address() || self.amount() != other.amount() {
+            return false;
+        }
+
+        match borrow_resource_manager!(resource_address).resource_type() {
+            ResourceType::Fungible { .. } => true,
+            ResourceType::NonFungible { .. } => {
+                self.non_fungible_local_ids() == other.non_fungible_local_ids()
+            }
+        }
+    }
+
     fn proof_id(&self) -> ProofId {
         self.0 .0
     }
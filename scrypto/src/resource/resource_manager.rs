@@ -221,6 +221,16 @@ impl ResourceManager {
                 receiver: self.0,
             }
         }
+        pub fn behavior(&self) -> BehaviorFlags {
+            ResourceManagerGetBehaviorInvocation {
+                receiver: self.0,
+            }
+        }
+        pub fn is_frozen(&self) -> bool {
+            ResourceManagerIsFrozenInvocation {
+                receiver: self.0,
+            }
+        }
         pub fn non_fungible_exists(&self, id: &NonFungibleLocalId) -> bool {
             ResourceManagerNonFungibleExistsInvocation {
                 receiver: self.0,
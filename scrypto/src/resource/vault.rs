@@ -77,6 +77,8 @@ pub trait ScryptoVault {
     fn create_proof(&self) -> Proof;
     fn create_proof_by_amount(&self, amount: Decimal) -> Proof;
     fn create_proof_by_ids(&self, ids: &BTreeSet<NonFungibleLocalId>) -> Proof;
+    fn create_one_shot_proof(&self) -> Proof;
+    fn create_presence_proof(&self) -> Proof;
     fn lock_fee<A: Into<Decimal>>(&mut self, amount: A);
     fn lock_contingent_fee<A: Into<Decimal>>(&mut self, amount: A);
     fn take<A: Into<Decimal>>(&mut self, amount: A) -> Bucket;
@@ -173,7 +175,7 @@ impl ScryptoVault for Vault {
         }
 
         fn create_proof_by_amount(&self, amount: Decimal) -> Proof {
-            VaultCreateProofByAmountInvocation {  receiver: self.0,amount }
+            VaultCreateProofByAmountInvocation {  receiver: self.0,amount, allow_zero_amount: false }
         }
 
         fn create_proof_by_ids(&self, ids: &BTreeSet<NonFungibleLocalId>) -> Proof {
@@ -181,6 +183,28 @@ impl ScryptoVault for Vault {
         }
     }
 
+    /// Creates a proof of this vault that can be pushed onto the auth zone only once and can't be
+    /// cloned.
+    fn create_one_shot_proof(&self) -> Proof {
+        let proof = self.create_proof();
+        let mut env = ScryptoEnv;
+        env.invoke(ProofMakeOneShotInvocation { receiver: proof.0 })
+            .unwrap();
+        proof
+    }
+
+    /// Creates a zero-amount presence proof of this vault, which succeeds even if the vault is empty. See
+    /// [`ProofValidationMode::ValidatePresence`] for what such a proof does and doesn't prove.
+    fn create_presence_proof(&self) -> Proof {
+        let mut env = ScryptoEnv;
+        env.invoke(VaultCreateProofByAmountInvocation {
+            receiver: self.0,
+            amount: Decimal::zero(),
+            allow_zero_amount: true,
+        })
+        .unwrap()
+    }
+
     /// Locks the specified amount as transaction fee.
     ///
     /// Unused fee will be refunded to the vaults from the most recently locked to the least.
@@ -917,6 +917,7 @@ impl ManifestBuilder {
         recovery_role: AccessRule,
         confirmation_role: AccessRule,
         timed_recovery_delay_in_minutes: Option<u32>,
+        settings: AccessControllerSettings,
     ) -> &mut Self {
         self.add_instruction(BasicInstruction::CreateAccessController {
             controlled_asset,
@@ -924,6 +925,7 @@ impl ManifestBuilder {
             recovery_role,
             confirmation_role,
             timed_recovery_delay_in_minutes,
+            settings,
         })
         .0
     }
@@ -203,6 +203,7 @@ pub enum Instruction {
         recovery_role: Value,
         confirmation_role: Value,
         timed_recovery_delay_in_minutes: Value,
+        settings: Value,
     },
     CreateIdentity {
         access_rule: Value,
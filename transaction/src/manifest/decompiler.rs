@@ -561,6 +561,7 @@ pub fn decompile_instruction<F: fmt::Write>(
             recovery_role,
             confirmation_role,
             timed_recovery_delay_in_minutes,
+            settings,
         } => {
             f.write_str("CREATE_ACCESS_CONTROLLER")?;
             format_typed_value(f, context, controlled_asset)?;
@@ -568,6 +569,7 @@ pub fn decompile_instruction<F: fmt::Write>(
             format_typed_value(f, context, recovery_role)?;
             format_typed_value(f, context, confirmation_role)?;
             format_typed_value(f, context, timed_recovery_delay_in_minutes)?;
+            format_typed_value(f, context, settings)?;
             f.write_str(";")?;
         }
         BasicInstruction::CreateIdentity { access_rule } => {
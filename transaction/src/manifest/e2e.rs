@@ -584,7 +584,8 @@ CREATE_ACCESS_CONTROLLER
     Enum(0u8)
     Enum(0u8)
     Enum(0u8)
-    Enum(1u8, 1u32);
+    Enum(1u8, 1u32)
+    Tuple(false, Enum(0u8), Enum(1u8, 2u8), Enum(1u8, 60u32), false);
 "##,
         );
     }
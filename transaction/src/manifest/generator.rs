@@ -554,6 +554,7 @@ pub fn generate_instruction(
             recovery_role,
             confirmation_role,
             timed_recovery_delay_in_minutes,
+            settings,
         } => BasicInstruction::CreateAccessController {
             controlled_asset: generate_typed_value(
                 controlled_asset,
@@ -575,6 +576,7 @@ pub fn generate_instruction(
                 bech32_decoder,
                 blobs,
             )?,
+            settings: generate_typed_value(settings, resolver, bech32_decoder, blobs)?,
         },
         ast::Instruction::CreateIdentity { access_rule } => BasicInstruction::CreateIdentity {
             access_rule: generate_typed_value(access_rule, resolver, bech32_decoder, blobs)?,
@@ -255,6 +255,7 @@ impl Parser {
                 recovery_role: self.parse_value()?,
                 confirmation_role: self.parse_value()?,
                 timed_recovery_delay_in_minutes: self.parse_value()?,
+                settings: self.parse_value()?,
             },
             TokenKind::CreateIdentity => Instruction::CreateIdentity {
                 access_rule: self.parse_value()?,
@@ -205,6 +205,7 @@ pub enum BasicInstruction {
         recovery_role: AccessRule,
         confirmation_role: AccessRule,
         timed_recovery_delay_in_minutes: Option<u32>,
+        settings: AccessControllerSettings,
     },
 
     CreateIdentity {